@@ -0,0 +1,44 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import androidx.test.ext.junit.runners.AndroidJUnit4
+import org.junit.Assert.*
+import org.junit.Assume.assumeNotNull
+import org.junit.Test
+import org.junit.runner.RunWith
+
+/** Affinity needs the native library and a real kernel, so this runs on a device */
+@RunWith(AndroidJUnit4::class)
+class CpuAffinityManagerTest {
+
+    @Test
+    fun testRunWithAffinityPinsAndRestores() {
+        val before = CpuAffinityManager.getThreadAffinity()
+        assumeNotNull(before)
+        val core = CpuAffinityManager.lastCoreIds()
+
+        val inside = CpuAffinityManager.runWithAffinity(core) { CpuAffinityManager.getThreadAffinity() }
+
+        assertArrayEquals(core, inside)
+        assertArrayEquals(before, CpuAffinityManager.getThreadAffinity())
+    }
+
+    @Test
+    fun testRunWithAffinityRestoresWhenBlockThrows() {
+        val before = CpuAffinityManager.getThreadAffinity()
+        assumeNotNull(before)
+
+        val error =
+            runCatching {
+                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) { error("boom") }
+            }.exceptionOrNull()
+
+        assertTrue(error is IllegalStateException)
+        assertArrayEquals(before, CpuAffinityManager.getThreadAffinity())
+    }
+
+    @Test
+    fun testLastCoreIdsIsHighestCore() {
+        val highest = CpuAffinityManager.detectCpuTopology().maxOf { it.id }
+        assertArrayEquals(intArrayOf(highest), CpuAffinityManager.lastCoreIds())
+    }
+}
@@ -13,34 +13,44 @@
 extern "C" {
 
 /**
- * Sets CPU affinity for the current thread to a specific core
+ * Sets CPU affinity for the current thread to a set of cores
  * 
  * @param env JNI environment
  * @param obj Java object (unused)
- * @param coreId The CPU core ID to pin to (0-based)
+ * @param coreIds The CPU core IDs to allow (0-based)
  * @return true if successful, false otherwise
  */
 JNIEXPORT jboolean JNICALL
-Java_com_ivarna_finalbenchmark2_cpuBenchmark_CpuAffinityManager_nativeSetCpuAffinity(
+Java_com_ivarna_finalbenchmark2_cpuBenchmark_CpuAffinityManager_nativeSetCpuAffinityMask(
         JNIEnv* env,
         jobject obj,
-        jint coreId) {
+        jintArray coreIds) {
     
-    cpu_set_t cpuset;
-    CPU_ZERO(&cpuset);              // Clear the CPU set
-    CPU_SET(coreId, &cpuset);       // Add the specified core to the set
+    jsize count = env->GetArrayLength(coreIds);
+    jint* cores = env->GetIntArrayElements(coreIds, nullptr);
+    if (cores == nullptr) {
+        LOGE("Failed to read core ID array");
+        return JNI_FALSE;
+    }
     
-    pid_t tid = gettid();           // Get current thread ID
+    cpu_set_t cpuset;
+    CPU_ZERO(&cpuset);
+    for (jsize i = 0; i < count; i++) {
+        if (cores[i] >= 0 && cores[i] < CPU_SETSIZE) {
+            CPU_SET(cores[i], &cpuset);
+        }
+    }
+    env->ReleaseIntArrayElements(coreIds, cores, JNI_ABORT);
     
-    // Set CPU affinity for the current thread
+    pid_t tid = gettid();
     int result = sched_setaffinity(tid, sizeof(cpu_set_t), &cpuset);
     
     if (result == 0) {
-        LOGI("Successfully pinned thread %d to CPU core %d", tid, coreId);
+        LOGI("Successfully pinned thread %d to %d CPU cores", tid, (int) count);
         return JNI_TRUE;
     } else {
-        LOGE("Failed to set CPU affinity for thread %d to core %d: %s (errno=%d)", 
-             tid, coreId, strerror(errno), errno);
+        LOGE("Failed to set CPU affinity mask for thread %d: %s (errno=%d)", 
+             tid, strerror(errno), errno);
         return JNI_FALSE;
     }
 }
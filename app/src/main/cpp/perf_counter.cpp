@@ -0,0 +1,87 @@
+#include <jni.h>
+#include <linux/perf_event.h>
+#include <sys/ioctl.h>
+#include <sys/syscall.h>
+#include <unistd.h>
+#include <android/log.h>
+#include <errno.h>
+#include <string.h>
+#include <stdint.h>
+
+#define LOG_TAG "NativePerfCounter"
+#define LOGD(...) __android_log_print(ANDROID_LOG_DEBUG, LOG_TAG, __VA_ARGS__)
+#define LOGW(...) __android_log_print(ANDROID_LOG_WARN, LOG_TAG, __VA_ARGS__)
+
+extern "C" {
+
+/**
+ * Opens a hardware counter for the calling thread (user space only) and starts it
+ *
+ * @param env JNI environment
+ * @param obj Java object (unused)
+ * @param config PERF_COUNT_HW_* event id
+ * @return File descriptor, or -errno on failure
+ */
+JNIEXPORT jint JNICALL
+Java_com_ivarna_finalbenchmark2_cpuBenchmark_PerfCounters_nativeOpen(
+        JNIEnv* env,
+        jobject obj,
+        jint config) {
+
+    struct perf_event_attr attr;
+    memset(&attr, 0, sizeof(attr));
+    attr.type = PERF_TYPE_HARDWARE;
+    attr.size = sizeof(attr);
+    attr.config = (uint64_t) config;
+    attr.disabled = 1;
+    // Unprivileged apps may only count their own user-space execution
+    attr.exclude_kernel = 1;
+    attr.exclude_hv = 1;
+
+    // pid 0, cpu -1: this thread, on whichever CPU it runs
+    int fd = (int) syscall(__NR_perf_event_open, &attr, 0, -1, -1, 0);
+    if (fd < 0) {
+        int err = errno;
+        LOGW("perf_event_open(config=%d) failed: %s (errno=%d)", config, strerror(err), err);
+        return -err;
+    }
+
+    ioctl(fd, PERF_EVENT_IOC_RESET, 0);
+    ioctl(fd, PERF_EVENT_IOC_ENABLE, 0);
+    LOGD("Opened perf counter config=%d fd=%d", config, fd);
+    return fd;
+}
+
+/**
+ * Reads the current count of an open counter
+ *
+ * @return The count, or -1 if the read failed
+ */
+JNIEXPORT jlong JNICALL
+Java_com_ivarna_finalbenchmark2_cpuBenchmark_PerfCounters_nativeRead(
+        JNIEnv* env,
+        jobject obj,
+        jint fd) {
+
+    uint64_t count = 0;
+    if (read(fd, &count, sizeof(count)) != sizeof(count)) {
+        LOGW("Failed to read perf counter fd=%d: %s (errno=%d)", fd, strerror(errno), errno);
+        return -1;
+    }
+    return (jlong) count;
+}
+
+/**
+ * Stops and closes a counter
+ */
+JNIEXPORT void JNICALL
+Java_com_ivarna_finalbenchmark2_cpuBenchmark_PerfCounters_nativeClose(
+        JNIEnv* env,
+        jobject obj,
+        jint fd) {
+
+    ioctl(fd, PERF_EVENT_IOC_DISABLE, 0);
+    close(fd);
+}
+
+} // extern "C"
@@ -0,0 +1,46 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import java.util.concurrent.atomic.AtomicBoolean
+
+/**
+ * Cross-thread control for a running benchmark suite
+ *
+ * Benchmark kernels are plain CPU-bound loops that don't suspend, so cancelling the coroutine
+ * running the suite does not stop them. The suite polls this flag between benchmarks, and kernels
+ * poll it at their outer iteration boundary through [repeatUntilCancelled].
+ */
+object BenchmarkControl {
+    private val cancelled = AtomicBoolean(false)
+
+    /** Ask the running suite to stop at the next benchmark boundary */
+    fun requestCancellation() {
+        cancelled.set(true)
+    }
+
+    fun isCancelled(): Boolean = cancelled.get()
+
+    /**
+     * Check stride for outer loops whose iterations take well under a microsecond, where a flag
+     * read every iteration would show up in the measurement
+     */
+    const val FINE_GRAINED_CHECK_STRIDE = 1024
+
+    /**
+     * [repeat] for a kernel's outer iteration loop: stops before the next iteration once
+     * cancellation has been requested, checking every [checkEvery] iterations
+     *
+     * @return Iterations actually run; less than [times] only when cancelled
+     */
+    inline fun repeatUntilCancelled(times: Int, checkEvery: Int = 1, action: (Int) -> Unit): Int {
+        for (i in 0 until times) {
+            if (i % checkEvery == 0 && isCancelled()) return i
+            action(i)
+        }
+        return times
+    }
+
+    /** Clear a previous cancellation request; called at the start of every suite run */
+    fun reset() {
+        cancelled.set(false)
+    }
+}
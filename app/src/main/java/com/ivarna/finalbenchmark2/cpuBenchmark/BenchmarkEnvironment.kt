@@ -0,0 +1,101 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import android.content.Context
+import android.os.BatteryManager
+import android.os.Build
+import android.os.Debug
+import android.os.PowerManager
+import android.util.Log
+import java.io.File
+import org.json.JSONObject
+
+/** A condition at the start of a run that makes its results less trustworthy */
+sealed class EnvironmentWarning(val type: String) {
+    /** Below [LOW_BATTERY_PERCENT] many devices cap CPU frequencies to stretch the battery */
+    data class LowBattery(val pct: Int) : EnvironmentWarning("low_battery")
+
+    /** See [MemoryPressure]; reclaim and swapping compete with the benchmarks */
+    object HighMemoryPressure : EnvironmentWarning("high_memory_pressure")
+
+    /** A tracer or the Java debugger slows the process and may stop it at any point */
+    object DebuggerAttached : EnvironmentWarning("debugger_attached")
+
+    /** Battery saver lowers CPU frequency limits and defers background work onto the run */
+    object PowerSavingModeEnabled : EnvironmentWarning("power_saving_mode")
+
+    /** The platform already reports moderate or worse thermal status (API 29+) */
+    object ThrottlingActive : EnvironmentWarning("throttling_active")
+
+    fun toJson(): JSONObject =
+        JSONObject().apply {
+            put("type", type)
+            (this@EnvironmentWarning as? LowBattery)?.let { put("pct", it.pct) }
+        }
+
+    companion object {
+        /** Parse a [toJson] object; null for unknown types */
+        fun fromJson(json: JSONObject): EnvironmentWarning? =
+            when (json.optString("type")) {
+                "low_battery" -> LowBattery(json.optInt("pct"))
+                "high_memory_pressure" -> HighMemoryPressure
+                "debugger_attached" -> DebuggerAttached
+                "power_saving_mode" -> PowerSavingModeEnabled
+                "throttling_active" -> ThrottlingActive
+                else -> null
+            }
+    }
+}
+
+const val LOW_BATTERY_PERCENT = 20
+
+private const val ENVIRONMENT_TAG = "BenchmarkEnvironment"
+
+/**
+ * Check for conditions unfavorable to accurate benchmarking
+ *
+ * Battery, power saving and thermal status need [context] and are skipped without one; memory
+ * pressure comes from [memoryPressure] as already read for the run.
+ *
+ * @return Every warning that applies; empty if the environment looks clean
+ */
+fun checkBenchmarkEnvironment(context: Context?, memoryPressure: MemoryPressure?): List<EnvironmentWarning> {
+    val warnings = mutableListOf<EnvironmentWarning>()
+
+    if (context != null) {
+        val battery = context.getSystemService(Context.BATTERY_SERVICE) as? BatteryManager
+        val pct = battery?.getIntProperty(BatteryManager.BATTERY_PROPERTY_CAPACITY) ?: -1
+        // getIntProperty returns Int.MIN_VALUE (or 0 on some devices) when unsupported
+        if (pct in 1 until LOW_BATTERY_PERCENT) warnings.add(EnvironmentWarning.LowBattery(pct))
+
+        val power = context.getSystemService(Context.POWER_SERVICE) as? PowerManager
+        if (power?.isPowerSaveMode == true) warnings.add(EnvironmentWarning.PowerSavingModeEnabled)
+        if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.Q &&
+            power != null &&
+            power.currentThermalStatus >= PowerManager.THERMAL_STATUS_MODERATE
+        ) {
+            warnings.add(EnvironmentWarning.ThrottlingActive)
+        }
+    }
+
+    if (memoryPressure?.pressureLevel == PressureLevel.HIGH) warnings.add(EnvironmentWarning.HighMemoryPressure)
+
+    // JDWP debuggers don't show up as a tracer, and native tracers don't show up in JDWP
+    val tracerPid =
+        try {
+            parseTracerPid(File("/proc/self/status").readText())
+        } catch (e: Exception) {
+            Log.w(ENVIRONMENT_TAG, "Could not read /proc/self/status", e)
+            null
+        }
+    if (Debug.isDebuggerConnected() || (tracerPid ?: 0) != 0) warnings.add(EnvironmentWarning.DebuggerAttached)
+
+    return warnings
+}
+
+/** The TracerPid field of /proc/<pid>/status text (0 when untraced); null if absent */
+fun parseTracerPid(status: String): Int? =
+    status.lineSequence()
+        .firstOrNull { it.startsWith("TracerPid:") }
+        ?.substringAfter(':')
+        ?.trim()
+        ?.toIntOrNull()
@@ -1,5 +1,17 @@
 package com.ivarna.finalbenchmark2.cpuBenchmark
 
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.builtins.ListSerializer
+import kotlinx.serialization.builtins.serializer
+import kotlinx.serialization.descriptors.SerialDescriptor
+import kotlinx.serialization.encoding.Decoder
+import kotlinx.serialization.encoding.Encoder
+import kotlinx.serialization.json.Json
+import org.json.JSONArray
+import org.json.JSONObject
+
 /** Represents an event during benchmark execution */
 data class BenchmarkEvent(
         val testName: String,
@@ -29,6 +41,160 @@ data class BenchmarkResult(
         val accelerationMode: String? = null // Added for AI Benchmarks
 )
 
+/**
+ * Timings of three sorts over copies of the same 31-bit keys
+ *
+ * @property stableSortNs Library stable sort (TimSort on boxed Integers)
+ * @property unstableSortNs Library primitive sort (dual-pivot quicksort on IntArray)
+ * @property radixSortNs LSD radix sort (8-bit digits)
+ * @property count Keys per sort
+ * @property outputsMatch True when all three sorts produced the same order
+ */
+data class SortingComparisonResult(
+        val stableSortNs: Long,
+        val unstableSortNs: Long,
+        val radixSortNs: Long,
+        val count: Int,
+        val outputsMatch: Boolean
+) {
+        fun toJson(): JSONObject =
+                JSONObject().apply {
+                        put("stable_sort_ns", stableSortNs)
+                        put("unstable_sort_ns", unstableSortNs)
+                        put("radix_sort_ns", radixSortNs)
+                        put("count", count)
+                        put("outputs_match", outputsMatch)
+                }
+}
+
+/**
+ * A finished suite run: summary scores, every individual result, and device metadata
+ * (label -> value, e.g. "Model" -> "Pixel 8")
+ *
+ * [threadPoolWarmupMs] is the time spent starting the multi-core worker threads before the
+ * run, or null when the summary does not record it. [deviceFingerprint] and [memoryPressure]
+ * are null for summaries recorded before they were added. [environmentWarnings] lists the
+ * conditions found by [checkBenchmarkEnvironment] before the run.
+ */
+data class SuiteResult(
+        val summary: BenchmarkSummary,
+        val results: List<BenchmarkResult>,
+        val deviceInfo: Map<String, String> = emptyMap(),
+        val threadPoolWarmupMs: Double? = null,
+        val deviceFingerprint: DeviceFingerprint? = null,
+        val memoryPressure: MemoryPressure? = null,
+        val environmentWarnings: List<EnvironmentWarning> = emptyList()
+) {
+        val singleCoreResults: List<BenchmarkResult>
+                get() = results.filter { it.name.startsWith("Single-Core") }
+
+        val multiCoreResults: List<BenchmarkResult>
+                get() = results.filter { it.name.startsWith("Multi-Core") }
+
+        companion object {
+                /** Parse the summary JSON emitted on [KotlinBenchmarkManager.benchmarkComplete] */
+                fun fromSummaryJson(summaryJson: String, deviceInfo: Map<String, String> = emptyMap()): SuiteResult {
+                        val json = JSONObject(summaryJson)
+                        val detailed = json.optJSONArray("detailed_results") ?: JSONArray()
+                        val results =
+                                (0 until detailed.length()).map { i ->
+                                        val item = detailed.getJSONObject(i)
+                                        BenchmarkResult(
+                                                name = item.optString("name"),
+                                                executionTimeMs = item.optDouble("executionTimeMs", 0.0),
+                                                opsPerSecond = item.optDouble("opsPerSecond", 0.0),
+                                                isValid = item.optBoolean("isValid", false),
+                                                metricsJson = item.optString("metricsJson", "{}")
+                                        )
+                                }
+                        val summary =
+                                BenchmarkSummary(
+                                        singleCoreScore = json.optDouble("single_core_score", 0.0),
+                                        multiCoreScore = json.optDouble("multi_core_score", 0.0),
+                                        finalScore = json.optDouble("final_score", 0.0),
+                                        normalizedScore = json.optDouble("normalized_score", 0.0),
+                                        rating = json.optString("rating")
+                                )
+                        val threadPoolWarmupMs =
+                                if (json.has("thread_pool_warmup_ms")) json.optDouble("thread_pool_warmup_ms") else null
+                        val deviceFingerprint = json.optJSONObject("device_fingerprint")?.let { DeviceFingerprint.fromJson(it) }
+                        val memoryPressure = json.optJSONObject("memory_pressure")?.let { MemoryPressure.fromJson(it) }
+                        val warningsArray = json.optJSONArray("environment_warnings") ?: JSONArray()
+                        val environmentWarnings =
+                                (0 until warningsArray.length()).mapNotNull { i ->
+                                        warningsArray.optJSONObject(i)?.let { EnvironmentWarning.fromJson(it) }
+                                }
+                        return SuiteResult(
+                                summary,
+                                results,
+                                deviceInfo,
+                                threadPoolWarmupMs,
+                                deviceFingerprint,
+                                memoryPressure,
+                                environmentWarnings
+                        )
+                }
+        }
+}
+
+/**
+ * Builder for [BenchmarkResult] so scoring tests can create results without running benchmarks
+ *
+ * Unset fields default to an empty name, 0 ms, 0 ops/s, valid and empty metrics ("{}").
+ */
+class BenchmarkResultBuilder {
+        private var name: String = ""
+        private var timeMs: Double = 0.0
+        private var ops: Double = 0.0
+        private var valid: Boolean = true
+        private var accelerationMode: String? = null
+
+        fun name(n: String) = apply { name = n }
+
+        fun ops(o: Double) = apply { ops = o }
+
+        fun timeMs(ms: Double) = apply { timeMs = ms }
+
+        fun valid(v: Boolean) = apply { valid = v }
+
+        fun accelerationMode(mode: String?) = apply { accelerationMode = mode }
+
+        fun build(): BenchmarkResult = buildWithMetrics("{}")
+
+        fun buildWithMetrics(metricsJson: String): BenchmarkResult =
+                BenchmarkResult(
+                        name = name,
+                        executionTimeMs = timeMs,
+                        opsPerSecond = ops,
+                        isValid = valid,
+                        metricsJson = metricsJson,
+                        accelerationMode = accelerationMode
+                )
+}
+
+/**
+ * Invoked before each benchmark in a suite run starts
+ * (benchmark name, zero-based index, total benchmarks in the run)
+ */
+typealias ProgressCallback = (benchmarkName: String, index: Int, total: Int) -> Unit
+
+/**
+ * Invoked after each benchmark in a suite run finishes
+ * (benchmark name, result JSON, zero-based index, total benchmarks in the run)
+ */
+typealias BenchmarkCompleteCallback = (benchmarkName: String, resultJson: String, index: Int, total: Int) -> Unit
+
+/** Receives the outcome of [KotlinBenchmarkManager.runBenchmarksAsync] on the worker thread */
+interface SuiteCallback {
+        /** @param resultJson Summary JSON, as emitted on [KotlinBenchmarkManager.benchmarkComplete] */
+        fun onComplete(resultJson: String)
+
+        /** Called after each benchmark finishes; see [BenchmarkCompleteCallback] */
+        fun onBenchmarkComplete(name: String, resultJson: String, index: Int, total: Int) {}
+
+        fun onError(errorMessage: String)
+}
+
 /** Represents benchmark configuration */
 data class BenchmarkConfig(
         val iterations: Int = 3,
@@ -40,30 +206,125 @@ data class BenchmarkConfig(
 /**
  * Represents workload parameters for standardized benchmarking Optimized for consistent 1.5-2.0
  * second execution times on flagship devices
+ *
+ * Defaults are the Mid-tier values, so WorkloadParams() is a usable workload on its own.
+ * Serializes to JSON with snake_case keys; Pair fields are encoded as two-element arrays.
  */
+@Serializable
 data class WorkloadParams(
-        val primeRange: Int = 500_000,  // Increased for Miller-Rabin
-        val fibonacciNRange: Pair<Int, Int> = Pair(30, 32),
-        val fibonacciIterations: Int = 333_333,  // Reduced 3x for polynomial
-        val matrixSize: Int = 128, // FIXED: Small size for cache-resident strategy
-        val matrixIterations: Int = 200, // FIXED: Number of repetitions for cache-resident strategy
-        val hashDataSizeMb: Int = 2,
-        val hashIterations: Int = 200_000, // FIXED WORK PER CORE: Target ~1.5-2.0 seconds execution
-        val stringSortCount: Int = 50_000, // LEGACY: Kept for backward compatibility
-        val stringSortIterations: Int =
+        @SerialName("prime_range") val primeRange: Int = 490_000_000,
+        @SerialName("fibonacci_n_range")
+        @Serializable(with = IntPairAsArraySerializer::class)
+        val fibonacciNRange: Pair<Int, Int> = Pair(92, 92),
+        @SerialName("fibonacci_iterations") val fibonacciIterations: Int = 20_833_333,
+        @SerialName("matrix_size") val matrixSize: Int = 128, // FIXED: Small size for cache-resident strategy
+        @SerialName("matrix_iterations") val matrixIterations: Int = 1_500, // Repetitions for cache-resident strategy
+        @SerialName("hash_data_size_mb") val hashDataSizeMb: Int = 8,
+        @SerialName("hash_iterations") val hashIterations: Int = 262_750_000, // FIXED WORK PER CORE
+        @SerialName("string_sort_count") val stringSortCount: Int = 50_000, // LEGACY: Kept for backward compatibility
+        @SerialName("string_sort_iterations") val stringSortIterations: Int =
                 2_500, // CACHE-RESIDENT: Explicit iterations for string sorting
-        val rayTracingIterations: Int =
-                400, // FIXED: Increased from 40 to 400 for proper test duration with fast kernel
-        val rayTracingResolution: Pair<Int, Int> = Pair(192, 192),
-        val rayTracingDepth: Int = 3,
-        val compressionDataSizeMb: Int = 2,
-        val compressionIterations: Int =
-                100, // FIXED WORK PER CORE: Target ~1.5-2.0 seconds execution
-        val monteCarloSamples: Long = 10_000L,  // Reduced 100x for Mandelbrot Set
-        val jsonDataSizeMb: Int = 1,
-        val jsonParsingIterations: Int = 100, // Reduced 10x for CPU-bound parsing
-        val nqueensSize: Int = 10
-)
+        @SerialName("ray_tracing_iterations") val rayTracingIterations: Int = 400,
+        @SerialName("ray_tracing_resolution")
+        @Serializable(with = IntPairAsArraySerializer::class)
+        val rayTracingResolution: Pair<Int, Int> = Pair(256, 256),
+        @SerialName("ray_tracing_depth") val rayTracingDepth: Int = 5,
+        @SerialName("compression_data_size_mb") val compressionDataSizeMb: Int = 2,
+        @SerialName("compression_iterations") val compressionIterations: Int = 1_000, // FIXED WORK PER CORE
+        @SerialName("monte_carlo_samples") val monteCarloSamples: Long = 25_000_000L, // Mandelbrot Set samples
+        @SerialName("json_data_size_mb") val jsonDataSizeMb: Int = 1,
+        @SerialName("json_parsing_iterations") val jsonParsingIterations: Int = 1_250,
+        @SerialName("nqueens_size") val nqueensSize: Int = 15,
+        @SerialName("scan_element_count") val scanElementCount: Int = 4_000_000, // Parallel prefix sum: elements per scan
+        @SerialName("scan_iterations") val scanIterations: Int = 50,
+        @SerialName("string_search_count") val stringSearchCount: Int = 200_000, // String search: strings in the corpus
+        @SerialName("string_search_pattern_len") val stringSearchPatternLen: Int = 5,
+        @SerialName("string_search_iterations") val stringSearchIterations: Int = 20,
+        @SerialName("graph_node_count") val graphNodeCount: Int = 500_000, // Graph BFS: nodes (average out-degree 8)
+        @SerialName("graph_bfs_iterations") val graphBfsIterations: Int = 10,
+        @SerialName("fft_size") val fftSize: Int = 65_536, // FFT: points per transform (power of two)
+        @SerialName("fft_iterations") val fftIterations: Int = 100,
+        @SerialName("aes_data_size_mb") val aesDataSizeMb: Int = 128, // AES-128-CBC: MB encrypted and decrypted per thread
+        @SerialName("regex_corpus_size") val regexCorpusSize: Int = 200_000, // Regex: sentences (80-120 chars) in the corpus
+        @SerialName("hash_chain_depth") val hashChainDepth: Int = 2_000_000, // SHA-256 chain: sequential rounds
+        @SerialName("sort_element_count") val sortElementCount: Int = 4_000_000, // Merge sort: 32-bit keys per sort
+        @SerialName("levenshtein_pair_count") val levenshteinPairCount: Int = 400_000, // Levenshtein: string pairs (30-50 chars each)
+        @SerialName("knapsack_items") val knapsackItems: Int = 5_000, // 0/1 knapsack: items (capacity = items × 10)
+        @SerialName("polynomial_degree") val polynomialDegree: Int = 1_000, // Polynomial evaluation: degree of each polynomial
+        @SerialName("polynomial_eval_points") val polynomialEvalPoints: Int = 10_000, // Polynomial evaluation: points each polynomial is evaluated at
+        @SerialName("integer_ops_count") val integerOpsCount: Long = 100_000_000L, // Bitwise / atomic operation iterations (channel benchmarks send 1/100 as many messages)
+        @SerialName("ray_tracing_spp") val rayTracingSpp: Int = 16, // Anti-aliased ray tracing: random sub-pixel samples per pixel
+        @SerialName("wavelet_frame_size") val waveletFrameSize: Int = 4_096, // Haar wavelet: samples per frame in the multi-core run (power of two)
+        @SerialName("dedup_duplicate_fraction") val dedupDuplicateFraction: Double = 0.30 // String dedup: fraction of strings that repeat an earlier one (0 until 1)
+) {
+        /** Encode as JSON with snake_case keys (all fields, including defaults) */
+        fun toJson(): String = jsonFormat.encodeToString(serializer(), this)
+
+        companion object {
+                private val jsonFormat = Json {
+                        encodeDefaults = true
+                        ignoreUnknownKeys = true
+                }
+
+                /** Decode from JSON; missing keys fall back to the Mid-tier defaults */
+                fun fromJson(json: String): WorkloadParams = jsonFormat.decodeFromString(serializer(), json)
+        }
+
+        /**
+         * Return a copy with every workload size and iteration count multiplied by [factor]
+         *
+         * Results are clamped to sane minimums (at least 1, matrixSize >= 10). nqueensSize is
+         * capped at 18 because solve time grows exponentially with board size, and fftSize is
+         * rounded down to a power of two. Shape parameters (fibonacciNRange, rayTracingDepth,
+         * rayTracingSpp, stringSearchPatternLen, polynomialDegree, waveletFrameSize,
+         * dedupDuplicateFraction) are kept as-is.
+         */
+        fun scale(factor: Double): WorkloadParams {
+                require(factor > 0.0 && factor.isFinite()) { "Scale factor must be positive, got $factor" }
+
+                fun scaled(value: Int, min: Int = 1): Int =
+                        (value * factor).coerceIn(min.toDouble(), Int.MAX_VALUE.toDouble()).toInt()
+
+                return copy(
+                        primeRange = scaled(primeRange, 10),
+                        fibonacciIterations = scaled(fibonacciIterations),
+                        matrixSize = scaled(matrixSize, 10),
+                        matrixIterations = scaled(matrixIterations),
+                        hashDataSizeMb = scaled(hashDataSizeMb),
+                        hashIterations = scaled(hashIterations),
+                        stringSortCount = scaled(stringSortCount),
+                        stringSortIterations = scaled(stringSortIterations),
+                        rayTracingIterations = scaled(rayTracingIterations),
+                        rayTracingResolution =
+                                Pair(
+                                        scaled(rayTracingResolution.first, 8),
+                                        scaled(rayTracingResolution.second, 8)
+                                ),
+                        compressionDataSizeMb = scaled(compressionDataSizeMb),
+                        compressionIterations = scaled(compressionIterations),
+                        monteCarloSamples = (monteCarloSamples * factor).toLong().coerceAtLeast(1L),
+                        jsonDataSizeMb = scaled(jsonDataSizeMb),
+                        jsonParsingIterations = scaled(jsonParsingIterations),
+                        nqueensSize = scaled(nqueensSize, MIN_NQUEENS_SIZE).coerceAtMost(MAX_NQUEENS_SIZE),
+                        scanElementCount = scaled(scanElementCount),
+                        scanIterations = scaled(scanIterations),
+                        stringSearchCount = scaled(stringSearchCount),
+                        stringSearchIterations = scaled(stringSearchIterations),
+                        graphNodeCount = scaled(graphNodeCount, 2),
+                        graphBfsIterations = scaled(graphBfsIterations),
+                        fftSize = Integer.highestOneBit(scaled(fftSize, 2)),
+                        fftIterations = scaled(fftIterations),
+                        aesDataSizeMb = scaled(aesDataSizeMb),
+                        regexCorpusSize = scaled(regexCorpusSize),
+                        hashChainDepth = scaled(hashChainDepth),
+                        sortElementCount = scaled(sortElementCount),
+                        levenshteinPairCount = scaled(levenshteinPairCount),
+                        knapsackItems = scaled(knapsackItems),
+                        polynomialEvalPoints = scaled(polynomialEvalPoints),
+                        integerOpsCount = (integerOpsCount * factor).toLong().coerceAtLeast(1L)
+                )
+        }
+}
 
 /**
  * Represents workload parameters for AI Benchmarks
@@ -85,3 +346,20 @@ data class AiWorkloadParams(
     val heavyModelWarmup: Int = 1, // For LLM, Whisper
     val asrWarmup: Int = 0 // Whisper specific
 )
+
+/** Encodes Pair<Int, Int> as a two-element JSON array ([first, second]) */
+object IntPairAsArraySerializer : KSerializer<Pair<Int, Int>> {
+        private val delegate = ListSerializer(Int.serializer())
+
+        override val descriptor: SerialDescriptor = delegate.descriptor
+
+        override fun serialize(encoder: Encoder, value: Pair<Int, Int>) {
+                encoder.encodeSerializableValue(delegate, listOf(value.first, value.second))
+        }
+
+        override fun deserialize(decoder: Decoder): Pair<Int, Int> {
+                val values = decoder.decodeSerializableValue(delegate)
+                require(values.size == 2) { "Expected a two-element array, got ${values.size} elements" }
+                return Pair(values[0], values[1])
+        }
+}
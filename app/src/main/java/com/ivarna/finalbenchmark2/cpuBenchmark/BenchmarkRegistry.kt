@@ -0,0 +1,362 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import com.ivarna.finalbenchmark2.cpuBenchmark.algorithms.MultiCoreBenchmarks
+import com.ivarna.finalbenchmark2.cpuBenchmark.algorithms.SingleCoreBenchmarks
+import kotlinx.coroutines.CoroutineDispatcher
+import org.json.JSONArray
+import org.json.JSONObject
+
+/** Errors returned by name-based benchmark dispatch */
+sealed class BenchmarkError(message: String) : Exception(message) {
+    class UnknownBenchmark(val name: String) : BenchmarkError("Unknown benchmark: $name")
+
+    class InvalidParams(val errors: List<WorkloadParamError>) :
+        BenchmarkError("Invalid workload params: ${errors.joinToString()}")
+}
+
+/** Whether a CPU benchmark runs on one pinned core or on all cores */
+enum class BenchmarkCoreMode {
+    SINGLE_CORE,
+    MULTI_CORE
+}
+
+/**
+ * Describes a runnable CPU benchmark
+ *
+ * @property defaultWeight Share of the final CPU score (0.0 for benchmarks outside the scored suite)
+ */
+data class BenchmarkMetadata(
+    val name: String,
+    val coreMode: BenchmarkCoreMode,
+    val description: String,
+    val defaultWeight: Double
+)
+
+/**
+ * Name-based dispatch for every CPU benchmark, keyed by the name each benchmark reports in
+ * [BenchmarkResult.name] (e.g. "Single-Core Prime Generation")
+ *
+ * New benchmarks only need an entry here to become runnable by name and discoverable.
+ */
+object BenchmarkRegistry {
+
+    // Final score = 35% single-core geometric mean + 65% multi-core geometric mean, with every
+    // suite benchmark weighted equally inside its geometric mean
+    private val SUITE_SIZE = BenchmarkName.getByCategory(BenchmarkCategory.CPU).size
+    private val SINGLE_CORE_WEIGHT = 0.35 / SUITE_SIZE
+    private val MULTI_CORE_WEIGHT = 0.65 / SUITE_SIZE
+
+    // Multi-core benchmarks take an optional pool; single-core entries ignore it
+    private class Entry(
+        val metadata: BenchmarkMetadata,
+        val run: suspend (WorkloadParams, Boolean, CoroutineDispatcher?) -> BenchmarkResult
+    )
+
+    private val entries = linkedMapOf<String, Entry>()
+
+    private fun registerSingle(
+        name: String,
+        description: String,
+        weight: Double,
+        run: suspend (WorkloadParams, Boolean) -> BenchmarkResult
+    ) {
+        entries[name] = Entry(BenchmarkMetadata(name, BenchmarkCoreMode.SINGLE_CORE, description, weight)) { params, isTestRun, _ ->
+            run(params, isTestRun)
+        }
+    }
+
+    private fun registerMulti(
+        name: String,
+        description: String,
+        weight: Double,
+        run: suspend (WorkloadParams, Boolean, CoroutineDispatcher?) -> BenchmarkResult
+    ) {
+        entries[name] = Entry(BenchmarkMetadata(name, BenchmarkCoreMode.MULTI_CORE, description, weight), run)
+    }
+
+    private fun registerSuite(
+        benchmark: BenchmarkName,
+        description: String,
+        single: suspend (WorkloadParams, Boolean) -> BenchmarkResult,
+        multi: suspend (WorkloadParams, Boolean, CoroutineDispatcher?) -> BenchmarkResult
+    ) {
+        registerSingle(benchmark.singleCore(), description, SINGLE_CORE_WEIGHT, single)
+        registerMulti(benchmark.multiCore(), description, MULTI_CORE_WEIGHT, multi)
+    }
+
+    init {
+        // Scored suite
+        registerSuite(BenchmarkName.PRIME_GENERATION, "Prime counting with Pollard's Rho factorization",
+            SingleCoreBenchmarks::primeGeneration, MultiCoreBenchmarks::primeGeneration)
+        registerSuite(BenchmarkName.FIBONACCI_ITERATIVE, "Polynomial evaluation (Horner's method) throughput",
+            SingleCoreBenchmarks::fibonacciRecursive, MultiCoreBenchmarks::fibonacciRecursive)
+        registerSuite(BenchmarkName.MATRIX_MULTIPLICATION, "Cache-resident dense matrix multiplication",
+            SingleCoreBenchmarks::matrixMultiplication, MultiCoreBenchmarks::matrixMultiplication)
+        registerSuite(BenchmarkName.HASH_COMPUTING, "Integer hashing throughput",
+            SingleCoreBenchmarks::hashComputing, MultiCoreBenchmarks::hashComputing)
+        registerSuite(BenchmarkName.STRING_SORTING, "Cache-resident string sorting",
+            SingleCoreBenchmarks::stringSorting, MultiCoreBenchmarks::stringSorting)
+        registerSuite(BenchmarkName.RAY_TRACING, "Perlin noise / ray tracing floating-point workload",
+            SingleCoreBenchmarks::rayTracing, MultiCoreBenchmarks::rayTracing)
+        registerSuite(BenchmarkName.COMPRESSION, "Run-length encoding compression",
+            SingleCoreBenchmarks::compression, MultiCoreBenchmarks::compression)
+        registerSuite(BenchmarkName.MONTE_CARLO, "Mandelbrot set sampling",
+            SingleCoreBenchmarks::monteCarloPi, MultiCoreBenchmarks::monteCarloPi)
+        registerSuite(BenchmarkName.JSON_PARSING, "JSON parsing and element counting",
+            SingleCoreBenchmarks::jsonParsing, MultiCoreBenchmarks::jsonParsing)
+        registerSuite(BenchmarkName.N_QUEENS, "Bitwise backtracking N-Queens solver",
+            SingleCoreBenchmarks::nqueens, MultiCoreBenchmarks::nqueens)
+
+        // Additional benchmarks (not part of the scored suite)
+        registerSingle("Single-Core String Search",
+            "Boyer-Moore-Horspool substring search", 0.0, SingleCoreBenchmarks::stringSearch)
+        registerSingle("Single-Core Graph BFS",
+            "Breadth-first search over a sparse random graph", 0.0, SingleCoreBenchmarks::graphBfs)
+        registerSingle("Single-Core FFT",
+            "Radix-2 Cooley-Tukey FFT round trips", 0.0, SingleCoreBenchmarks::fft)
+        registerSingle("Single-Core AES-128",
+            "AES-128-CBC encrypt and decrypt", 0.0, SingleCoreBenchmarks::aes128)
+        registerSingle("Single-Core Fibonacci Loop",
+            "Fibonacci numbers by iteration", 0.0, SingleCoreBenchmarks::fibonacciLoop)
+        registerSingle("Single-Core Fibonacci DP",
+            "Fibonacci numbers by bottom-up dynamic programming", 0.0, SingleCoreBenchmarks::fibonacciDp)
+        registerSingle("Single-Core Radix Sort",
+            "LSD radix sort of 32-bit keys", 0.0, SingleCoreBenchmarks::radixSort)
+        registerSingle("Single-Core Regex",
+            "Email-pattern regex matching over random sentences", 0.0, SingleCoreBenchmarks::regex)
+        registerSingle("Single-Core SHA-256 Chain",
+            "Sequential SHA-256 hash chaining", 0.0, SingleCoreBenchmarks::sha256Chain)
+        registerSingle("Single-Core CRC-32",
+            "CRC-32 checksum, one-shot and in 64 KB chunks", 0.0, SingleCoreBenchmarks::crc32)
+        registerSingle("Single-Core Base64",
+            "Base64 encode and decode round trip", 0.0, SingleCoreBenchmarks::base64)
+        registerSingle("Single-Core Merge Sort",
+            "Top-down merge sort of 32-bit keys", 0.0, SingleCoreBenchmarks::mergeSort)
+        registerSingle("Single-Core Levenshtein",
+            "Wagner-Fischer edit distance over random string pairs", 0.0, SingleCoreBenchmarks::levenshtein)
+        registerSingle("Single-Core Sparse MatVec",
+            "CSR sparse matrix-vector multiplication", 0.0, SingleCoreBenchmarks::sparseMatVec)
+        registerSingle("Single-Core Knapsack",
+            "0/1 knapsack by iterative DP", 0.0, SingleCoreBenchmarks::knapsack)
+        registerSingle("Single-Core Dijkstra",
+            "Binary-heap Dijkstra on a random weighted graph", 0.0, SingleCoreBenchmarks::dijkstra)
+        registerSingle("Single-Core Convex Hull",
+            "Andrew's monotone chain convex hull", 0.0, SingleCoreBenchmarks::convexHull)
+        registerSingle("Single-Core Bloom Filter",
+            "3-hash bitset Bloom filter inserts and queries", 0.0, SingleCoreBenchmarks::bloomFilter)
+        registerSingle("Single-Core UTF-8 Validation",
+            "Strict UTF-8 validation of mixed-script text", 0.0, SingleCoreBenchmarks::utf8Validation)
+        registerSingle("Single-Core JSON Serialization",
+            "Serialization of the JSON parsing benchmark's document with org.json", 0.0, SingleCoreBenchmarks::jsonSerialization)
+        registerSingle("Single-Core Polynomial Evaluation",
+            "Horner's method on random high-degree polynomials", 0.0, SingleCoreBenchmarks::polynomialEval)
+        registerSingle("Single-Core Prime Factorization",
+            "Trial division plus Pollard's rho on 64-bit integers", 0.0, SingleCoreBenchmarks::primeFactorization)
+        registerSingle("Single-Core Bitwise Operations",
+            "Popcount, parity and bit reversal dependency chain", 0.0, SingleCoreBenchmarks::bitwiseOperations)
+        registerSingle("Single-Core Monte Carlo Integration",
+            "Hit-or-miss integration of exp(-x^2) and sin(1/x)", 0.0, SingleCoreBenchmarks::monteCarloIntegration)
+        registerSingle("Single-Core Fibonacci u128",
+            "Iterative Fibonacci in 128-bit unsigned integers, n up to 186", 0.0, SingleCoreBenchmarks::fibonacciU128)
+        registerSingle("Single-Core Fibonacci BigInt",
+            "Fast-doubling Fibonacci with BigInteger, n up to 500", 0.0, SingleCoreBenchmarks::fibonacciBigInt)
+        registerSingle("Single-Core String Formatting",
+            "printf-style formatting of five-field records", 0.0, SingleCoreBenchmarks::stringFormatting)
+        registerSingle("Single-Core Atomic Operations",
+            "Uncontended fetch-add, CAS and load on an AtomicLong", 0.0, SingleCoreBenchmarks::atomicOps)
+        registerSingle("Single-Core Channel Throughput",
+            "Producer/consumer message passing over a blocking queue", 0.0, SingleCoreBenchmarks::channelThroughput)
+        registerSingle("Single-Core Hash Map",
+            "Insert and look up string keys in a HashMap", 0.0, SingleCoreBenchmarks::concurrentHashMap)
+        registerSingle("Single-Core Memory Allocation",
+            "Short-lived byte array allocation by size class", 0.0, SingleCoreBenchmarks::allocation)
+        registerSingle("Single-Core N-Queens Bitmask",
+            "Iterative 64-bit bitmask N-Queens, compared with a boolean-array solver", 0.0, SingleCoreBenchmarks::nqueensBitmask)
+        registerSingle("Single-Core Ray Tracing Anti-aliased",
+            "Sphere ray tracing with random sub-pixel samples", 0.0, SingleCoreBenchmarks::rayTracingAntialiased)
+        registerSingle("Single-Core Ray Tracing BVH",
+            "Ray tracing 50 spheres through a bounding volume hierarchy", 0.0, SingleCoreBenchmarks::rayTracingBvh)
+        registerSingle("Single-Core Tree Reduction",
+            "Pairwise tree reduction (sum, max, min) of doubles", 0.0, SingleCoreBenchmarks::parallelReduction)
+        registerSingle("Single-Core LZ77",
+            "Sliding-window LZ77 compression round trips", 0.0, SingleCoreBenchmarks::lz77)
+        registerSingle("Single-Core Huffman",
+            "Canonical Huffman encode and decode", 0.0, SingleCoreBenchmarks::huffman)
+        registerSingle("Single-Core Fibonacci Matrix",
+            "Fibonacci by 2x2 matrix exponentiation, 128-bit exact", 0.0, SingleCoreBenchmarks::fibonacciMatrix)
+        registerSingle("Single-Core N-Queens Dancing Links",
+            "N-Queens solutions/s as exact cover with dancing links (Algorithm X)", 0.0, SingleCoreBenchmarks::nqueensDancingLinks)
+        registerSingle("Single-Core Transcendental Functions",
+            "sin / cos / exp / ln / sqrt evaluations per second", 0.0, SingleCoreBenchmarks::floatTranscendental)
+        registerSingle("Single-Core Unicode String Sorting",
+            "Merge sort of mixed-script strings, ordinal vs lowercase comparison", 0.0, SingleCoreBenchmarks::unicodeStringSorting)
+        registerSingle("Single-Core Haar Wavelet",
+            "Multi-level 1D Haar wavelet transform and inverse, samples/s", 0.0, SingleCoreBenchmarks::waveletTransform)
+        registerSingle("Single-Core String Dedup",
+            "HashSet deduplication of strings with a tier-dependent duplicate rate", 0.0, SingleCoreBenchmarks::stringDedup)
+        registerSingle("Single-Core Z-Order Curve",
+            "Morton code encode / decode of 32-bit coordinate pairs, pairs/s", 0.0, SingleCoreBenchmarks::zorderCurve)
+        registerSingle("Single-Core JPEG Decode Sim",
+            "8x8 DCT, quantization and IDCT on image blocks, blocks/s", 0.0, SingleCoreBenchmarks::jpegDecodeSim)
+        registerSingle("Single-Core LRU Cache",
+            "Linked-list + HashMap LRU cache under a Zipf access pattern", 0.0, SingleCoreBenchmarks::lruCache)
+        registerSingle("Single-Core Priority Queue",
+            "Mixed insert / extract-min on a binary heap of event times", 0.0, SingleCoreBenchmarks::priorityQueue)
+        registerSingle("Single-Core Compression Sweep",
+            "LZ77 ratio and throughput from constant to random data", 0.0, SingleCoreBenchmarks::compressionRatioSweep)
+        registerSingle("Single-Core Bit-Reversal Permutation",
+            "In-place bit-reversal permutation vs sequential copy, elements/s", 0.0, SingleCoreBenchmarks::bitReversal)
+        registerSingle("Single-Core Matrix Transpose",
+            "Naive, blocked and cache-oblivious transpose, elements/s", 0.0, SingleCoreBenchmarks::matrixTranspose)
+        registerSingle("Single-Core Crypto RNG",
+            "SecureRandom throughput vs the XorShift PRNG, bytes/s", 0.0, SingleCoreBenchmarks::cryptoRng)
+        registerSingle("Single-Core Linked List Traversal",
+            "Pointer chasing through a shuffled linked list, nodes/s", 0.0, SingleCoreBenchmarks::linkedListTraversal)
+        registerSingle("Single-Core B-Tree Operations",
+            "Ordered map inserts, point lookups and range scans", 0.0, SingleCoreBenchmarks::bTreeOps)
+        registerSingle("Single-Core String Builder",
+            "Presized vs growing StringBuilder vs concatenation, chars/s", 0.0, SingleCoreBenchmarks::stringBuilder)
+        registerSingle("Single-Core Vector Operations",
+            "Float multiply-add, scalar vs 8-lane blocks", 0.0, SingleCoreBenchmarks::vectorOps)
+        registerSingle("Single-Core Counting Sort",
+            "Counting sort of 16-bit keys vs Arrays.sort, elements/s", 0.0, SingleCoreBenchmarks::countingSort)
+        registerSingle("Single-Core String Interning",
+            "HashMap symbol table lookups, 90% hits", 0.0, SingleCoreBenchmarks::stringInterning)
+        registerMulti("Multi-Core Parallel Scan",
+            "Two-pass parallel prefix sum", 0.0, MultiCoreBenchmarks::parallelScan)
+        registerMulti("Multi-Core String Search",
+            "Boyer-Moore-Horspool substring search over a partitioned corpus", 0.0, MultiCoreBenchmarks::stringSearch)
+        registerMulti("Multi-Core Graph BFS",
+            "Level-synchronous parallel breadth-first search", 0.0, MultiCoreBenchmarks::graphBfs)
+        registerMulti("Multi-Core FFT",
+            "Independent radix-2 FFT frames per thread", 0.0, MultiCoreBenchmarks::fft)
+        registerMulti("Multi-Core AES-128",
+            "AES-128-CBC encrypt and decrypt, one buffer per thread", 0.0, MultiCoreBenchmarks::aes128)
+        registerMulti("Multi-Core Fibonacci DP",
+            "Fibonacci dynamic programming, fixed work per core", 0.0, MultiCoreBenchmarks::fibonacciDp)
+        registerMulti("Multi-Core Radix Sort",
+            "Parallel LSD radix sort with per-chunk histograms", 0.0, MultiCoreBenchmarks::radixSort)
+        registerMulti("Multi-Core Regex",
+            "Email-pattern regex matching over a partitioned corpus", 0.0, MultiCoreBenchmarks::regex)
+        registerMulti("Multi-Core CRC-32",
+            "CRC-32 checksum of independent blocks in parallel", 0.0, MultiCoreBenchmarks::crc32)
+        registerMulti("Multi-Core Base64",
+            "Base64 encode and decode round trip, one block per thread", 0.0, MultiCoreBenchmarks::base64)
+        registerMulti("Multi-Core Parallel Merge Sort",
+            "Merge sort with a parallel divide step", 0.0, MultiCoreBenchmarks::parallelMergeSort)
+        registerMulti("Multi-Core Levenshtein",
+            "Wagner-Fischer edit distance, pairs partitioned across threads", 0.0, MultiCoreBenchmarks::levenshtein)
+        registerMulti("Multi-Core Sparse MatVec",
+            "Row-parallel CSR sparse matrix-vector multiplication", 0.0, MultiCoreBenchmarks::sparseMatVec)
+        registerMulti("Multi-Core Knapsack",
+            "0/1 knapsack, independent instance per thread", 0.0, MultiCoreBenchmarks::knapsack)
+        registerMulti("Multi-Core Dijkstra",
+            "Binary-heap Dijkstra, one source per thread", 0.0, MultiCoreBenchmarks::dijkstra)
+        registerMulti("Multi-Core Convex Hull",
+            "Monotone chain convex hull, independent point set per thread", 0.0, MultiCoreBenchmarks::convexHull)
+        registerMulti("Multi-Core Bloom Filter",
+            "Lock-free Bloom filter: private inserts, merged, partitioned queries", 0.0, MultiCoreBenchmarks::bloomFilter)
+        registerMulti("Multi-Core UTF-8 Validation",
+            "Strict UTF-8 validation, buffer partitioned on sequence boundaries", 0.0, MultiCoreBenchmarks::utf8Validation)
+        registerMulti("Multi-Core JSON Serialization",
+            "Independent per-thread JSON document serialization", 0.0, MultiCoreBenchmarks::jsonSerialization)
+        registerMulti("Multi-Core Polynomial Evaluation",
+            "Horner's method, evaluation points partitioned across threads", 0.0, MultiCoreBenchmarks::polynomialEval)
+        registerMulti("Multi-Core Prime Factorization",
+            "Trial division plus Pollard's rho, values partitioned across threads", 0.0, MultiCoreBenchmarks::primeFactorization)
+        registerMulti("Multi-Core Bitwise Operations",
+            "Popcount, parity and bit reversal, iterations split across threads", 0.0, MultiCoreBenchmarks::bitwiseOperations)
+        registerMulti("Multi-Core Monte Carlo Integration",
+            "Hit-or-miss integration, samples split across threads", 0.0, MultiCoreBenchmarks::monteCarloIntegration)
+        registerMulti("Multi-Core String Formatting",
+            "printf-style formatting of five-field records", 0.0, MultiCoreBenchmarks::stringFormatting)
+        registerMulti("Multi-Core Atomic Operations",
+            "Fetch-add, CAS and load with all threads on one AtomicLong", 0.0, MultiCoreBenchmarks::atomicOps)
+        registerMulti("Multi-Core Channel Throughput",
+            "Many producers, one consumer over a blocking queue", 0.0, MultiCoreBenchmarks::channelThroughput)
+        registerMulti("Multi-Core Concurrent Hash Map",
+            "Shared ConcurrentHashMap insert and lookup", 0.0, MultiCoreBenchmarks::concurrentHashMap)
+        registerMulti("Multi-Core Memory Allocation",
+            "Independent short-lived allocations per thread", 0.0, MultiCoreBenchmarks::allocation)
+        registerMulti("Multi-Core N-Queens Bitmask",
+            "Iterative 64-bit bitmask N-Queens, one task per first-row column", 0.0, MultiCoreBenchmarks::nqueensBitmask)
+        registerMulti("Multi-Core Parallel Reduction",
+            "Chunked parallel reduction (sum, max, min) of doubles", 0.0, MultiCoreBenchmarks::parallelReduction)
+        registerMulti("Multi-Core Huffman",
+            "Per-thread Huffman blocks with a frame table for parallel decode", 0.0, MultiCoreBenchmarks::huffman)
+        registerMulti("Multi-Core Transcendental Functions",
+            "sin / cos / exp / ln / sqrt evaluations per second across threads", 0.0, MultiCoreBenchmarks::floatTranscendental)
+        registerMulti("Multi-Core Haar Wavelet",
+            "Haar wavelet round trips on independent frames across threads", 0.0, MultiCoreBenchmarks::waveletTransform)
+        registerMulti("Multi-Core String Dedup",
+            "Sharded HashSet deduplication with per-shard locks across threads", 0.0, MultiCoreBenchmarks::stringDedup)
+        registerMulti("Multi-Core Z-Order Curve",
+            "Morton code round trips on coordinate ranges split across threads", 0.0, MultiCoreBenchmarks::zorderCurve)
+        registerMulti("Multi-Core JPEG Decode Sim",
+            "8x8 DCT / quantization / IDCT on block ranges split across threads", 0.0, MultiCoreBenchmarks::jpegDecodeSim)
+        registerMulti("Multi-Core LRU Cache",
+            "Sharded LRU cache with per-shard locks across threads", 0.0, MultiCoreBenchmarks::lruCache)
+        registerMulti("Multi-Core Priority Queue",
+            "Lock-per-shard event heaps with mixed insert / extract-min across threads", 0.0, MultiCoreBenchmarks::priorityQueue)
+        registerMulti("Multi-Core Matrix Transpose",
+            "Blocked transpose with source row blocks split across threads", 0.0, MultiCoreBenchmarks::matrixTranspose)
+        registerMulti("Multi-Core Crypto RNG",
+            "Per-thread SecureRandom instances filling separate chunks", 0.0, MultiCoreBenchmarks::cryptoRng)
+        registerMulti("Multi-Core B-Tree Operations",
+            "One independent ordered map per thread", 0.0, MultiCoreBenchmarks::bTreeOps)
+        registerMulti("Multi-Core String Interning",
+            "Shared ConcurrentHashMap symbol table", 0.0, MultiCoreBenchmarks::stringInterning)
+    }
+
+    /** All registered benchmark names, scored suite first */
+    val names: List<String>
+        get() = entries.keys.toList()
+
+    /** Metadata for every registered benchmark, scored suite first */
+    fun listAvailableBenchmarks(): List<BenchmarkMetadata> = entries.values.map { it.metadata }
+
+    /** [listAvailableBenchmarks] as a JSON array of {name, core_mode, description, default_weight} */
+    fun listAvailableBenchmarksJson(): String {
+        return JSONArray().apply {
+            listAvailableBenchmarks().forEach { metadata ->
+                put(JSONObject().apply {
+                    put("name", metadata.name)
+                    put("core_mode", metadata.coreMode.name)
+                    put("description", metadata.description)
+                    put("default_weight", metadata.defaultWeight)
+                })
+            }
+        }.toString()
+    }
+
+    /** Metadata for one benchmark, or null if it isn't registered */
+    fun metadata(name: String): BenchmarkMetadata? = entries[name.trim()]?.metadata
+
+    /** Look up a benchmark function by name, or null if it isn't registered */
+    fun find(name: String): (suspend (WorkloadParams, Boolean) -> BenchmarkResult)? {
+        val entry = entries[name.trim()] ?: return null
+        return { params, isTestRun -> entry.run(params, isTestRun, null) }
+    }
+
+    /**
+     * Run a benchmark by name
+     *
+     * @param pool Pool for multi-core benchmarks (see [MultiCoreBenchmarks.buildBenchmarkPool]);
+     * null uses the shared pool, single-core benchmarks ignore it
+     * @return The benchmark result, or a failure with [BenchmarkError.UnknownBenchmark] or
+     * [BenchmarkError.InvalidParams] (checked before anything runs)
+     */
+    suspend fun runBenchmarkByName(
+        name: String,
+        params: WorkloadParams,
+        isTestRun: Boolean = false,
+        pool: CoroutineDispatcher? = null
+    ): Result<BenchmarkResult> {
+        val entry = entries[name.trim()] ?: return Result.failure(BenchmarkError.UnknownBenchmark(name))
+        val errors = validateWorkloadParams(params)
+        if (errors.isNotEmpty()) return Result.failure(BenchmarkError.InvalidParams(errors))
+        val result = entry.run(params, isTestRun, pool)
+        // A kernel cut short by cancellation returns a partial measurement
+        return Result.success(if (BenchmarkControl.isCancelled()) result.copy(isValid = false) else result)
+    }
+}
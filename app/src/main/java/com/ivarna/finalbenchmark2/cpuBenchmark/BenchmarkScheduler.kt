@@ -0,0 +1,116 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import kotlinx.coroutines.CoroutineDispatcher
+import org.json.JSONArray
+import org.json.JSONObject
+
+/**
+ * One benchmark as handled by [BenchmarkScheduler]
+ *
+ * @property result The benchmark result, or null when it was skipped
+ */
+data class ScheduledBenchmark(
+    val name: String,
+    val estimatedSecs: Double,
+    val skipped: Boolean,
+    val result: BenchmarkResult?
+)
+
+/**
+ * Outcome of [BenchmarkScheduler.runAll], with benchmarks in the order they were considered
+ * (cheapest first)
+ */
+data class SchedulerResult(
+    val budgetSecs: Double,
+    val elapsedSecs: Double,
+    val benchmarks: List<ScheduledBenchmark>
+) {
+    val skippedCount: Int
+        get() = benchmarks.count { it.skipped }
+
+    fun toJson(): JSONObject {
+        return JSONObject().apply {
+            put("budget_secs", budgetSecs)
+            put("elapsed_secs", elapsedSecs)
+            put("skipped_count", skippedCount)
+            put("benchmarks", JSONArray().apply {
+                benchmarks.forEach { benchmark ->
+                    put(JSONObject().apply {
+                        put("name", benchmark.name)
+                        put("estimated_secs", benchmark.estimatedSecs)
+                        put("skipped", benchmark.skipped)
+                        benchmark.result?.let { result ->
+                            put("opsPerSecond", result.opsPerSecond)
+                            put("executionTimeMs", result.executionTimeMs)
+                            put("isValid", result.isValid)
+                            put("metricsJson", result.metricsJson)
+                        }
+                    })
+                }
+            })
+        }
+    }
+}
+
+/**
+ * Runs a set of benchmarks within a wall-clock budget
+ *
+ * Slow devices can take over half an hour for the full suite, long enough for Android to kill
+ * or ANR the app. The scheduler runs the cheapest benchmarks first and skips any benchmark whose
+ * estimate would take the elapsed time past [maxTotalSecs], so a slow device still gets as many
+ * results as fit. Elapsed time is measured, not estimated, so estimates only need to be rough.
+ */
+class BenchmarkScheduler(val maxTotalSecs: Double) {
+
+    private class Entry(
+        val name: String,
+        val run: suspend (WorkloadParams, Boolean, CoroutineDispatcher?) -> BenchmarkResult,
+        val estimatedSecs: Double
+    )
+
+    private val benchmarks = mutableListOf<Entry>()
+
+    init {
+        require(maxTotalSecs > 0.0 && !maxTotalSecs.isNaN()) { "Time budget must be positive, got $maxTotalSecs" }
+    }
+
+    /** Queue a benchmark; [run] receives the params, isTestRun and the pool passed to [runAll] */
+    fun add(
+        name: String,
+        run: suspend (WorkloadParams, Boolean, CoroutineDispatcher?) -> BenchmarkResult,
+        estimatedSecs: Double
+    ) {
+        require(estimatedSecs >= 0.0 && estimatedSecs.isFinite()) {
+            "Estimated duration for $name must be non-negative, got $estimatedSecs"
+        }
+        benchmarks.add(Entry(name, run, estimatedSecs))
+    }
+
+    /**
+     * Run every queued benchmark that fits the budget, cheapest first (ties keep insertion order)
+     *
+     * Benchmarks left after a cancellation request ([BenchmarkControl]) are reported as skipped,
+     * and the one running when it arrived is kept but marked invalid.
+     *
+     * @throws BenchmarkError.InvalidParams If [config] fails [validateWorkloadParams]; nothing runs
+     */
+    suspend fun runAll(config: WorkloadParams, pool: CoroutineDispatcher? = null): SchedulerResult {
+        requireValidWorkloadParams(config)
+        val startNs = System.nanoTime()
+        fun elapsedSecs() = (System.nanoTime() - startNs) / 1_000_000_000.0
+
+        val scheduled =
+            benchmarks.sortedBy { it.estimatedSecs }.map { entry ->
+                if (BenchmarkControl.isCancelled() || elapsedSecs() + entry.estimatedSecs > maxTotalSecs) {
+                    ScheduledBenchmark(entry.name, entry.estimatedSecs, skipped = true, result = null)
+                } else {
+                    val result = entry.run(config, false, pool).let {
+                        // A kernel cut short by cancellation returns a partial measurement
+                        if (BenchmarkControl.isCancelled()) it.copy(isValid = false) else it
+                    }
+                    ScheduledBenchmark(entry.name, entry.estimatedSecs, skipped = false, result = result)
+                }
+            }
+        return SchedulerResult(maxTotalSecs, elapsedSecs(), scheduled)
+    }
+}
@@ -27,7 +27,7 @@ object CpuAffinityManager {
     }
     
     // Native method declarations
-    private external fun nativeSetCpuAffinity(coreId: Int): Boolean
+    private external fun nativeSetCpuAffinityMask(coreIds: IntArray): Boolean
     private external fun nativeResetCpuAffinity(): Boolean
     private external fun nativeGetCpuAffinity(): IntArray?
 
@@ -137,64 +137,110 @@ object CpuAffinityManager {
         return detectCpuTopology().filter { it.coreType == CoreType.LITTLE }.map { it.id }
     }
 
+    private var cachedHardwareAes: Boolean? = null
+
+    /** Check /proc/cpuinfo Features for the ARMv8 crypto extension AES flag */
+    fun hasHardwareAes(): Boolean {
+        cachedHardwareAes?.let {
+            return it
+        }
+
+        val detected = try {
+            File("/proc/cpuinfo").readLines()
+                .filter { it.startsWith("Features", ignoreCase = true) || it.startsWith("flags") }
+                .any { line -> line.substringAfter(':').split(' ', '\t').contains("aes") }
+        } catch (e: Exception) {
+            Log.w(TAG, "Could not read /proc/cpuinfo", e)
+            false
+        }
+
+        Log.d(TAG, "Hardware AES: $detected")
+        cachedHardwareAes = detected
+        return detected
+    }
+
     /**
-     * Pin current thread to the last (largest) CPU core In big.LITTLE architectures, the last core
-     * is typically the highest-performance core This ensures single-core benchmarks run on the
-     * fastest available core
+     * Read back the current thread's affinity mask via sched_getaffinity
+     *
+     * @return IDs of the cores the thread may run on, or null if the native library is missing or
+     * the call failed
      */
-    fun setLastCoreAffinity() {
-        try {
-            val cores = detectCpuTopology()
-            if (cores.isEmpty()) {
-                Log.w(TAG, "No CPU cores detected, cannot set affinity")
-                return
-            }
+    fun getThreadAffinity(): IntArray? {
+        if (!nativeLibraryAvailable) {
+            return null
+        }
 
-            // Get the last core (highest core ID) - typically the largest/fastest core
-            val lastCore = cores.maxByOrNull { it.id }
-            if (lastCore == null) {
-                Log.w(TAG, "Could not determine last core")
-                return
-            }
+        return try {
+            nativeGetCpuAffinity()
+        } catch (e: Exception) {
+            Log.w(TAG, "Exception reading CPU affinity", e)
+            null
+        }
+    }
 
-            // Try to use native CPU affinity if available
-            if (nativeLibraryAvailable) {
-                try {
-                    val success = nativeSetCpuAffinity(lastCore.id)
-                    
-                    if (success) {
-                        Log.d(
-                            TAG,
-                            "✓ Successfully pinned thread to CPU${lastCore.id} " +
-                            "(${lastCore.maxFreqKhz/1000}MHz, ${lastCore.coreType} core)"
-                        )
-                        
-                        // Verify the affinity was set
-                        val affinity = nativeGetCpuAffinity()
-                        if (affinity != null) {
-                            Log.d(TAG, "Current CPU affinity: ${affinity.contentToString()}")
-                        }
-                        return
-                    } else {
-                        Log.w(
-                            TAG,
-                            "Failed to set CPU affinity to core ${lastCore.id}, falling back to thread priority hint"
-                        )
-                    }
-                } catch (e: Exception) {
-                    Log.w(TAG, "Exception calling native CPU affinity, falling back to thread priority hint", e)
-                }
-            }
-            
-            // Fallback: Use thread priority hint
-            Log.d(
-                TAG,
-                "Using thread priority hint for CPU${lastCore.id} (${lastCore.maxFreqKhz/1000}MHz, ${lastCore.coreType} core)"
-            )
+    /**
+     * Restrict the current thread to [coreIds] via sched_setaffinity
+     *
+     * @return False if the native library is missing or the kernel rejected the mask
+     */
+    fun setThreadAffinity(coreIds: IntArray): Boolean {
+        if (!nativeLibraryAvailable || coreIds.isEmpty()) {
+            return false
+        }
 
+        return try {
+            nativeSetCpuAffinityMask(coreIds)
         } catch (e: Exception) {
-            Log.e(TAG, "Error setting last core affinity", e)
+            Log.w(TAG, "Exception setting CPU affinity to ${coreIds.contentToString()}", e)
+            false
+        }
+    }
+
+    /**
+     * Run [block] with the current thread pinned to [coreIds], then restore the thread's previous
+     * affinity (or all cores if it couldn't be read)
+     *
+     * [block] must not suspend: a coroutine may resume on a different thread, which would leave
+     * the pin on the wrong one. If pinning fails [block] still runs, unpinned.
+     */
+    fun <T> runWithAffinity(coreIds: IntArray, block: () -> T): T {
+        val previous = getThreadAffinity()
+        if (!setThreadAffinity(coreIds)) {
+            Log.w(TAG, "Could not pin thread to ${coreIds.contentToString()}, running unpinned")
+        } else {
+            // Some kernels accept the call but ignore it
+            val affinity = getThreadAffinity()
+            if (affinity != null && !affinity.sortedArray().contentEquals(coreIds.sortedArray())) {
+                Log.w(
+                    TAG,
+                    "Requested ${coreIds.contentToString()} but affinity mask is ${affinity.contentToString()}; " +
+                    "kernel may have ignored sched_setaffinity"
+                )
+            }
+        }
+        try {
+            return block()
+        } finally {
+            if (previous == null || !setThreadAffinity(previous)) {
+                resetCpuAffinity()
+            }
+        }
+    }
+
+    /**
+     * The core single-core benchmarks pin to: the last (highest-numbered) one, which in big.LITTLE
+     * layouts is typically the fastest
+     *
+     * @return The core's ID, or an empty array if no cores were detected
+     */
+    fun lastCoreIds(): IntArray {
+        val lastCore = detectCpuTopology().maxByOrNull { it.id }
+        if (lastCore == null) {
+            Log.w(TAG, "No CPU cores detected, cannot pick a core to pin to")
+            return IntArray(0)
         }
+        Log.d(TAG, "Last core: CPU${lastCore.id} (${lastCore.maxFreqKhz/1000}MHz, ${lastCore.coreType} core)")
+        return intArrayOf(lastCore.id)
     }
 
     /**
@@ -2,6 +2,8 @@ package com.ivarna.finalbenchmark2.cpuBenchmark
 
 import android.util.Log
 import java.io.File
+import org.json.JSONArray
+import org.json.JSONObject
 
 /**
  * Detects CPU topology on ARM big.LITTLE architectures
@@ -17,6 +19,24 @@ class CpuTopologyDetector {
         val isOnline: Boolean,
         val isBigCore: Boolean
     )
+
+    enum class ClusterType {
+        PRIME,
+        BIG,
+        MID,
+        LITTLE
+    }
+
+    /** Cores sharing the same max frequency, fastest cluster first in [CpuTopology.clusters] */
+    data class CpuCluster(
+        val coreIds: List<Int>,
+        val maxFreqMhz: Int,
+        val clusterType: ClusterType
+    )
+
+    data class CpuTopology(
+        val clusters: List<CpuCluster>
+    )
     
     companion object {
         private const val TAG = "CpuTopologyDetector"
@@ -25,6 +45,83 @@ class CpuTopologyDetector {
         // Frequency threshold to distinguish big vs LITTLE cores (in KHz)
         // Cores with max freq > 2.0 GHz are typically "big" cores
         private const val BIG_CORE_THRESHOLD_KHZ = 2000000L
+
+        // A fastest cluster with this many cores or fewer is treated as a prime cluster
+        private const val PRIME_CLUSTER_MAX_CORES = 2
+
+        // Kernel cpu_capacity thresholds (the fastest core is normalized to 1024)
+        private const val PRIME_CAPACITY = 768L
+        private const val BIG_CAPACITY = 512L
+        private const val MID_CAPACITY = 256L
+
+        /** Classify one core by its kernel capacity hint (0-1024) */
+        fun classifyCapacity(capacity: Long): ClusterType = when {
+            capacity >= PRIME_CAPACITY -> ClusterType.PRIME
+            capacity >= BIG_CAPACITY -> ClusterType.BIG
+            capacity >= MID_CAPACITY -> ClusterType.MID
+            else -> ClusterType.LITTLE
+        }
+
+        /**
+         * Group cores into clusters by kernel capacity, highest first, classifying each with
+         * [classifyCapacity]
+         *
+         * @param capacityByCore cpu_capacity per core id
+         * @param maxFreqKhzByCore Max frequency (KHz) per core id, for [CpuCluster.maxFreqMhz]
+         */
+        fun classifyClustersByCapacity(
+            capacityByCore: Map<Int, Long>,
+            maxFreqKhzByCore: Map<Int, Long>
+        ): CpuTopology {
+            val clusters = capacityByCore.entries
+                .groupBy({ it.value }, { it.key })
+                .toSortedMap(compareByDescending { it })
+                .map { (capacity, ids) ->
+                    val sortedIds = ids.sorted()
+                    CpuCluster(
+                        coreIds = sortedIds,
+                        maxFreqMhz = (sortedIds.maxOf { maxFreqKhzByCore[it] ?: 0L } / 1000).toInt(),
+                        clusterType = classifyCapacity(capacity)
+                    )
+                }
+            return CpuTopology(clusters)
+        }
+
+        /**
+         * Group cores into clusters by max frequency and classify them by relative rank
+         *
+         * - 1 cluster: BIG
+         * - 2 clusters: BIG, LITTLE
+         * - 3 clusters: PRIME, BIG, LITTLE if the fastest cluster is small (1-2 cores),
+         *   otherwise BIG, MID, LITTLE
+         * - 4+ clusters: PRIME, BIG, MID..., LITTLE
+         *
+         * @param maxFreqKhzByCore Max frequency (KHz) per core id; cores with 0 are ignored
+         */
+        fun classifyClusters(maxFreqKhzByCore: Map<Int, Long>): CpuTopology {
+            val groups = maxFreqKhzByCore
+                .filterValues { it > 0 }
+                .entries
+                .groupBy({ it.value }, { it.key })
+                .toSortedMap(compareByDescending { it })
+                .map { (freq, ids) -> Pair(freq, ids.sorted()) }
+
+            val count = groups.size
+            val clusters = groups.mapIndexed { rank, (freq, ids) ->
+                val type = when {
+                    count == 1 -> ClusterType.BIG
+                    rank == count - 1 -> ClusterType.LITTLE
+                    count == 2 -> ClusterType.BIG
+                    count == 3 && groups[0].second.size > PRIME_CLUSTER_MAX_CORES ->
+                        if (rank == 0) ClusterType.BIG else ClusterType.MID
+                    rank == 0 -> ClusterType.PRIME
+                    rank == 1 -> ClusterType.BIG
+                    else -> ClusterType.MID
+                }
+                CpuCluster(coreIds = ids, maxFreqMhz = (freq / 1000).toInt(), clusterType = type)
+            }
+            return CpuTopology(clusters)
+        }
     }
     
     /**
@@ -50,8 +147,13 @@ class CpuTopologyDetector {
                 val minFreq = readLongFromFile("$CPU_BASE_PATH/cpu$i/cpufreq/cpuinfo_min_freq")
                 val currentFreq = readLongFromFile("$CPU_BASE_PATH/cpu$i/cpufreq/scaling_cur_freq")
                 
-                // Classify as big or LITTLE core based on max frequency
-                val isBigCore = maxFreq > BIG_CORE_THRESHOLD_KHZ
+                // Classify as big or LITTLE core, preferring the kernel capacity hint
+                val capacity = readCoreCapacity(i)
+                val isBigCore = if (capacity != null) {
+                    capacity >= BIG_CAPACITY
+                } else {
+                    maxFreq > BIG_CORE_THRESHOLD_KHZ
+                }
                 
                 val core = CpuCore(
                     id = i,
@@ -94,6 +196,84 @@ class CpuTopologyDetector {
             .map { it.id }
     }
     
+    /**
+     * Kernel capacity hint (0-1024) for each core that exposes cpu_capacity
+     *
+     * @return (core id, capacity) pairs; empty on kernels without the file
+     */
+    fun detectCoreCapacities(): List<Pair<Int, Long>> {
+        val numCores = Runtime.getRuntime().availableProcessors()
+        return (0 until numCores).mapNotNull { i -> readCoreCapacity(i)?.let { i to it } }
+    }
+
+    /**
+     * Detect CPU clusters (PRIME / BIG / MID / LITTLE)
+     *
+     * Uses the kernel cpu_capacity hint when every core exposes it, since max frequency alone
+     * misranks some SoCs. Otherwise clusters are ranked by sysfs max frequency relative to each
+     * other instead of using a fixed threshold, so tri-cluster SoCs are still classified correctly.
+     */
+    fun detectClusters(): CpuTopology {
+        val numCores = Runtime.getRuntime().availableProcessors()
+        val maxFreqs = (0 until numCores).associateWith { i ->
+            readLongFromFile("$CPU_BASE_PATH/cpu$i/cpufreq/cpuinfo_max_freq")
+        }
+        val capacities = detectCoreCapacities()
+        val topology = if (capacities.isNotEmpty() && capacities.size == numCores) {
+            classifyClustersByCapacity(capacities.toMap(), maxFreqs)
+        } else {
+            classifyClusters(maxFreqs)
+        }
+        topology.clusters.forEach { cluster ->
+            Log.d(TAG, "Cluster ${cluster.clusterType}: cores ${cluster.coreIds}, Max: ${cluster.maxFreqMhz}MHz")
+        }
+        return topology
+    }
+
+    /**
+     * Cluster topology as JSON: {"clusters": [{"core_ids": [...], "max_freq_mhz": N, "cluster_type": "BIG"}, ...]}
+     */
+    fun getCpuTopologyJson(): String {
+        val clusters = JSONArray()
+        detectClusters().clusters.forEach { cluster ->
+            clusters.put(JSONObject().apply {
+                put("core_ids", JSONArray(cluster.coreIds))
+                put("max_freq_mhz", cluster.maxFreqMhz)
+                put("cluster_type", cluster.clusterType.name)
+            })
+        }
+        return JSONObject().apply { put("clusters", clusters) }.toString()
+    }
+    
+    /**
+     * Read the cpufreq governor (e.g. "schedutil", "performance") for one core
+     *
+     * @return Governor name, or null if the core has no readable cpufreq policy
+     */
+    fun detectCpuGovernor(coreId: Int): String? {
+        return try {
+            File("$CPU_BASE_PATH/cpu$coreId/cpufreq/scaling_governor").readText().trim()
+                .takeIf { it.isNotEmpty() }
+        } catch (e: Exception) {
+            null
+        }
+    }
+
+    /** Governors for every core that exposes one, keyed by core id */
+    fun detectAllGovernors(): Map<Int, String> {
+        val numCores = Runtime.getRuntime().availableProcessors()
+        return (0 until numCores)
+            .mapNotNull { i -> detectCpuGovernor(i)?.let { i to it } }
+            .toMap()
+    }
+
+    /** Governors as JSON: {"0": "schedutil", "1": "schedutil", ...} */
+    fun getCpuGovernorsJson(): String {
+        return JSONObject().apply {
+            detectAllGovernors().forEach { (core, governor) -> put(core.toString(), governor) }
+        }.toString()
+    }
+
     /**
      * Read integer value from sysfs file
      */
@@ -106,6 +286,15 @@ class CpuTopologyDetector {
         }
     }
     
+    /** Read cpu_capacity for one core, or null if the kernel doesn't expose it */
+    private fun readCoreCapacity(coreId: Int): Long? {
+        return try {
+            File("$CPU_BASE_PATH/cpu$coreId/cpu_capacity").readText().trim().toLong()
+        } catch (e: Exception) {
+            null
+        }
+    }
+
     /**
      * Read long value from sysfs file
      */
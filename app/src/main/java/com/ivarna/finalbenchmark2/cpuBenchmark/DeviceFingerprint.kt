@@ -0,0 +1,65 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import android.os.Build
+import org.json.JSONObject
+
+/**
+ * Hardware and OS characteristics recorded with every suite run, so stored results can be
+ * traced back to the device they came from and compared like for like
+ *
+ * Frequencies are the cpuinfo limits across all cores (0 when cpufreq is unreadable).
+ */
+data class DeviceFingerprint(
+        val model: String,
+        val coreCount: Int,
+        val bigCoreCount: Int,
+        val maxFreqMhz: Int,
+        val minFreqMhz: Int,
+        val arch: String,
+        val osVersion: String,
+        val kernelVersion: String
+) {
+        fun toJson(): JSONObject =
+                JSONObject().apply {
+                        put("model", model)
+                        put("core_count", coreCount)
+                        put("big_core_count", bigCoreCount)
+                        put("max_freq_mhz", maxFreqMhz)
+                        put("min_freq_mhz", minFreqMhz)
+                        put("arch", arch)
+                        put("os_version", osVersion)
+                        put("kernel_version", kernelVersion)
+                }
+
+        companion object {
+                /** Read the fingerprint of the current device from Build and /sys/devices/system/cpu */
+                fun detect(): DeviceFingerprint {
+                        val cores = CpuTopologyDetector().detectCpuTopology()
+                        val maxFreqs = cores.map { it.maxFreqKhz }.filter { it > 0 }
+                        val minFreqs = cores.map { it.minFreqKhz }.filter { it > 0 }
+                        return DeviceFingerprint(
+                                model = "${Build.MANUFACTURER} ${Build.MODEL}",
+                                coreCount = if (cores.isNotEmpty()) cores.size else Runtime.getRuntime().availableProcessors(),
+                                bigCoreCount = cores.count { it.isBigCore },
+                                maxFreqMhz = ((maxFreqs.maxOrNull() ?: 0L) / 1000).toInt(),
+                                minFreqMhz = ((minFreqs.minOrNull() ?: 0L) / 1000).toInt(),
+                                // Same values as uname -m / uname -r
+                                arch = System.getProperty("os.arch") ?: "unknown",
+                                osVersion = "Android ${Build.VERSION.RELEASE} (API ${Build.VERSION.SDK_INT})",
+                                kernelVersion = System.getProperty("os.version") ?: "unknown"
+                        )
+                }
+
+                fun fromJson(json: JSONObject): DeviceFingerprint =
+                        DeviceFingerprint(
+                                model = json.optString("model"),
+                                coreCount = json.optInt("core_count"),
+                                bigCoreCount = json.optInt("big_core_count"),
+                                maxFreqMhz = json.optInt("max_freq_mhz"),
+                                minFreqMhz = json.optInt("min_freq_mhz"),
+                                arch = json.optString("arch"),
+                                osVersion = json.optString("os_version"),
+                                kernelVersion = json.optString("kernel_version")
+                        )
+        }
+}
@@ -0,0 +1,113 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import android.util.Log
+import java.io.File
+import org.json.JSONObject
+
+/**
+ * Samples per-core CPU frequencies on a background thread while a benchmark runs
+ *
+ * Uses a dedicated daemon thread rather than a coroutine so sampling keeps its cadence even
+ * when every dispatcher thread is busy running benchmark kernels.
+ *
+ * Usage: `val monitor = FrequencyMonitor.start()` ... `val samples = monitor.stop()`
+ */
+class FrequencyMonitor private constructor(private val intervalMs: Long) {
+
+    /** Min / max / mean of the sampled frequency (MHz) for one core */
+    data class CoreFrequencyStats(
+        val minMhz: Int,
+        val maxMhz: Int,
+        val meanMhz: Double,
+        val sampleCount: Int
+    )
+
+    /** Per-core statistics collected between [start] and [stop] */
+    data class FrequencySamples(val cores: Map<Int, CoreFrequencyStats>) {
+        /** {"0": {"min": N, "max": N, "mean": N, "samples": N}, ...} */
+        fun toJson(): JSONObject {
+            return JSONObject().apply {
+                cores.toSortedMap().forEach { (core, stats) ->
+                    put(core.toString(), JSONObject().apply {
+                        put("min", stats.minMhz)
+                        put("max", stats.maxMhz)
+                        put("mean", stats.meanMhz)
+                        put("samples", stats.sampleCount)
+                    })
+                }
+            }
+        }
+    }
+
+    companion object {
+        private const val TAG = "FrequencyMonitor"
+        private const val CPU_BASE_PATH = "/sys/devices/system/cpu"
+        const val DEFAULT_INTERVAL_MS = 100L
+
+        /** Start sampling immediately */
+        fun start(intervalMs: Long = DEFAULT_INTERVAL_MS): FrequencyMonitor {
+            return FrequencyMonitor(intervalMs).also { it.begin() }
+        }
+    }
+
+    private val numCores = Runtime.getRuntime().availableProcessors()
+    private val samples = Array(numCores) { mutableListOf<Int>() }
+
+    @Volatile private var running = false
+    private var thread: Thread? = null
+
+    private fun begin() {
+        running = true
+        thread = Thread({
+            while (running) {
+                sampleOnce()
+                try {
+                    Thread.sleep(intervalMs)
+                } catch (e: InterruptedException) {
+                    break
+                }
+            }
+        }, "FrequencyMonitor").apply {
+            isDaemon = true
+            start()
+        }
+    }
+
+    private fun sampleOnce() {
+        for (i in 0 until numCores) {
+            try {
+                val khz = File("$CPU_BASE_PATH/cpu$i/cpufreq/scaling_cur_freq").readText().trim().toLong()
+                synchronized(samples) { samples[i].add((khz / 1000).toInt()) }
+            } catch (e: Exception) {
+                // Offline cores have no readable cpufreq node; skip them
+            }
+        }
+    }
+
+    /** Stop sampling and return the collected statistics (takes one final sample) */
+    fun stop(): FrequencySamples {
+        running = false
+        thread?.interrupt()
+        try {
+            thread?.join(intervalMs * 2)
+        } catch (e: InterruptedException) {
+            Log.w(TAG, "Interrupted while stopping frequency monitor")
+        }
+        thread = null
+        sampleOnce()
+
+        val stats = synchronized(samples) {
+            samples.withIndex()
+                .filter { it.value.isNotEmpty() }
+                .associate { (core, values) ->
+                    core to CoreFrequencyStats(
+                        minMhz = values.min(),
+                        maxMhz = values.max(),
+                        meanMhz = values.average(),
+                        sampleCount = values.size
+                    )
+                }
+        }
+        return FrequencySamples(stats)
+    }
+}
@@ -4,7 +4,10 @@ import android.util.Log
 import com.ivarna.finalbenchmark2.cpuBenchmark.algorithms.MultiCoreBenchmarks
 import com.ivarna.finalbenchmark2.cpuBenchmark.algorithms.SingleCoreBenchmarks
 import com.ivarna.finalbenchmark2.cpuBenchmark.BenchmarkResult
+import com.ivarna.finalbenchmark2.cpuBenchmark.output.HtmlReport
+import com.ivarna.finalbenchmark2.cpuBenchmark.output.renderIterationHistogram
 import kotlinx.coroutines.Dispatchers
+import kotlinx.coroutines.runBlocking
 import kotlinx.coroutines.flow.MutableSharedFlow
 import kotlinx.coroutines.flow.SharedFlow
 import kotlinx.coroutines.flow.asSharedFlow
@@ -18,6 +21,7 @@ import com.ivarna.finalbenchmark2.aiBenchmark.AiBenchmarkManager
 import com.ivarna.finalbenchmark2.aiBenchmark.ModelRepository
 import com.ivarna.finalbenchmark2.aiBenchmark.AiBenchmarkResult
 import java.io.File
+import kotlin.concurrent.thread
 import java.nio.ByteBuffer
 import java.nio.ByteOrder
 import android.graphics.Bitmap
@@ -32,8 +36,23 @@ class KotlinBenchmarkManager(
         private val _benchmarkComplete = MutableSharedFlow<String>(replay = 1)
         val benchmarkComplete: SharedFlow<String> = _benchmarkComplete.asSharedFlow()
 
+        /** Progress reporting for one run, advanced on each benchmark start */
+        private class RunProgress(
+                val onProgress: ProgressCallback?,
+                val onBenchmarkComplete: BenchmarkCompleteCallback?,
+                val total: Int
+        ) {
+                var index = 0
+        }
+
+        // Per-benchmark CPU frequency samples from the most recent CPU run, keyed by test name
+        private val _lastFrequencySamples = mutableMapOf<String, FrequencyMonitor.FrequencySamples>()
+        val lastFrequencySamples: Map<String, FrequencyMonitor.FrequencySamples>
+                get() = _lastFrequencySamples.toMap()
+
         companion object {
                 private const val TAG = "KotlinBenchmarkManager"
+                private const val CANCELLED_METRICS = "{\"cancelled\": true}"
 
 
         // Reference device: Snapdragon 8 Gen 3 (OnePlus Pad 2)
@@ -143,22 +162,85 @@ class KotlinBenchmarkManager(
                 )
         }
 
-        suspend fun runBenchmarks(deviceTier: String = "Flagship", category: BenchmarkCategory = BenchmarkCategory.CPU) {
+        /**
+         * Run the benchmark suite for [category]
+         *
+         * @param onProgress Optional callback invoked before each benchmark starts with its name,
+         * zero-based index and the total number of benchmarks in the run
+         * @param onBenchmarkComplete Optional callback invoked after each benchmark finishes with
+         * its name, result JSON, zero-based index and the total number of benchmarks in the run
+         * @param weights Optional per-benchmark CPU score weights keyed by result name (see
+         * [validateScoringWeights]); null keeps the default 35% single-core / 65% multi-core split
+         * @throws IllegalArgumentException If [weights] are invalid
+         * @throws BenchmarkError.InvalidParams If the CPU params for [deviceTier] fail
+         * [validateWorkloadParams]
+         */
+        suspend fun runBenchmarks(
+                deviceTier: String = "Flagship",
+                category: BenchmarkCategory = BenchmarkCategory.CPU,
+                onProgress: ProgressCallback? = null,
+                weights: Map<String, Double>? = null,
+                onBenchmarkComplete: BenchmarkCompleteCallback? = null
+        ) {
+                weights?.let {
+                        val errors = validateScoringWeights(it)
+                        require(errors.isEmpty()) { "Invalid scoring weights: ${errors.joinToString()}" }
+                }
+                if (category == BenchmarkCategory.CPU) requireValidWorkloadParams(getWorkloadParams(deviceTier))
+
                 Log.d(
                         TAG,
                         "SINGLE_SOURCE_OF_TRUTH: Starting benchmark execution with device tier: $deviceTier, Category: $category"
                 )
 
+                BenchmarkControl.reset()
+                _lastFrequencySamples.clear()
                 if (category == BenchmarkCategory.AI) {
-                    Log.d(TAG, "Running AI Benchmarks")
-                    runAiBenchmarks(deviceTier)
+                        Log.d(TAG, "Running AI Benchmarks")
+                        val total = BenchmarkName.getByCategory(BenchmarkCategory.AI).size
+                        runAiBenchmarks(deviceTier, RunProgress(onProgress, onBenchmarkComplete, total))
                 } else {
-                    Log.d(TAG, "Running CPU Benchmarks")
-                    runCpuBenchmarks(deviceTier)
+                        Log.d(TAG, "Running CPU Benchmarks")
+                        // Every CPU benchmark runs once single-core and once multi-core
+                        val total = BenchmarkName.getByCategory(BenchmarkCategory.CPU).size * 2
+                        runCpuBenchmarks(deviceTier, weights, RunProgress(onProgress, onBenchmarkComplete, total))
                 }
         }
 
-        private suspend fun runAiBenchmarks(deviceTier: String) {
+        /**
+         * [runBenchmarks] on a new background thread, for callers that can't suspend and must not
+         * block (e.g. the main thread)
+         *
+         * [callback] is invoked on the worker thread once the run finishes; cancel through
+         * [BenchmarkControl] as for a blocking run.
+         *
+         * @return The worker thread, already started
+         */
+        fun runBenchmarksAsync(
+                callback: SuiteCallback,
+                deviceTier: String = "Flagship",
+                category: BenchmarkCategory = BenchmarkCategory.CPU
+        ): Thread =
+                thread(name = "BenchmarkSuite") {
+                        try {
+                                val previous = _benchmarkComplete.replayCache.lastOrNull()
+                                runBlocking {
+                                        runBenchmarks(deviceTier, category, onBenchmarkComplete = callback::onBenchmarkComplete)
+                                }
+                                // A run that emits no summary leaves the previous run's one replayed
+                                val summaryJson = _benchmarkComplete.replayCache.lastOrNull()?.takeIf { it !== previous }
+                                if (summaryJson != null) {
+                                        callback.onComplete(summaryJson)
+                                } else {
+                                        callback.onError("Benchmark run finished without a result")
+                                }
+                        } catch (e: Exception) {
+                                Log.e(TAG, "Async benchmark run failed: ${e.message}", e)
+                                callback.onError(e.message ?: e.toString())
+                        }
+                }
+
+        private suspend fun runAiBenchmarks(deviceTier: String, progress: RunProgress) {
              // Placeholder for AI benchmarks
              // Simulate work for now to prevent crashes until actual implementation
              val categoryName = BenchmarkCategory.AI.name
@@ -212,9 +294,9 @@ class KotlinBenchmarkManager(
                     // Add skipped result
                     results.add(BenchmarkResult(testName, 0.0, 0.0, false, "{\"error\": \"Not implemented\"}"))
                     // Emit fake start/complete to resolve UI Pending state
-                    emitBenchmarkStart(testName, categoryName)
+                    emitBenchmarkStart(progress, testName, categoryName)
                     delay(100)
-                    emitBenchmarkComplete(testName, categoryName, 0, 0.0) 
+                    emitBenchmarkComplete(progress, testName, categoryName, 0, 0.0) 
                     return@forEachIndexed
                 }
 
@@ -227,13 +309,13 @@ class KotlinBenchmarkManager(
                      // Add skipped result
                      results.add(BenchmarkResult(testName, 0.0, 0.0, false, "{\"error\": \"Model missing\"}"))
                      // Emit fake start/complete to resolve UI Pending state
-                     emitBenchmarkStart(testName, categoryName)
+                     emitBenchmarkStart(progress, testName, categoryName)
                      delay(100)
-                     emitBenchmarkComplete(testName, categoryName, 0, 0.0)
+                     emitBenchmarkComplete(progress, testName, categoryName, 0, 0.0)
                     return@forEachIndexed 
                 }
                  
-                  emitBenchmarkStart(testName, categoryName)
+                  emitBenchmarkStart(progress, testName, categoryName)
                   
                   val startTime = System.currentTimeMillis()
 
@@ -276,10 +358,10 @@ class KotlinBenchmarkManager(
                          val score = result.throughput * multiplier
                         Log.i("FinalBenchmark", "PASS: ${result.modelName} | TPS=${result.throughput} | Mode=${result.accelerationMode}")
                         
-                        emitBenchmarkComplete(testName, categoryName, result.inferenceTimeMs.toLong(), score, result.accelerationMode)
+                        emitBenchmarkComplete(progress, testName, categoryName, result.inferenceTimeMs.toLong(), score, result.accelerationMode)
                     } else {
                         Log.e("FinalBenchmark", "FAIL: ${result.modelName} | Error: ${result.errorMessage}")
-                         emitBenchmarkComplete(testName, categoryName, 0, 0.0)
+                         emitBenchmarkComplete(progress, testName, categoryName, 0, 0.0)
                     }
 
                     results.add(BenchmarkResult(
@@ -322,7 +404,14 @@ class KotlinBenchmarkManager(
              _benchmarkComplete.emit(summaryJson)
         }
 
-        private suspend fun runCpuBenchmarks(deviceTier: String) {
+        private suspend fun runCpuBenchmarks(
+                deviceTier: String,
+                weights: Map<String, Double>?,
+                progress: RunProgress
+        ) {
+                // Spawn the multi-core worker threads before anything is timed
+                val threadPoolWarmupMs = MultiCoreBenchmarks.warmupThreadPool()
+
                 // Run test workload first (warm-up)
                 runTestWorkload()
 
@@ -331,294 +420,362 @@ class KotlinBenchmarkManager(
                 // Log CPU topology
                 CpuAffinityManager.logTopology()
 
+                // Record governors up front so results can be reproduced under the same policy
+                val cpuGovernors = CpuTopologyDetector().detectAllGovernors()
+                Log.i(TAG, "CPU governors: $cpuGovernors")
+
+                val memoryPressure = checkMemoryPressure()
+                val environmentWarnings = checkEnvironment(memoryPressure)
+
                 // Run single-core benchmarks
                 val singleResults = mutableListOf<BenchmarkResult>()
 
                 // Prime Generation
-                emitBenchmarkStart(BenchmarkName.PRIME_GENERATION.singleCore(), "SINGLE")
+                emitBenchmarkStart(progress, BenchmarkName.PRIME_GENERATION.singleCore(), "SINGLE")
                 val singlePrimeResult =
                         safeBenchmarkRun(BenchmarkName.PRIME_GENERATION.singleCore()) {
                                 SingleCoreBenchmarks.primeGeneration(params)
                         }
                 singleResults.add(singlePrimeResult)
                 emitBenchmarkComplete(
+                        progress,
                         BenchmarkName.PRIME_GENERATION.singleCore(),
                         "SINGLE",
                         singlePrimeResult.executionTimeMs.toLong(),
-                        singlePrimeResult.opsPerSecond
+                        singlePrimeResult.opsPerSecond,
+                        result = singlePrimeResult
                 )
 
                 // Fibonacci Iterative
-                emitBenchmarkStart(BenchmarkName.FIBONACCI_ITERATIVE.singleCore(), "SINGLE")
+                emitBenchmarkStart(progress, BenchmarkName.FIBONACCI_ITERATIVE.singleCore(), "SINGLE")
                 val singleFibResult =
                         safeBenchmarkRun(BenchmarkName.FIBONACCI_ITERATIVE.singleCore()) {
                                 SingleCoreBenchmarks.fibonacciRecursive(params)
                         }
                 singleResults.add(singleFibResult)
                 emitBenchmarkComplete(
+                        progress,
                         BenchmarkName.FIBONACCI_ITERATIVE.singleCore(),
                         "SINGLE",
                         singleFibResult.executionTimeMs.toLong(),
-                        singleFibResult.opsPerSecond
+                        singleFibResult.opsPerSecond,
+                        result = singleFibResult
                 )
 
                 // Matrix Multiplication
-                emitBenchmarkStart(BenchmarkName.MATRIX_MULTIPLICATION.singleCore(), "SINGLE")
+                emitBenchmarkStart(progress, BenchmarkName.MATRIX_MULTIPLICATION.singleCore(), "SINGLE")
                 val singleMatrixResult =
                         safeBenchmarkRun(BenchmarkName.MATRIX_MULTIPLICATION.singleCore()) {
                                 SingleCoreBenchmarks.matrixMultiplication(params)
                         }
                 singleResults.add(singleMatrixResult)
                 emitBenchmarkComplete(
+                        progress,
                         BenchmarkName.MATRIX_MULTIPLICATION.singleCore(),
                         "SINGLE",
                         singleMatrixResult.executionTimeMs.toLong(),
-                        singleMatrixResult.opsPerSecond
+                        singleMatrixResult.opsPerSecond,
+                        result = singleMatrixResult
                 )
 
                 // Hash Computing
-                emitBenchmarkStart(BenchmarkName.HASH_COMPUTING.singleCore(), "SINGLE")
+                emitBenchmarkStart(progress, BenchmarkName.HASH_COMPUTING.singleCore(), "SINGLE")
                 val singleHashResult =
                         safeBenchmarkRun(BenchmarkName.HASH_COMPUTING.singleCore()) {
                                 SingleCoreBenchmarks.hashComputing(params)
                         }
                 singleResults.add(singleHashResult)
                 emitBenchmarkComplete(
+                        progress,
                         BenchmarkName.HASH_COMPUTING.singleCore(),
                         "SINGLE",
                         singleHashResult.executionTimeMs.toLong(),
-                        singleHashResult.opsPerSecond
+                        singleHashResult.opsPerSecond,
+                        result = singleHashResult
                 )
 
                 // String Sorting
-                emitBenchmarkStart(BenchmarkName.STRING_SORTING.singleCore(), "SINGLE")
+                emitBenchmarkStart(progress, BenchmarkName.STRING_SORTING.singleCore(), "SINGLE")
                 val singleStringResult =
                         safeBenchmarkRun(BenchmarkName.STRING_SORTING.singleCore()) {
                                 SingleCoreBenchmarks.stringSorting(params)
                         }
                 singleResults.add(singleStringResult)
                 emitBenchmarkComplete(
+                        progress,
                         BenchmarkName.STRING_SORTING.singleCore(),
                         "SINGLE",
                         singleStringResult.executionTimeMs.toLong(),
-                        singleStringResult.opsPerSecond
+                        singleStringResult.opsPerSecond,
+                        result = singleStringResult
                 )
 
                 // Ray Tracing
-                emitBenchmarkStart(BenchmarkName.RAY_TRACING.singleCore(), "SINGLE")
+                emitBenchmarkStart(progress, BenchmarkName.RAY_TRACING.singleCore(), "SINGLE")
                 val singleRayResult =
                         safeBenchmarkRun(BenchmarkName.RAY_TRACING.singleCore()) {
                                 SingleCoreBenchmarks.rayTracing(params)
                         }
                 singleResults.add(singleRayResult)
                 emitBenchmarkComplete(
+                        progress,
                         BenchmarkName.RAY_TRACING.singleCore(),
                         "SINGLE",
                         singleRayResult.executionTimeMs.toLong(),
-                        singleRayResult.opsPerSecond
+                        singleRayResult.opsPerSecond,
+                        result = singleRayResult
                 )
 
                 // Compression
-                emitBenchmarkStart(BenchmarkName.COMPRESSION.singleCore(), "SINGLE")
+                emitBenchmarkStart(progress, BenchmarkName.COMPRESSION.singleCore(), "SINGLE")
                 val singleCompressionResult =
                         safeBenchmarkRun(BenchmarkName.COMPRESSION.singleCore()) {
                                 SingleCoreBenchmarks.compression(params)
                         }
                 singleResults.add(singleCompressionResult)
                 emitBenchmarkComplete(
+                        progress,
                         BenchmarkName.COMPRESSION.singleCore(),
                         "SINGLE",
                         singleCompressionResult.executionTimeMs.toLong(),
-                        singleCompressionResult.opsPerSecond
+                        singleCompressionResult.opsPerSecond,
+                        result = singleCompressionResult
                 )
 
                 // Monte Carlo Pi
-                emitBenchmarkStart(BenchmarkName.MONTE_CARLO.singleCore(), "SINGLE")
+                emitBenchmarkStart(progress, BenchmarkName.MONTE_CARLO.singleCore(), "SINGLE")
                 val singleMonteResult =
                         safeBenchmarkRun(BenchmarkName.MONTE_CARLO.singleCore()) {
                                 SingleCoreBenchmarks.monteCarloPi(params)
                         }
                 singleResults.add(singleMonteResult)
                 emitBenchmarkComplete(
+                        progress,
                         BenchmarkName.MONTE_CARLO.singleCore(),
                         "SINGLE",
                         singleMonteResult.executionTimeMs.toLong(),
-                        singleMonteResult.opsPerSecond
+                        singleMonteResult.opsPerSecond,
+                        result = singleMonteResult
                 )
 
                 // JSON Parsing
-                emitBenchmarkStart(BenchmarkName.JSON_PARSING.singleCore(), "SINGLE")
+                emitBenchmarkStart(progress, BenchmarkName.JSON_PARSING.singleCore(), "SINGLE")
                 val singleJsonResult =
                         safeBenchmarkRun(BenchmarkName.JSON_PARSING.singleCore()) {
                                 SingleCoreBenchmarks.jsonParsing(params)
                         }
                 singleResults.add(singleJsonResult)
                 emitBenchmarkComplete(
+                        progress,
                         BenchmarkName.JSON_PARSING.singleCore(),
                         "SINGLE",
                         singleJsonResult.executionTimeMs.toLong(),
-                        singleJsonResult.opsPerSecond
+                        singleJsonResult.opsPerSecond,
+                        result = singleJsonResult
                 )
 
                 // N-Queens
-                emitBenchmarkStart(BenchmarkName.N_QUEENS.singleCore(), "SINGLE")
+                emitBenchmarkStart(progress, BenchmarkName.N_QUEENS.singleCore(), "SINGLE")
                 val singleNqueensResult =
                         safeBenchmarkRun(BenchmarkName.N_QUEENS.singleCore()) {
                                 SingleCoreBenchmarks.nqueens(params)
                         }
                 singleResults.add(singleNqueensResult)
                 emitBenchmarkComplete(
+                        progress,
                         BenchmarkName.N_QUEENS.singleCore(),
                         "SINGLE",
                         singleNqueensResult.executionTimeMs.toLong(),
-                        singleNqueensResult.opsPerSecond
+                        singleNqueensResult.opsPerSecond,
+                        result = singleNqueensResult
                 )
 
                 // Run multi-core benchmarks
                 val multiResults = mutableListOf<BenchmarkResult>()
 
                 // Prime Generation
-                emitBenchmarkStart(BenchmarkName.PRIME_GENERATION.multiCore(), "MULTI")
+                emitBenchmarkStart(progress, BenchmarkName.PRIME_GENERATION.multiCore(), "MULTI")
                 val multiPrimeResult =
                         safeBenchmarkRun(BenchmarkName.PRIME_GENERATION.multiCore()) {
                                 MultiCoreBenchmarks.primeGeneration(params)
                         }
                 multiResults.add(multiPrimeResult)
                 emitBenchmarkComplete(
+                        progress,
                         BenchmarkName.PRIME_GENERATION.multiCore(),
                         "MULTI",
                         multiPrimeResult.executionTimeMs.toLong(),
-                        multiPrimeResult.opsPerSecond
+                        multiPrimeResult.opsPerSecond,
+                        result = multiPrimeResult
                 )
 
                 // Fibonacci Iterative
-                emitBenchmarkStart(BenchmarkName.FIBONACCI_ITERATIVE.multiCore(), "MULTI")
+                emitBenchmarkStart(progress, BenchmarkName.FIBONACCI_ITERATIVE.multiCore(), "MULTI")
                 val multiFibResult =
                         safeBenchmarkRun(BenchmarkName.FIBONACCI_ITERATIVE.multiCore()) {
                                 MultiCoreBenchmarks.fibonacciRecursive(params)
                         }
                 multiResults.add(multiFibResult)
                 emitBenchmarkComplete(
+                        progress,
                         BenchmarkName.FIBONACCI_ITERATIVE.multiCore(),
                         "MULTI",
                         multiFibResult.executionTimeMs.toLong(),
-                        multiFibResult.opsPerSecond
+                        multiFibResult.opsPerSecond,
+                        result = multiFibResult
                 )
 
                 // Matrix Multiplication
-                emitBenchmarkStart(BenchmarkName.MATRIX_MULTIPLICATION.multiCore(), "MULTI")
+                emitBenchmarkStart(progress, BenchmarkName.MATRIX_MULTIPLICATION.multiCore(), "MULTI")
                 val multiMatrixResult =
                         safeBenchmarkRun(BenchmarkName.MATRIX_MULTIPLICATION.multiCore()) {
                                 MultiCoreBenchmarks.matrixMultiplication(params)
                         }
                 multiResults.add(multiMatrixResult)
                 emitBenchmarkComplete(
+                        progress,
                         BenchmarkName.MATRIX_MULTIPLICATION.multiCore(),
                         "MULTI",
                         multiMatrixResult.executionTimeMs.toLong(),
-                        multiMatrixResult.opsPerSecond
+                        multiMatrixResult.opsPerSecond,
+                        result = multiMatrixResult
                 )
 
                 // Hash Computing
-                emitBenchmarkStart(BenchmarkName.HASH_COMPUTING.multiCore(), "MULTI")
+                emitBenchmarkStart(progress, BenchmarkName.HASH_COMPUTING.multiCore(), "MULTI")
                 val multiHashResult =
                         safeBenchmarkRun(BenchmarkName.HASH_COMPUTING.multiCore()) {
                                 MultiCoreBenchmarks.hashComputing(params)
                         }
                 multiResults.add(multiHashResult)
                 emitBenchmarkComplete(
+                        progress,
                         BenchmarkName.HASH_COMPUTING.multiCore(),
                         "MULTI",
                         multiHashResult.executionTimeMs.toLong(),
-                        multiHashResult.opsPerSecond
+                        multiHashResult.opsPerSecond,
+                        result = multiHashResult
                 )
 
                 // String Sorting
-                emitBenchmarkStart(BenchmarkName.STRING_SORTING.multiCore(), "MULTI")
+                emitBenchmarkStart(progress, BenchmarkName.STRING_SORTING.multiCore(), "MULTI")
                 val multiStringResult =
                         safeBenchmarkRun(BenchmarkName.STRING_SORTING.multiCore()) {
                                 MultiCoreBenchmarks.stringSorting(params)
                         }
                 multiResults.add(multiStringResult)
                 emitBenchmarkComplete(
+                        progress,
                         BenchmarkName.STRING_SORTING.multiCore(),
                         "MULTI",
                         multiStringResult.executionTimeMs.toLong(),
-                        multiStringResult.opsPerSecond
+                        multiStringResult.opsPerSecond,
+                        result = multiStringResult
                 )
 
                 // Ray Tracing
-                emitBenchmarkStart(BenchmarkName.RAY_TRACING.multiCore(), "MULTI")
+                emitBenchmarkStart(progress, BenchmarkName.RAY_TRACING.multiCore(), "MULTI")
                 val multiRayResult =
                         safeBenchmarkRun(BenchmarkName.RAY_TRACING.multiCore()) {
                                 MultiCoreBenchmarks.rayTracing(params)
                         }
                 multiResults.add(multiRayResult)
                 emitBenchmarkComplete(
+                        progress,
                         BenchmarkName.RAY_TRACING.multiCore(),
                         "MULTI",
                         multiRayResult.executionTimeMs.toLong(),
-                        multiRayResult.opsPerSecond
+                        multiRayResult.opsPerSecond,
+                        result = multiRayResult
                 )
 
                 // Compression
-                emitBenchmarkStart(BenchmarkName.COMPRESSION.multiCore(), "MULTI")
+                emitBenchmarkStart(progress, BenchmarkName.COMPRESSION.multiCore(), "MULTI")
                 val multiCompressionResult =
                         safeBenchmarkRun(BenchmarkName.COMPRESSION.multiCore()) {
                                 MultiCoreBenchmarks.compression(params)
                         }
                 multiResults.add(multiCompressionResult)
                 emitBenchmarkComplete(
+                        progress,
                         BenchmarkName.COMPRESSION.multiCore(),
                         "MULTI",
                         multiCompressionResult.executionTimeMs.toLong(),
-                        multiCompressionResult.opsPerSecond
+                        multiCompressionResult.opsPerSecond,
+                        result = multiCompressionResult
                 )
 
                 // Monte Carlo Pi
-                emitBenchmarkStart(BenchmarkName.MONTE_CARLO.multiCore(), "MULTI")
+                emitBenchmarkStart(progress, BenchmarkName.MONTE_CARLO.multiCore(), "MULTI")
                 val multiMonteResult =
                         safeBenchmarkRun(BenchmarkName.MONTE_CARLO.multiCore()) {
                                 MultiCoreBenchmarks.monteCarloPi(params)
                         }
                 multiResults.add(multiMonteResult)
                 emitBenchmarkComplete(
+                        progress,
                         BenchmarkName.MONTE_CARLO.multiCore(),
                         "MULTI",
                         multiMonteResult.executionTimeMs.toLong(),
-                        multiMonteResult.opsPerSecond
+                        multiMonteResult.opsPerSecond,
+                        result = multiMonteResult
                 )
 
                 // JSON Parsing
-                emitBenchmarkStart(BenchmarkName.JSON_PARSING.multiCore(), "MULTI")
+                emitBenchmarkStart(progress, BenchmarkName.JSON_PARSING.multiCore(), "MULTI")
                 val multiJsonResult =
                         safeBenchmarkRun(BenchmarkName.JSON_PARSING.multiCore()) {
                                 MultiCoreBenchmarks.jsonParsing(params)
                         }
                 multiResults.add(multiJsonResult)
                 emitBenchmarkComplete(
+                        progress,
                         BenchmarkName.JSON_PARSING.multiCore(),
                         "MULTI",
                         multiJsonResult.executionTimeMs.toLong(),
-                        multiJsonResult.opsPerSecond
+                        multiJsonResult.opsPerSecond,
+                        result = multiJsonResult
                 )
 
                 // N-Queens
-                emitBenchmarkStart(BenchmarkName.N_QUEENS.multiCore(), "MULTI")
+                emitBenchmarkStart(progress, BenchmarkName.N_QUEENS.multiCore(), "MULTI")
                 val multiNqueensResult =
                         safeBenchmarkRun(BenchmarkName.N_QUEENS.multiCore()) {
                                 MultiCoreBenchmarks.nqueens(params)
                         }
                 multiResults.add(multiNqueensResult)
                 emitBenchmarkComplete(
+                        progress,
                         BenchmarkName.N_QUEENS.multiCore(),
                         "MULTI",
                         multiNqueensResult.executionTimeMs.toLong(),
-                        multiNqueensResult.opsPerSecond
+                        multiNqueensResult.opsPerSecond,
+                        result = multiNqueensResult
                 )
 
+                // Cancelled run: only report benchmarks that actually ran
+                val cancelled = BenchmarkControl.isCancelled()
+                if (cancelled) {
+                        singleResults.removeAll { it.metricsJson == CANCELLED_METRICS }
+                        multiResults.removeAll { it.metricsJson == CANCELLED_METRICS }
+                        Log.d(
+                                TAG,
+                                "Suite cancelled after ${singleResults.size + multiResults.size} benchmarks"
+                        )
+                }
+
                 // Calculate and emit final results
-                val summaryJson = calculateSummary(singleResults, multiResults)
+                val summaryJson =
+                        calculateSummary(
+                                singleResults,
+                                multiResults,
+                                weights,
+                                cancelled,
+                                cpuGovernors,
+                                threadPoolWarmupMs,
+                                memoryPressure,
+                                environmentWarnings
+                        )
                 Log.d(TAG, "SINGLE_SOURCE_OF_TRUTH: Generated summary JSON: $summaryJson")
                 Log.d(
                         TAG,
@@ -628,20 +785,388 @@ class KotlinBenchmarkManager(
                 Log.d(TAG, "SINGLE_SOURCE_OF_TRUTH: Completion signal emitted successfully")
         }
 
+        /**
+         * Run one CPU benchmark in sustained mode for [durationSecs]
+         *
+         * @param benchmarkName Registered name, e.g. "Single-Core Prime Generation"
+         * @param deviceTier Workload tier for each repetition; small tiers give finer resolution
+         * @return Result JSON (name, opsPerSecond, executionTimeMs, isValid, metricsJson)
+         */
+        suspend fun runSustainedBenchmark(
+                benchmarkName: String,
+                durationSecs: Double,
+                deviceTier: String = "test"
+        ): String {
+                val benchmark = BenchmarkRegistry.find(benchmarkName)
+                val result =
+                        if (benchmark == null) {
+                                Log.e(TAG, "Unknown benchmark for sustained run: $benchmarkName")
+                                BenchmarkResult(
+                                        name = benchmarkName,
+                                        executionTimeMs = 0.0,
+                                        opsPerSecond = 0.0,
+                                        isValid = false,
+                                        metricsJson = "{\"error\": \"Unknown benchmark\"}"
+                                )
+                        } else {
+                                BenchmarkControl.reset()
+                                SustainedBenchmarkRunner.run(
+                                        benchmarkName,
+                                        durationSecs,
+                                        getWorkloadParams(deviceTier),
+                                        benchmark
+                                )
+                        }
+
+                return resultToJson(result)
+        }
+
+        private fun resultToJson(result: BenchmarkResult): String =
+                JSONObject()
+                        .apply {
+                                put("name", result.name)
+                                put("opsPerSecond", result.opsPerSecond)
+                                put("executionTimeMs", result.executionTimeMs)
+                                put("isValid", result.isValid)
+                                put("metricsJson", result.metricsJson)
+                        }
+                        .toString()
+
+        /**
+         * Run any registered CPU benchmark by name with JSON workload parameters
+         *
+         * @param name Registered name, e.g. "Multi-Core FFT" (see [BenchmarkRegistry.names])
+         * @param paramsJson WorkloadParams JSON; missing keys use the Mid-tier defaults
+         * @param threads When set, multi-core benchmarks run on a private pool of this many threads
+         * that is closed afterwards, instead of the pool shared with other callers
+         * @return Result JSON (name, opsPerSecond, executionTimeMs, isValid, metricsJson), or
+         * {"error": ...} for unknown names or malformed parameters
+         */
+        suspend fun runBenchmarkByName(name: String, paramsJson: String, threads: Int? = null): String {
+                val params =
+                        try {
+                                WorkloadParams.fromJson(paramsJson)
+                        } catch (e: Exception) {
+                                Log.e(TAG, "Invalid workload params for $name: ${e.message}")
+                                return JSONObject().apply { put("error", "Invalid params: ${e.message}") }.toString()
+                        }
+
+                val pool = threads?.let { MultiCoreBenchmarks.buildBenchmarkPool(it) }
+                val result =
+                        try {
+                                BenchmarkRegistry.runBenchmarkByName(name, params, pool = pool)
+                        } finally {
+                                pool?.close()
+                        }
+                return result
+                        .fold(
+                                onSuccess = { result -> resultToJson(result) },
+                                onFailure = { e ->
+                                        Log.e(TAG, "runBenchmarkByName failed: ${e.message}")
+                                        JSONObject().apply { put("error", e.message) }.toString()
+                                }
+                        )
+        }
+
+        /**
+         * Run only the named benchmarks, e.g. hash and compression for a storage-focused app
+         *
+         * Scores cover only the scored suite benchmarks among [namesJson], with their default
+         * weights rescaled to sum to 1; other benchmarks are reported but not scored. Benchmarks
+         * not requested are absent from detailed_results. Stops early if cancelled through
+         * [BenchmarkControl].
+         *
+         * @param namesJson JSON array of registered names (see [BenchmarkRegistry.names])
+         * @param paramsJson WorkloadParams JSON; missing keys use the Mid-tier defaults
+         * @return Summary JSON in the [benchmarkComplete] format with "partial": true, or
+         * {"error": ...} for unknown names, malformed input or invalid params
+         */
+        suspend fun runPartialSuite(namesJson: String, paramsJson: String): String {
+                val names: List<String>
+                val params: WorkloadParams
+                try {
+                        val array = JSONArray(namesJson)
+                        names = (0 until array.length()).map { array.getString(it).trim() }
+                        params = WorkloadParams.fromJson(paramsJson)
+                } catch (e: Exception) {
+                        Log.e(TAG, "Invalid partial suite request: ${e.message}")
+                        return JSONObject().apply { put("error", "Invalid request: ${e.message}") }.toString()
+                }
+                val unknown = names.filter { BenchmarkRegistry.metadata(it) == null }
+                if (unknown.isNotEmpty()) {
+                        return JSONObject().apply { put("error", "Unknown benchmarks: ${unknown.joinToString()}") }.toString()
+                }
+                val errors = validateWorkloadParams(params)
+                if (errors.isNotEmpty()) {
+                        return JSONObject().apply { put("error", BenchmarkError.InvalidParams(errors).message) }.toString()
+                }
+
+                BenchmarkControl.reset()
+                val memoryPressure = checkMemoryPressure()
+                val environmentWarnings = checkEnvironment(memoryPressure)
+                val singleResults = mutableListOf<BenchmarkResult>()
+                val multiResults = mutableListOf<BenchmarkResult>()
+                for (name in names.distinct()) {
+                        if (BenchmarkControl.isCancelled()) break
+                        val result =
+                                BenchmarkRegistry.runBenchmarkByName(name, params).getOrElse { e ->
+                                        Log.e(TAG, "✗ $name failed: ${e.message}")
+                                        BenchmarkResult(name, 0.0, 0.0, false, JSONObject().put("error", e.message).toString())
+                                }
+                        if (BenchmarkRegistry.metadata(name)?.coreMode == BenchmarkCoreMode.SINGLE_CORE) {
+                                singleResults.add(result)
+                        } else {
+                                multiResults.add(result)
+                        }
+                }
+
+                // Rescale the included suite weights to sum to 1 and score through the custom-weight path
+                val included = defaultScoringWeights().filterKeys { it in names }
+                val includedWeight = included.values.sum()
+                val weights = if (includedWeight > 0.0) included.mapValues { it.value / includedWeight } else emptyMap()
+                val summaryJson =
+                        calculateSummary(
+                                singleResults,
+                                multiResults,
+                                weights,
+                                BenchmarkControl.isCancelled(),
+                                memoryPressure = memoryPressure,
+                                environmentWarnings = environmentWarnings
+                        )
+                return JSONObject(summaryJson).put("partial", true).toString()
+        }
+
+        /**
+         * Run a thermal throttling probe (Single-Core Monte Carlo for [testSecs])
+         *
+         * @return ThermalReport JSON (initial/final ops per second, throttle ratio, detected flag)
+         */
+        suspend fun detectThermalThrottling(testSecs: Double, deviceTier: String = "test"): String {
+                BenchmarkControl.reset()
+                return SustainedBenchmarkRunner.measureThermalCliff(getWorkloadParams(deviceTier), testSecs)
+                        .toJson()
+                        .toString()
+        }
+
+        /**
+         * Time stable, unstable and radix sorts on the same keys (stringSortCount for [deviceTier])
+         *
+         * @return SortingComparisonResult JSON (stable_sort_ns, unstable_sort_ns, radix_sort_ns, count)
+         */
+        suspend fun runSortingComparison(deviceTier: String = "mid"): String =
+                SingleCoreBenchmarks.sortingComparison(getWorkloadParams(deviceTier)).toJson().toString()
+
+        /**
+         * Pick a workload for a device that fits none of the tiers, by probing each scored
+         * benchmark at 1% of Mid and extrapolating (clamped between Test and Flagship)
+         *
+         * @return WorkloadParams JSON, usable with [runBenchmarkByName]
+         */
+        suspend fun autoSizeWorkload(targetSecsPerBenchmark: Double): String {
+                BenchmarkControl.reset()
+                return WorkloadParams.forDurationTarget(
+                                targetSecsPerBenchmark,
+                                maximum = getWorkloadParams("flagship"),
+                                minimum = getWorkloadParams("test")
+                        )
+                        .toJson()
+        }
+
+        /**
+         * Run the scored suite within [budgetSecs] of wall-clock time, cheapest benchmarks first,
+         * skipping whatever would not fit (see [BenchmarkScheduler])
+         *
+         * Each benchmark is first timed once on the test tier, which doubles as the warm-up; its
+         * estimate for [deviceTier] is that time times the tier's iteration ratio to the test
+         * tier. Probe time counts against the budget.
+         *
+         * @return SchedulerResult JSON (budget_secs, elapsed_secs, skipped_count, benchmarks), or
+         * {"error": ...} if the params for [deviceTier] are invalid
+         */
+        suspend fun runSuiteWithTimeBudget(budgetSecs: Double, deviceTier: String = "mid"): String {
+                val params = getWorkloadParams(deviceTier)
+                val errors = validateWorkloadParams(params)
+                if (errors.isNotEmpty()) {
+                        Log.e(TAG, "Invalid workload params for tier $deviceTier: ${errors.joinToString()}")
+                        return JSONObject().apply { put("error", BenchmarkError.InvalidParams(errors).message) }.toString()
+                }
+
+                BenchmarkControl.reset()
+                val startNs = System.nanoTime()
+                val testParams = getWorkloadParams("test")
+                val tierMultiplier = testTierMultiplier(deviceTier)
+
+                val suite = BenchmarkRegistry.listAvailableBenchmarks().filter { it.defaultWeight > 0.0 }
+                val estimates = linkedMapOf<String, Double>()
+                for (metadata in suite) {
+                        if (BenchmarkControl.isCancelled()) break
+                        val probe = BenchmarkRegistry.runBenchmarkByName(metadata.name, testParams, isTestRun = true)
+                        val probeSecs = probe.getOrNull()?.executionTimeMs?.div(1000.0) ?: 0.0
+                        estimates[metadata.name] = probeSecs * tierMultiplier
+                }
+
+                val probeSecs = (System.nanoTime() - startNs) / 1_000_000_000.0
+                Log.i(TAG, "Time budget ${budgetSecs}s, probes took ${probeSecs}s")
+
+                val scheduler = BenchmarkScheduler((budgetSecs - probeSecs).coerceAtLeast(Double.MIN_VALUE))
+                for ((name, estimate) in estimates) {
+                        scheduler.add(
+                                name,
+                                { params, isTestRun, pool ->
+                                        BenchmarkRegistry.runBenchmarkByName(name, params, isTestRun, pool).getOrThrow()
+                                },
+                                estimate
+                        )
+                }
+
+                val result = scheduler.runAll(params)
+                Log.i(TAG, "Time-budgeted suite: ${result.skippedCount} of ${suite.size} benchmarks skipped")
+                return result.toJson().toString()
+        }
+
+        // Iteration counts per tier relative to the test tier (test = 0.1x slow, slow = 0.25x mid,
+        // mid = 0.5x flagship). Data sizes are the same across tiers except the N-Queens board,
+        // whose estimate is therefore low on Mid and Flagship
+        private fun testTierMultiplier(deviceTier: String): Double =
+                when (deviceTier.lowercase()) {
+                        "test" -> 1.0
+                        "slow" -> 10.0
+                        "flagship" -> 80.0
+                        else -> 40.0
+                }
+
+        /**
+         * Render a finished suite run as a standalone HTML report for sharing
+         *
+         * @param summaryJson Summary JSON emitted on [benchmarkComplete]
+         */
+        fun exportHtmlReport(summaryJson: String): String =
+                HtmlReport(SuiteResult.fromSummaryJson(summaryJson, deviceInfo())).render()
+
+        /**
+         * Render a finished suite run in Prometheus text format for dashboards tracking scores
+         * across builds or firmware versions
+         *
+         * @param summaryJson Summary JSON emitted on [benchmarkComplete]
+         */
+        fun exportPrometheusMetrics(summaryJson: String, prefix: String = "cpu_benchmark"): String =
+                com.ivarna.finalbenchmark2.cpuBenchmark.output.exportPrometheusMetrics(
+                        SuiteResult.fromSummaryJson(summaryJson),
+                        prefix
+                )
+
+        /**
+         * Compare two finished suite runs benchmark by benchmark, e.g. before and after a
+         * firmware update
+         *
+         * @param baselineJson Summary JSON of the earlier run
+         * @param currentJson Summary JSON of the run to check
+         * @return SuiteDiff JSON (regression_count, per_benchmark), or {"error": ...} if either
+         * summary can't be parsed
+         */
+        fun compareSuiteResults(baselineJson: String, currentJson: String): String =
+                try {
+                        diffResults(SuiteResult.fromSummaryJson(baselineJson), SuiteResult.fromSummaryJson(currentJson))
+                                .toJson()
+                                .toString()
+                } catch (e: Exception) {
+                        Log.e(TAG, "compareSuiteResults failed: ${e.message}")
+                        JSONObject().apply { put("error", e.message) }.toString()
+                }
+
+        /**
+         * Explain which benchmarks contributed most to a finished run's score
+         *
+         * @param suiteResultJson Summary JSON emitted on [benchmarkComplete]
+         * @return ScoreExplanation JSON (contributions, bottleneck, highlight, text), or
+         * {"error": ...} if the summary can't be parsed
+         */
+        fun explainScore(suiteResultJson: String): String =
+                try {
+                        com.ivarna.finalbenchmark2.cpuBenchmark
+                                .explainScore(SuiteResult.fromSummaryJson(suiteResultJson))
+                                .toJson()
+                                .toString()
+                } catch (e: Exception) {
+                        Log.e(TAG, "explainScore failed: ${e.message}")
+                        JSONObject().apply { put("error", e.message) }.toString()
+                }
+
+        /**
+         * Save the most recent summary emitted on [benchmarkComplete] to [path] (see
+         * [saveResults])
+         *
+         * @return False if no run has completed yet or the file couldn't be written
+         */
+        fun saveResults(path: String): Boolean {
+                val summaryJson = _benchmarkComplete.replayCache.lastOrNull()
+                if (summaryJson == null) {
+                        Log.w(TAG, "saveResults: no completed run to save")
+                        return false
+                }
+                return try {
+                        com.ivarna.finalbenchmark2.cpuBenchmark.saveResults(summaryJson, File(path))
+                        true
+                } catch (e: Exception) {
+                        Log.e(TAG, "saveResults failed for $path: ${e.message}")
+                        false
+                }
+        }
+
+        /**
+         * Load a summary saved with [saveResults], e.g. as the baseline for
+         * [compareSuiteResults]
+         *
+         * @return The summary JSON, or {"error": ...} if the file is missing or isn't a summary
+         */
+        fun loadResults(path: String): String =
+                try {
+                        val summaryJson = File(path).readText()
+                        // Parse to reject files that aren't suite summaries
+                        SuiteResult.fromSummaryJson(summaryJson)
+                        summaryJson
+                } catch (e: Exception) {
+                        Log.e(TAG, "loadResults failed for $path: ${e.message}")
+                        JSONObject().apply { put("error", e.message) }.toString()
+                }
+
+        private fun deviceInfo(): Map<String, String> =
+                linkedMapOf(
+                        "Manufacturer" to android.os.Build.MANUFACTURER,
+                        "Model" to android.os.Build.MODEL,
+                        "Android" to "${android.os.Build.VERSION.RELEASE} (API ${android.os.Build.VERSION.SDK_INT})",
+                        "Hardware" to android.os.Build.HARDWARE,
+                        "CPU Cores" to Runtime.getRuntime().availableProcessors().toString()
+                )
+
         private suspend fun safeBenchmarkRun(
                 testName: String,
                 block: suspend () -> BenchmarkResult
         ): BenchmarkResult {
+                // Cancelled suites skip the remaining benchmarks without running them
+                if (BenchmarkControl.isCancelled()) {
+                        Log.d(TAG, "Skipping $testName: suite cancelled")
+                        return cancelledResult(testName)
+                }
+
+                val frequencyMonitor = FrequencyMonitor.start()
                 return try {
                         withContext(Dispatchers.Default) {
-                                val result = block()
+                                val result = withFrequencySamples(testName, block(), frequencyMonitor.stop())
+                                // Cancellation requested mid-run: the result can't be trusted
+                                if (BenchmarkControl.isCancelled()) {
+                                        Log.d(TAG, "$testName finished after cancellation, marking invalid")
+                                        return@withContext result.copy(isValid = false)
+                                }
                                 Log.d(
                                         TAG,
                                         "✓ $testName completed successfully: ${result.opsPerSecond} ops/sec"
                                 )
+                                logIterationHistogram(testName, result)
                                 result
                         }
                 } catch (e: Exception) {
+                        frequencyMonitor.stop()
                         Log.e(TAG, "✗ $testName failed with exception: ${e.message}", e)
                         // Return a dummy result so the benchmark suite can continue
                         BenchmarkResult(
@@ -654,6 +1179,45 @@ class KotlinBenchmarkManager(
                 }
         }
 
+        /** Verbose-log a histogram of raw_iteration_times_ms for benchmarks that record it */
+        private fun logIterationHistogram(testName: String, result: BenchmarkResult) {
+                if (!Log.isLoggable(TAG, Log.VERBOSE)) return
+                val times =
+                        try {
+                                JSONObject(result.metricsJson).optJSONArray("raw_iteration_times_ms")
+                        } catch (e: Exception) {
+                                null
+                        } ?: return
+                val timesMs = (0 until times.length()).map { times.getDouble(it) }
+                Log.v(TAG, "$testName iteration times:\n${renderIterationHistogram(timesMs)}")
+        }
+
+        /** Attach frequency samples to the result metrics as frequency_samples_mhz */
+        private fun withFrequencySamples(
+                testName: String,
+                result: BenchmarkResult,
+                samples: FrequencyMonitor.FrequencySamples
+        ): BenchmarkResult {
+                _lastFrequencySamples[testName] = samples
+                return try {
+                        val metrics = JSONObject(result.metricsJson)
+                        metrics.put("frequency_samples_mhz", samples.toJson())
+                        result.copy(metricsJson = metrics.toString())
+                } catch (e: Exception) {
+                        Log.w(TAG, "Could not attach frequency samples to $testName: ${e.message}")
+                        result
+                }
+        }
+
+        private fun cancelledResult(testName: String): BenchmarkResult =
+                BenchmarkResult(
+                        name = testName,
+                        executionTimeMs = 0.0,
+                        opsPerSecond = 0.0,
+                        isValid = false,
+                        metricsJson = CANCELLED_METRICS
+                )
+
         /**
          * Calculate geometric mean score for benchmark results.
          * 
@@ -704,19 +1268,61 @@ class KotlinBenchmarkManager(
                 return score
         }
 
+        /**
+         * [calculateGeometricMean] with each ratio weighted: score = 100 × exp(Σ wᵢ·ln(ratioᵢ) / Σ wᵢ)
+         *
+         * Results without a weight (or with weight 0) are left out.
+         */
+        private fun calculateWeightedGeometricMean(
+                results: List<BenchmarkResult>,
+                weights: Map<String, Double>
+        ): Double {
+                var weightedLogSum = 0.0
+                var totalWeight = 0.0
+                for (result in results) {
+                        val weight = weights[result.name] ?: continue
+                        if (weight <= 0.0) continue
+                        val refMops = BenchmarkName.fromString(result.name)?.let { REFERENCE_MOPS[it] }
+                        if (refMops == null || refMops <= 0.0) {
+                                Log.w(TAG, "No reference value for ${result.name}, skipping in weighted geometric mean")
+                                continue
+                        }
+                        weightedLogSum += weight * Math.log(result.opsPerSecond / refMops)
+                        totalWeight += weight
+                }
+                if (totalWeight <= 0.0) return 0.0
+
+                val score = Math.exp(weightedLogSum / totalWeight) * 100.0
+                Log.d(TAG, "Weighted geometric mean: total weight $totalWeight, Score=$score")
+                return score
+        }
+
         private fun calculateSummary(
                 singleResults: List<BenchmarkResult>,
-                multiResults: List<BenchmarkResult>
+                multiResults: List<BenchmarkResult>,
+                weights: Map<String, Double>?,
+                cancelled: Boolean = false,
+                cpuGovernors: Map<Int, String> = emptyMap(),
+                threadPoolWarmupMs: Double? = null,
+                memoryPressure: MemoryPressure? = null,
+                environmentWarnings: List<EnvironmentWarning> = emptyList()
         ): String {
                 // Calculate single-core score using geometric mean
-                val calculatedSingleCoreScore = calculateGeometricMean(singleResults)
+                val calculatedSingleCoreScore =
+                        if (weights == null) calculateGeometricMean(singleResults)
+                        else calculateWeightedGeometricMean(singleResults, weights)
 
                 // Calculate multi-core score using geometric mean
-                val calculatedMultiCoreScore = calculateGeometricMean(multiResults)
-
-                // Calculate final weighted score (35% single, 65% multi)
+                val calculatedMultiCoreScore =
+                        if (weights == null) calculateGeometricMean(multiResults)
+                        else calculateWeightedGeometricMean(multiResults, weights)
+
+                // Calculate final weighted score (35% single, 65% multi, unless custom weights
+                // give each side the combined weight of its benchmarks)
+                val singleCoreWeight = weights?.let { w -> singleResults.sumOf { w[it.name] ?: 0.0 } } ?: 0.35
+                val multiCoreWeight = weights?.let { w -> multiResults.sumOf { w[it.name] ?: 0.0 } } ?: 0.65
                 val calculatedFinalScore =
-                        (calculatedSingleCoreScore * 0.35) + (calculatedMultiCoreScore * 0.65)
+                        (calculatedSingleCoreScore * singleCoreWeight) + (calculatedMultiCoreScore * multiCoreWeight)
 
                 // Normalize the score to a reasonable range
                 val calculatedNormalizedScore = calculatedFinalScore
@@ -765,35 +1371,11 @@ class KotlinBenchmarkManager(
                         JSONArray().apply {
                                 // Add single core results
                                 singleResults.forEach { result ->
-                                        put(
-                                                JSONObject().apply {
-                                                        put("name", result.name)
-                                                        put("opsPerSecond", result.opsPerSecond)
-                                                        put(
-                                                                "executionTimeMs",
-                                                                result.executionTimeMs
-                                                        )
-                                                        put("isValid", result.isValid)
-                                                        put("metricsJson", result.metricsJson)
-                                                        put("acceleration_mode", result.accelerationMode)
-                                                }
-                                        )
+                                        put(benchmarkResultJson(result))
                                 }
                                 // Add multi core results
                                 multiResults.forEach { result ->
-                                        put(
-                                                JSONObject().apply {
-                                                        put("name", result.name)
-                                                        put("opsPerSecond", result.opsPerSecond)
-                                                        put(
-                                                                "executionTimeMs",
-                                                                result.executionTimeMs
-                                                        )
-                                                        put("isValid", result.isValid)
-                                                        put("metricsJson", result.metricsJson)
-                                                        put("acceleration_mode", result.accelerationMode)
-                                                }
-                                        )
+                                        put(benchmarkResultJson(result))
                                 }
                         }
 
@@ -804,12 +1386,67 @@ class KotlinBenchmarkManager(
                                 put("final_score", calculatedFinalScore)
                                 put("normalized_score", calculatedNormalizedScore)
                                 put("rating", rating)
+                                put("cancelled", cancelled)
+                                put("custom_weights", weights != null)
+                                put("device_fingerprint", DeviceFingerprint.detect().toJson())
+                                threadPoolWarmupMs?.let { put("thread_pool_warmup_ms", it) }
+                                memoryPressure?.let { put("memory_pressure", it.toJson()) }
+                                put("environment_warnings", JSONArray().apply { environmentWarnings.forEach { put(it.toJson()) } })
+                                put(
+                                        "cpu_governors",
+                                        JSONObject().apply {
+                                                cpuGovernors.forEach { (core, governor) ->
+                                                        put(core.toString(), governor)
+                                                }
+                                        }
+                                )
                                 put("detailed_results", detailedResultsArray)
                         }
                         .toString()
         }
 
-        private suspend fun emitBenchmarkStart(testName: String, mode: String) {
+        /** Read memory pressure before a run, warning when it's high enough to skew results */
+        private fun checkMemoryPressure(): MemoryPressure? {
+                val pressure = MemoryPressure.detect() ?: return null
+                Log.i(TAG, "Memory: ${pressure.availableMb} of ${pressure.totalMb} MB available (${pressure.pressureLevel})")
+                if (pressure.pressureLevel == PressureLevel.HIGH) {
+                        Log.w(
+                                TAG,
+                                "High memory pressure: only ${pressure.availableMb} MB available, " +
+                                        "${pressure.swapUsedMb} MB swapped; results may not be comparable"
+                        )
+                }
+                return pressure
+        }
+
+        /** Check the run's environment, logging a warning for each problem found */
+        private fun checkEnvironment(memoryPressure: MemoryPressure?): List<EnvironmentWarning> {
+                val warnings = checkBenchmarkEnvironment(context, memoryPressure)
+                warnings.forEach { Log.w(TAG, "Benchmark environment: ${it.toJson()}; results may not be representative") }
+                return warnings
+        }
+
+        /** One entry of the summary's detailed_results array */
+        private fun benchmarkResultJson(result: BenchmarkResult): JSONObject =
+                JSONObject().apply {
+                        put("name", result.name)
+                        put("opsPerSecond", result.opsPerSecond)
+                        put("executionTimeMs", result.executionTimeMs)
+                        put("isValid", result.isValid)
+                        put("metricsJson", result.metricsJson)
+                        put("acceleration_mode", result.accelerationMode)
+                }
+
+        private suspend fun emitBenchmarkStart(progress: RunProgress, testName: String, mode: String) {
+                progress.onProgress?.let { callback ->
+                        try {
+                                callback(testName, progress.index, progress.total)
+                        } catch (e: Exception) {
+                                Log.w(TAG, "Progress callback failed for $testName: ${e.message}")
+                        }
+                }
+                progress.index++
+
                 _benchmarkEvents.emit(
                         BenchmarkEvent(
                                 testName = testName,
@@ -822,12 +1459,31 @@ class KotlinBenchmarkManager(
         }
 
         private suspend fun emitBenchmarkComplete(
+                progress: RunProgress,
                 testName: String,
                 mode: String,
                 timeMs: Long,
                 score: Double,
-                accelerationMode: String? = null
+                accelerationMode: String? = null,
+                result: BenchmarkResult? = null
         ) {
+                progress.onBenchmarkComplete?.let { callback ->
+                        val resultJson =
+                                result?.let { benchmarkResultJson(it) }
+                                        ?: JSONObject().apply {
+                                                put("name", testName)
+                                                put("executionTimeMs", timeMs)
+                                                put("score", score)
+                                                put("acceleration_mode", accelerationMode)
+                                        }
+                        try {
+                                // emitBenchmarkStart has already advanced the index past this benchmark
+                                callback(testName, resultJson.toString(), progress.index - 1, progress.total)
+                        } catch (e: Exception) {
+                                Log.w(TAG, "Completion callback failed for $testName: ${e.message}")
+                        }
+                }
+
                 _benchmarkEvents.emit(
                         BenchmarkEvent(
                                 testName = testName,
@@ -860,7 +1516,28 @@ class KotlinBenchmarkManager(
                                         monteCarloSamples = 625_000L,  // 0.1x slow
                                         jsonDataSizeMb = 1,
                                         jsonParsingIterations = 50,  // 0.1x slow (rounded from 31.25)
-                                        nqueensSize = 12  // N-Queens: keep same as slow
+                                        nqueensSize = 12,  // N-Queens: keep same as slow
+                                        scanElementCount = 1_000_000,
+                                        scanIterations = 5,
+                                        stringSearchCount = 20_000,
+                                        stringSearchPatternLen = 5,
+                                        stringSearchIterations = 2,
+                                        graphNodeCount = 50_000,
+                                        graphBfsIterations = 2,
+                                        fftSize = 4_096,
+                                        fftIterations = 10,
+                                        aesDataSizeMb = 8,
+                                        regexCorpusSize = 20_000,
+                                        hashChainDepth = 100_000,
+                                        sortElementCount = 200_000,
+                                        levenshteinPairCount = 20_000,
+                                        knapsackItems = 1_000,
+                                        polynomialDegree = 200,
+                                        polynomialEvalPoints = 1_000,
+                                        integerOpsCount = 1_000_000L,
+                                        rayTracingSpp = 2,
+                                        waveletFrameSize = 1_024,
+                                        dedupDuplicateFraction = 0.30
                                 )
                         "slow" ->
                                 WorkloadParams(
@@ -880,7 +1557,28 @@ class KotlinBenchmarkManager(
                                         monteCarloSamples = 6_250_000L,  // 0.25x mid
                                         jsonDataSizeMb = 1,
                                         jsonParsingIterations = 312,  // 0.25x mid (rounded from 312.5)
-                                        nqueensSize = 12  // N-Queens: -1 from mid
+                                        nqueensSize = 12,  // N-Queens: -1 from mid
+                                        scanElementCount = 2_000_000,
+                                        scanIterations = 25,
+                                        stringSearchCount = 100_000,
+                                        stringSearchPatternLen = 5,
+                                        stringSearchIterations = 10,
+                                        graphNodeCount = 250_000,
+                                        graphBfsIterations = 5,
+                                        fftSize = 16_384,
+                                        fftIterations = 100,
+                                        aesDataSizeMb = 64,
+                                        regexCorpusSize = 100_000,
+                                        hashChainDepth = 1_000_000,
+                                        sortElementCount = 2_000_000,
+                                        levenshteinPairCount = 200_000,
+                                        knapsackItems = 3_500,
+                                        polynomialDegree = 1_000,
+                                        polynomialEvalPoints = 5_000,
+                                        integerOpsCount = 50_000_000L,
+                                        rayTracingSpp = 8,
+                                        waveletFrameSize = 4_096,
+                                        dedupDuplicateFraction = 0.50
                                 )
                         "mid" ->
                                 WorkloadParams(
@@ -900,7 +1598,28 @@ class KotlinBenchmarkManager(
                                         monteCarloSamples = 25_000_000L,  // 0.5x flagship
                                         jsonDataSizeMb = 1,
                                         jsonParsingIterations = 1_250,  // 0.5x flagship
-                                        nqueensSize = 15  // N-Queens: -1 from flagship
+                                        nqueensSize = 15,  // N-Queens: -1 from flagship
+                                        scanElementCount = 4_000_000,
+                                        scanIterations = 50,
+                                        stringSearchCount = 200_000,
+                                        stringSearchPatternLen = 5,
+                                        stringSearchIterations = 20,
+                                        graphNodeCount = 500_000,
+                                        graphBfsIterations = 10,
+                                        fftSize = 65_536,
+                                        fftIterations = 100,
+                                        aesDataSizeMb = 128,
+                                        regexCorpusSize = 200_000,
+                                        hashChainDepth = 2_000_000,
+                                        sortElementCount = 4_000_000,
+                                        levenshteinPairCount = 400_000,
+                                        knapsackItems = 5_000,
+                                        polynomialDegree = 1_000,
+                                        polynomialEvalPoints = 10_000,
+                                        integerOpsCount = 100_000_000L,
+                                        rayTracingSpp = 16,
+                                        waveletFrameSize = 4_096,
+                                        dedupDuplicateFraction = 0.30
                                 )
                         "flagship" ->
                                 WorkloadParams(
@@ -945,7 +1664,28 @@ class KotlinBenchmarkManager(
                                         jsonDataSizeMb = 1,
                                         jsonParsingIterations = 2500,  // Reduced 10x for CPU-bound parsing
                                         nqueensSize =
-                                                16 // INCREASED: 14,200 solutions, ~20s (was 10)
+                                                16,  // INCREASED: 14,200 solutions, ~20s (was 10)
+                                        scanElementCount = 8_000_000,
+                                        scanIterations = 50,
+                                        stringSearchCount = 400_000,
+                                        stringSearchPatternLen = 5,
+                                        stringSearchIterations = 20,
+                                        graphNodeCount = 1_000_000,
+                                        graphBfsIterations = 10,
+                                        fftSize = 262_144,
+                                        fftIterations = 50,
+                                        aesDataSizeMb = 256,
+                                        regexCorpusSize = 400_000,
+                                        hashChainDepth = 4_000_000,
+                                        sortElementCount = 8_000_000,
+                                        levenshteinPairCount = 800_000,
+                                        knapsackItems = 7_000,
+                                        polynomialDegree = 1_000,
+                                        polynomialEvalPoints = 20_000,
+                                        integerOpsCount = 200_000_000L,
+                                        rayTracingSpp = 32,
+                                        waveletFrameSize = 4_096,
+                                        dedupDuplicateFraction = 0.10
                                 )
                         else -> WorkloadParams() // Default values
                 }
@@ -0,0 +1,102 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import android.util.Log
+import java.io.File
+import org.json.JSONObject
+
+enum class PressureLevel {
+    LOW,
+    MODERATE,
+    HIGH
+}
+
+/**
+ * System memory state from /proc/meminfo, recorded with each suite run because results from a
+ * device short on memory (reclaim, swapping, killed background work) aren't comparable with
+ * results from an unconstrained one
+ *
+ * Pressure is graded on the share of memory still available: under 10% is high, under 25%
+ * moderate.
+ */
+data class MemoryPressure(
+        val totalMb: Long,
+        val availableMb: Long,
+        val cachedMb: Long,
+        val swapUsedMb: Long,
+        val pressureLevel: PressureLevel
+) {
+        fun toJson(): JSONObject =
+                JSONObject().apply {
+                        put("total_mb", totalMb)
+                        put("available_mb", availableMb)
+                        put("cached_mb", cachedMb)
+                        put("swap_used_mb", swapUsedMb)
+                        put("pressure_level", pressureLevel.name)
+                }
+
+        companion object {
+                private const val TAG = "MemoryPressure"
+
+                const val HIGH_PRESSURE_AVAILABLE_FRACTION = 0.10
+                const val MODERATE_PRESSURE_AVAILABLE_FRACTION = 0.25
+
+                /** Read the current memory state; null if /proc/meminfo can't be read */
+                fun detect(): MemoryPressure? =
+                        try {
+                                parse(File("/proc/meminfo").readText())
+                        } catch (e: Exception) {
+                                Log.w(TAG, "Could not read /proc/meminfo", e)
+                                null
+                        }
+
+                /**
+                 * Parse /proc/meminfo text ("Key:   value kB" lines)
+                 *
+                 * Kernels before 3.14 have no MemAvailable; free + buffers + cached stands in for
+                 * it there.
+                 *
+                 * @return null if MemTotal is missing
+                 */
+                fun parse(meminfo: String): MemoryPressure? {
+                        val kb =
+                                meminfo.lineSequence()
+                                        .mapNotNull { line ->
+                                                val key = line.substringBefore(':', "").trim()
+                                                val value = line.substringAfter(':').trim().substringBefore(' ').toLongOrNull()
+                                                if (key.isEmpty() || value == null) null else key to value
+                                        }
+                                        .toMap()
+                        val totalKb = kb["MemTotal"]?.takeIf { it > 0 } ?: return null
+                        val cachedKb = kb["Cached"] ?: 0L
+                        val availableKb =
+                                kb["MemAvailable"] ?: ((kb["MemFree"] ?: 0L) + (kb["Buffers"] ?: 0L) + cachedKb)
+                        val swapUsedKb = ((kb["SwapTotal"] ?: 0L) - (kb["SwapFree"] ?: 0L)).coerceAtLeast(0L)
+
+                        val availableFraction = availableKb.toDouble() / totalKb
+                        val level =
+                                when {
+                                        availableFraction < HIGH_PRESSURE_AVAILABLE_FRACTION -> PressureLevel.HIGH
+                                        availableFraction < MODERATE_PRESSURE_AVAILABLE_FRACTION -> PressureLevel.MODERATE
+                                        else -> PressureLevel.LOW
+                                }
+                        return MemoryPressure(
+                                totalMb = totalKb / 1024,
+                                availableMb = availableKb / 1024,
+                                cachedMb = cachedKb / 1024,
+                                swapUsedMb = swapUsedKb / 1024,
+                                pressureLevel = level
+                        )
+                }
+
+                fun fromJson(json: JSONObject): MemoryPressure =
+                        MemoryPressure(
+                                totalMb = json.optLong("total_mb"),
+                                availableMb = json.optLong("available_mb"),
+                                cachedMb = json.optLong("cached_mb"),
+                                swapUsedMb = json.optLong("swap_used_mb"),
+                                pressureLevel =
+                                        PressureLevel.values().firstOrNull { it.name == json.optString("pressure_level") }
+                                                ?: PressureLevel.LOW
+                        )
+        }
+}
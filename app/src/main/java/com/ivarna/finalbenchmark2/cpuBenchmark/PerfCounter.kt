@@ -0,0 +1,122 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import android.util.Log
+import java.io.Closeable
+import java.util.EnumMap
+import org.json.JSONObject
+
+/** Hardware events countable through perf_event_open, with their PERF_COUNT_HW_* ids */
+enum class PerfEvent(internal val config: Int) {
+    CYCLES(0),
+    INSTRUCTIONS(1),
+    CACHE_REFERENCES(2),
+    CACHE_MISSES(3)
+}
+
+/**
+ * One hardware performance counter, counting user-space events on the thread that opened it
+ *
+ * Android 10+ lets apps count their own threads without root; older kernels, emulators and
+ * some vendor kernels refuse, in which case [open] fails.
+ */
+class PerfCounter private constructor(val event: PerfEvent, private val fd: Int) : Closeable {
+
+    /** Events counted since [open], or -1 if the read failed */
+    fun read(): Long = PerfCounters.nativeRead(fd)
+
+    override fun close() = PerfCounters.nativeClose(fd)
+
+    companion object {
+        /** Open and start a counter for [event] on the calling thread */
+        fun open(event: PerfEvent): Result<PerfCounter> {
+            if (!PerfCounters.nativeLibraryAvailable) {
+                return Result.failure(IllegalStateException("Native library not available"))
+            }
+            val fd = PerfCounters.nativeOpen(event.config)
+            return if (fd >= 0) {
+                Result.success(PerfCounter(event, fd))
+            } else {
+                Result.failure(IllegalStateException("perf_event_open failed for $event (errno ${-fd})"))
+            }
+        }
+    }
+}
+
+/**
+ * Hardware counter totals over a benchmark's timed sections, for instructions_per_cycle,
+ * cache_miss_rate and the raw counts in its metrics
+ *
+ * Counters follow one thread. Single-core kernels count through
+ * [com.ivarna.finalbenchmark2.cpuBenchmark.algorithms.BenchmarkHelpers.measureBenchmark] inside
+ * their pinned section, so the counts cover the kernel only, not input generation, thermal delays
+ * or other coroutines sharing the dispatcher thread. Multi-core kernels run on pool threads the
+ * counters can't see and aren't counted.
+ */
+class PerfReadings {
+    private val counts = EnumMap<PerfEvent, Long>(PerfEvent::class.java)
+
+    /** Open every counter the device allows on the calling thread (none if it refuses) */
+    fun start(): List<PerfCounter> = PerfEvent.values().mapNotNull { PerfCounter.open(it).getOrNull() }
+
+    /** Read and close [counters] from [start] on the same thread, adding their counts */
+    fun stop(counters: List<PerfCounter>) {
+        counters.forEach { counter ->
+            val value = counter.read()
+            counter.close()
+            if (value >= 0) counts[counter.event] = (counts[counter.event] ?: 0L) + value
+        }
+    }
+
+    internal fun snapshot(): Map<PerfEvent, Long> = counts.toMap()
+}
+
+/** This result with the counts in [perf] added to its metrics; unchanged if nothing was counted */
+fun BenchmarkResult.withCounters(perf: PerfReadings): BenchmarkResult =
+    PerfCounters.withCounterMetrics(this, perf.snapshot())
+
+/** Native perf_event_open bindings behind [PerfCounter] */
+object PerfCounters {
+    private const val TAG = "PerfCounters"
+
+    internal var nativeLibraryAvailable = false
+        private set
+
+    init {
+        try {
+            System.loadLibrary("vulkan_native")
+            nativeLibraryAvailable = true
+        } catch (e: UnsatisfiedLinkError) {
+            Log.w(TAG, "Native library not available, hardware counters disabled", e)
+        }
+    }
+
+    internal external fun nativeOpen(config: Int): Int
+    internal external fun nativeRead(fd: Int): Long
+    internal external fun nativeClose(fd: Int)
+
+    internal fun withCounterMetrics(result: BenchmarkResult, counts: Map<PerfEvent, Long>): BenchmarkResult {
+        if (counts.isEmpty()) return result
+        return try {
+            val metrics = JSONObject(result.metricsJson)
+            counts[PerfEvent.CYCLES]?.let { metrics.put("cycles", it) }
+            counts[PerfEvent.INSTRUCTIONS]?.let { metrics.put("instructions", it) }
+            counts[PerfEvent.CACHE_REFERENCES]?.let { metrics.put("cache_references", it) }
+            counts[PerfEvent.CACHE_MISSES]?.let { metrics.put("cache_misses", it) }
+
+            val cycles = counts[PerfEvent.CYCLES]
+            val instructions = counts[PerfEvent.INSTRUCTIONS]
+            if (cycles != null && instructions != null && cycles > 0) {
+                metrics.put("instructions_per_cycle", instructions.toDouble() / cycles)
+            }
+            val references = counts[PerfEvent.CACHE_REFERENCES]
+            val misses = counts[PerfEvent.CACHE_MISSES]
+            if (references != null && misses != null && references > 0) {
+                metrics.put("cache_miss_rate", misses.toDouble() / references)
+            }
+            result.copy(metricsJson = metrics.toString())
+        } catch (e: Exception) {
+            Log.w(TAG, "Could not attach hardware counters to ${result.name}: ${e.message}")
+            result
+        }
+    }
+}
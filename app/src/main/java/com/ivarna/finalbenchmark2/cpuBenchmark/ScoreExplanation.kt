@@ -0,0 +1,111 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import org.json.JSONArray
+import org.json.JSONObject
+
+/**
+ * How much one benchmark added to the suite score
+ *
+ * @property rawScore Throughput relative to the reference device, scaled so the reference
+ * device scores 100
+ * @property weightedScore [rawScore] × the benchmark's scoring weight
+ * @property pctOfTotal Share of the summed weighted scores, in percent
+ */
+data class BenchmarkContribution(
+        val name: String,
+        val rawScore: Double,
+        val weightedScore: Double,
+        val pctOfTotal: Double
+)
+
+/**
+ * Per-benchmark breakdown of a suite score
+ *
+ * @property contributions Largest contribution first
+ * @property bottleneck Benchmark with the lowest raw score ("" if nothing was scored)
+ * @property highlight Benchmark with the highest raw score ("" if nothing was scored)
+ */
+data class ScoreExplanation(
+        val contributions: List<BenchmarkContribution>,
+        val bottleneck: String,
+        val highlight: String
+) {
+        /** One line per benchmark followed by the strongest and weakest result */
+        fun describe(): String = buildString {
+                contributions.forEach {
+                        appendLine(
+                                "${it.name}: ${String.format("%.1f", it.rawScore)} " +
+                                        "(${String.format("%.1f", it.pctOfTotal)}% of total)"
+                        )
+                }
+                if (contributions.isNotEmpty()) {
+                        appendLine("Strongest: $highlight")
+                        append("Weakest: $bottleneck")
+                }
+        }
+
+        fun toJson(): JSONObject =
+                JSONObject().apply {
+                        put("bottleneck", bottleneck)
+                        put("highlight", highlight)
+                        put(
+                                "contributions",
+                                JSONArray().apply {
+                                        contributions.forEach { contribution ->
+                                                put(
+                                                        JSONObject().apply {
+                                                                put("name", contribution.name)
+                                                                put("raw_score", contribution.rawScore)
+                                                                put("weighted_score", contribution.weightedScore)
+                                                                put("pct_of_total", contribution.pctOfTotal)
+                                                        }
+                                                )
+                                        }
+                                }
+                        )
+                        put("text", describe())
+                }
+}
+
+/**
+ * Break a suite result down into per-benchmark contributions
+ *
+ * The suite score is a weighted geometric mean, which can't be split exactly; shares are taken
+ * from the weighted raw scores instead, which ranks benchmarks the same way. Results without a
+ * reference value or a weight are left out.
+ *
+ * @param weights Scoring weight per result name; defaults to the suite's default weights
+ */
+fun explainScore(
+        result: SuiteResult,
+        weights: Map<String, Double> = defaultScoringWeights()
+): ScoreExplanation {
+        val scored =
+                result.results.mapNotNull { benchmark ->
+                        val weight = weights[benchmark.name] ?: return@mapNotNull null
+                        val refOps =
+                                BenchmarkName.fromString(benchmark.name)?.let {
+                                        KotlinBenchmarkManager.REFERENCE_MOPS[it]
+                                }
+                        if (weight <= 0.0 || refOps == null || refOps <= 0.0) return@mapNotNull null
+                        val rawScore = benchmark.opsPerSecond / refOps * 100.0
+                        Triple(benchmark.name, rawScore, rawScore * weight)
+                }
+        val total = scored.sumOf { it.third }
+        val contributions =
+                scored
+                        .map { (name, rawScore, weightedScore) ->
+                                BenchmarkContribution(
+                                        name = name,
+                                        rawScore = rawScore,
+                                        weightedScore = weightedScore,
+                                        pctOfTotal = if (total > 0.0) weightedScore / total * 100.0 else 0.0
+                                )
+                        }
+                        .sortedByDescending { it.weightedScore }
+        return ScoreExplanation(
+                contributions = contributions,
+                bottleneck = contributions.minByOrNull { it.rawScore }?.name ?: "",
+                highlight = contributions.maxByOrNull { it.rawScore }?.name ?: ""
+        )
+}
@@ -0,0 +1,57 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import java.io.File
+import org.json.JSONObject
+
+/** A reason a set of custom scoring weights can't be used */
+sealed class ScoringWeightError {
+    /** Only scored suite benchmarks have reference values to score against */
+    data class NotInSuite(val name: String) : ScoringWeightError()
+
+    data class Negative(val name: String, val weight: Double) : ScoringWeightError()
+
+    /** Weights must sum to 1 within [SCORING_WEIGHT_SUM_TOLERANCE] */
+    data class SumNotOne(val sum: Double) : ScoringWeightError()
+}
+
+const val SCORING_WEIGHT_SUM_TOLERANCE = 0.01
+
+/**
+ * Weight of every scored suite benchmark, keyed by result name: 35% split evenly over the
+ * single-core results and 65% over the multi-core results
+ */
+fun defaultScoringWeights(): Map<String, Double> =
+        BenchmarkRegistry.listAvailableBenchmarks()
+                .filter { it.defaultWeight > 0.0 }
+                .associate { it.name to it.defaultWeight }
+
+/**
+ * Check custom per-benchmark weights (keyed by result name, e.g. "Multi-Core FFT")
+ *
+ * Benchmarks left out of [weights] get weight 0.
+ *
+ * @param suiteNames Names that may be weighted; defaults to the scored suite
+ * @return Every problem found; empty if the weights are valid
+ */
+fun validateScoringWeights(
+        weights: Map<String, Double>,
+        suiteNames: Set<String> = defaultScoringWeights().keys
+): List<ScoringWeightError> {
+    val errors = mutableListOf<ScoringWeightError>()
+    weights.forEach { (name, weight) ->
+        if (name !in suiteNames) errors.add(ScoringWeightError.NotInSuite(name))
+        if (weight < 0.0 || weight.isNaN()) errors.add(ScoringWeightError.Negative(name, weight))
+    }
+    val sum = weights.values.sum()
+    if (!(Math.abs(sum - 1.0) <= SCORING_WEIGHT_SUM_TOLERANCE)) errors.add(ScoringWeightError.SumNotOne(sum))
+    return errors
+}
+
+/** Parse weights from a JSON object of {"benchmark name": weight} */
+fun scoringWeightsFromJson(json: String): Map<String, Double> {
+    val obj = JSONObject(json)
+    return obj.keys().asSequence().associateWith { obj.getDouble(it) }
+}
+
+/** Load weights from a JSON file in the [scoringWeightsFromJson] format */
+fun scoringWeightsFromFile(file: File): Map<String, Double> = scoringWeightsFromJson(file.readText())
@@ -0,0 +1,75 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import org.json.JSONArray
+import org.json.JSONObject
+
+/** Throughput change (in percent) below which a benchmark counts as a regression */
+const val REGRESSION_THRESHOLD_PERCENT = -5.0
+
+/**
+ * Throughput of one benchmark in two runs
+ *
+ * @property deltaPercent (current - baseline) / baseline × 100; 0 when the baseline is 0
+ * @property regression True when [deltaPercent] is below [REGRESSION_THRESHOLD_PERCENT]
+ */
+data class BenchmarkDiff(
+        val name: String,
+        val baselineOps: Double,
+        val currentOps: Double,
+        val deltaPercent: Double,
+        val regression: Boolean
+)
+
+/** Per-benchmark comparison of two suite runs, e.g. before and after a firmware update */
+data class SuiteDiff(val perBenchmark: List<BenchmarkDiff>) {
+        val regressions: List<BenchmarkDiff>
+                get() = perBenchmark.filter { it.regression }
+
+        fun toJson(): JSONObject =
+                JSONObject().apply {
+                        put("regression_count", regressions.size)
+                        put(
+                                "per_benchmark",
+                                JSONArray().apply {
+                                        perBenchmark.forEach { diff ->
+                                                put(
+                                                        JSONObject().apply {
+                                                                put("name", diff.name)
+                                                                put("baseline_ops", diff.baselineOps)
+                                                                put("current_ops", diff.currentOps)
+                                                                put("delta_percent", diff.deltaPercent)
+                                                                put("regression", diff.regression)
+                                                        }
+                                                )
+                                        }
+                                }
+                        )
+                }
+}
+
+/**
+ * Compare every benchmark present in both runs, in [baseline] order
+ *
+ * Benchmarks that only one run has are left out.
+ */
+fun diffResults(baseline: SuiteResult, current: SuiteResult): SuiteDiff {
+        val currentByName = current.results.associateBy { it.name }
+        val diffs =
+                baseline.results.mapNotNull { before ->
+                        val after = currentByName[before.name] ?: return@mapNotNull null
+                        val deltaPercent =
+                                if (before.opsPerSecond > 0.0) {
+                                        (after.opsPerSecond - before.opsPerSecond) / before.opsPerSecond * 100.0
+                                } else {
+                                        0.0
+                                }
+                        BenchmarkDiff(
+                                name = before.name,
+                                baselineOps = before.opsPerSecond,
+                                currentOps = after.opsPerSecond,
+                                deltaPercent = deltaPercent,
+                                regression = deltaPercent < REGRESSION_THRESHOLD_PERCENT
+                        )
+                }
+        return SuiteDiff(diffs)
+}
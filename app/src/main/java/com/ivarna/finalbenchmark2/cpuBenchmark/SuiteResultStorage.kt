@@ -0,0 +1,35 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import java.io.File
+import java.io.IOException
+
+/**
+ * Write a suite summary (the JSON emitted on [KotlinBenchmarkManager.benchmarkComplete]) to
+ * [file], replacing any previous contents
+ *
+ * The JSON goes to a temporary file in the same directory that is then renamed over [file], so
+ * a crash mid-write never leaves a truncated result behind.
+ *
+ * @throws IOException If the file can't be written or renamed
+ */
+fun saveResults(summaryJson: String, file: File) {
+    val target = file.absoluteFile
+    val directory = target.parentFile ?: throw IOException("No parent directory for $target")
+    if (!directory.isDirectory && !directory.mkdirs()) throw IOException("Can't create $directory")
+
+    val temp = File.createTempFile(".${target.name}", ".tmp", directory)
+    try {
+        temp.writeText(summaryJson)
+        if (!temp.renameTo(target)) throw IOException("Can't rename $temp to $target")
+    } finally {
+        temp.delete()
+    }
+}
+
+/**
+ * Read a suite summary written by [saveResults]
+ *
+ * @throws IOException If the file can't be read
+ * @throws org.json.JSONException If it doesn't hold a summary JSON object
+ */
+fun loadResults(file: File): SuiteResult = SuiteResult.fromSummaryJson(file.readText())
@@ -0,0 +1,153 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import android.util.Log
+import com.ivarna.finalbenchmark2.cpuBenchmark.algorithms.SingleCoreBenchmarks
+import org.json.JSONObject
+
+/**
+ * Result of a thermal cliff probe: throughput in the first vs last third of a sustained run
+ *
+ * @property throttleRatio finalOpsPerSec / initialOpsPerSec (1.0 = no slowdown)
+ * @property throttleDetected True when throttleRatio < [SustainedBenchmarkRunner.THROTTLE_THRESHOLD]
+ */
+data class ThermalReport(
+    val initialOpsPerSec: Double,
+    val finalOpsPerSec: Double,
+    val throttleRatio: Double,
+    val throttleDetected: Boolean
+) {
+    fun toJson(): JSONObject {
+        return JSONObject().apply {
+            put("initial_ops_per_sec", initialOpsPerSec)
+            put("final_ops_per_sec", finalOpsPerSec)
+            put("throttle_ratio", throttleRatio)
+            put("throttle_detected", throttleDetected)
+        }
+    }
+}
+
+/**
+ * Runs a benchmark repeatedly for a fixed wall-clock duration (sustained mode)
+ *
+ * Standard mode runs a fixed workload once and measures its time. Sustained mode keeps re-running
+ * the same workload until the window has elapsed, so the reported rate averages over thermal
+ * ramp-down. Operations done in the first and last 10% of the window are reported separately
+ * to quantify throttling.
+ */
+object SustainedBenchmarkRunner {
+    private const val TAG = "SustainedBenchmarkRunner"
+
+    /** Final/initial throughput ratio below which a run is reported as throttled */
+    const val THROTTLE_THRESHOLD = 0.85
+
+    /** One benchmark run inside the window, with wall-clock bounds relative to the window start */
+    private data class RunSlice(val startSec: Double, val endSec: Double, val ops: Double)
+
+    /**
+     * @param name Name for the returned result
+     * @param durationSecs Length of the window; the last run may overshoot it
+     * @param params Workload for each run (smaller workloads give finer time resolution)
+     * @param benchmark Benchmark to repeat; called with isTestRun = true to skip cooldown delays
+     * @return Result whose opsPerSecond is the average over the full window
+     */
+    suspend fun run(
+        name: String,
+        durationSecs: Double,
+        params: WorkloadParams,
+        benchmark: suspend (WorkloadParams, Boolean) -> BenchmarkResult
+    ): BenchmarkResult {
+        require(durationSecs > 0.0) { "Sustained duration must be positive, got $durationSecs" }
+        Log.d(TAG, "Starting sustained run of $name for ${durationSecs}s")
+
+        val (slices, allValid) = collectSlices(durationSecs, params, benchmark)
+        val elapsedSec = slices.lastOrNull()?.endSec ?: 0.0
+
+        val totalOps = slices.sumOf { it.ops }
+        val opsPerSecond = if (elapsedSec > 0) totalOps / elapsedSec else 0.0
+        val window = durationSecs * 0.1
+        val firstOps = opsInWindow(slices, 0.0, window)
+        val lastOps = opsInWindow(slices, elapsedSec - window, elapsedSec)
+        val sustainRatio = if (firstOps > 0) lastOps / firstOps else 0.0
+
+        Log.d(TAG, "Sustained $name: ${slices.size} runs, ${elapsedSec}s, avg $opsPerSecond ops/s, last/first 10%: $sustainRatio")
+
+        return BenchmarkResult(
+            name = name,
+            executionTimeMs = elapsedSec * 1000.0,
+            opsPerSecond = opsPerSecond,
+            isValid = allValid && slices.isNotEmpty() && !BenchmarkControl.isCancelled(),
+            metricsJson = JSONObject().apply {
+                put("mode", "sustained")
+                put("duration_secs", durationSecs)
+                put("elapsed_secs", elapsedSec)
+                put("runs", slices.size)
+                put("total_ops", totalOps)
+                put("ops_in_first_10pct", firstOps)
+                put("ops_in_last_10pct", lastOps)
+                put("last_to_first_ratio", sustainRatio)
+            }.toString()
+        )
+    }
+
+    /**
+     * Probe for thermal throttling using Single-Core Monte Carlo as a steady, CPU-bound workload
+     *
+     * Compares the throughput of the first and last thirds of a [testSecs] sustained run.
+     */
+    suspend fun measureThermalCliff(params: WorkloadParams, testSecs: Double): ThermalReport {
+        require(testSecs > 0.0) { "Thermal probe duration must be positive, got $testSecs" }
+        Log.d(TAG, "Starting thermal cliff probe for ${testSecs}s")
+
+        val (slices, _) = collectSlices(testSecs, params, SingleCoreBenchmarks::monteCarloPi)
+        val elapsedSec = slices.lastOrNull()?.endSec ?: 0.0
+        val third = elapsedSec / 3.0
+
+        val initialOpsPerSec = if (third > 0) opsInWindow(slices, 0.0, third) / third else 0.0
+        val finalOpsPerSec = if (third > 0) opsInWindow(slices, elapsedSec - third, elapsedSec) / third else 0.0
+        val ratio = if (initialOpsPerSec > 0) finalOpsPerSec / initialOpsPerSec else 0.0
+
+        val report = ThermalReport(
+            initialOpsPerSec = initialOpsPerSec,
+            finalOpsPerSec = finalOpsPerSec,
+            throttleRatio = ratio,
+            throttleDetected = initialOpsPerSec > 0 && ratio < THROTTLE_THRESHOLD
+        )
+        Log.d(TAG, "Thermal cliff probe: $report")
+        return report
+    }
+
+    /** Repeat [benchmark] until [durationSecs] have elapsed; returns the runs and whether all were valid */
+    private suspend fun collectSlices(
+        durationSecs: Double,
+        params: WorkloadParams,
+        benchmark: suspend (WorkloadParams, Boolean) -> BenchmarkResult
+    ): Pair<List<RunSlice>, Boolean> {
+        val slices = mutableListOf<RunSlice>()
+        var allValid = true
+        val windowStart = System.nanoTime()
+        var elapsedSec = 0.0
+
+        while (elapsedSec < durationSecs && !BenchmarkControl.isCancelled()) {
+            val runStart = elapsedSec
+            val result = benchmark(params, true)
+            elapsedSec = (System.nanoTime() - windowStart) / 1e9
+
+            val ops = result.opsPerSecond * (result.executionTimeMs / 1000.0)
+            slices.add(RunSlice(runStart, elapsedSec, ops))
+            allValid = allValid && result.isValid
+        }
+        return Pair(slices, allValid)
+    }
+
+    /** Ops falling in [from, to), assuming each run's ops are spread evenly over its wall time */
+    private fun opsInWindow(slices: List<RunSlice>, from: Double, to: Double): Double {
+        var ops = 0.0
+        for (slice in slices) {
+            val length = slice.endSec - slice.startSec
+            if (length <= 0) continue
+            val overlap = minOf(slice.endSec, to) - maxOf(slice.startSec, from)
+            if (overlap > 0) ops += slice.ops * (overlap / length)
+        }
+        return ops
+    }
+}
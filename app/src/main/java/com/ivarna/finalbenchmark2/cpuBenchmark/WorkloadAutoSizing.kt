@@ -0,0 +1,84 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import android.util.Log
+import com.ivarna.finalbenchmark2.cpuBenchmark.algorithms.SingleCoreBenchmarks
+
+private const val TAG = "WorkloadAutoSizing"
+
+/** Fraction of the Mid-tier iteration count each probe run uses */
+const val AUTO_SIZE_PROBE_FRACTION = 0.01
+
+/**
+ * The iteration knob of one scored benchmark: runtime grows linearly with it while the data
+ * sizes stay fixed, so a probe time can be extrapolated
+ */
+private class WorkloadKnob(
+        val name: String,
+        val get: (WorkloadParams) -> Long,
+        val set: (WorkloadParams, Long) -> WorkloadParams,
+        val run: suspend (WorkloadParams, Boolean) -> BenchmarkResult
+)
+
+// N-Queens is left out: its only knob is the board size, and solve time grows exponentially
+private val workloadKnobs =
+        listOf(
+                WorkloadKnob("primeRange", { it.primeRange.toLong() }, { p, v -> p.copy(primeRange = v.toInt()) },
+                        SingleCoreBenchmarks::primeGeneration),
+                WorkloadKnob("fibonacciIterations", { it.fibonacciIterations.toLong() },
+                        { p, v -> p.copy(fibonacciIterations = v.toInt()) }, SingleCoreBenchmarks::fibonacciRecursive),
+                WorkloadKnob("matrixIterations", { it.matrixIterations.toLong() },
+                        { p, v -> p.copy(matrixIterations = v.toInt()) }, SingleCoreBenchmarks::matrixMultiplication),
+                WorkloadKnob("hashIterations", { it.hashIterations.toLong() },
+                        { p, v -> p.copy(hashIterations = v.toInt()) }, SingleCoreBenchmarks::hashComputing),
+                WorkloadKnob("stringSortIterations", { it.stringSortIterations.toLong() },
+                        { p, v -> p.copy(stringSortIterations = v.toInt()) }, SingleCoreBenchmarks::stringSorting),
+                WorkloadKnob("rayTracingIterations", { it.rayTracingIterations.toLong() },
+                        { p, v -> p.copy(rayTracingIterations = v.toInt()) }, SingleCoreBenchmarks::rayTracing),
+                WorkloadKnob("compressionIterations", { it.compressionIterations.toLong() },
+                        { p, v -> p.copy(compressionIterations = v.toInt()) }, SingleCoreBenchmarks::compression),
+                WorkloadKnob("monteCarloSamples", { it.monteCarloSamples },
+                        { p, v -> p.copy(monteCarloSamples = v) }, SingleCoreBenchmarks::monteCarloPi),
+                WorkloadKnob("jsonParsingIterations", { it.jsonParsingIterations.toLong() },
+                        { p, v -> p.copy(jsonParsingIterations = v.toInt()) }, SingleCoreBenchmarks::jsonParsing)
+        )
+
+/**
+ * Size a workload for an unfamiliar device so each scored benchmark takes roughly
+ * [targetSecsPerBenchmark] single-core
+ *
+ * Every benchmark is probed on the Mid-tier data sizes with [AUTO_SIZE_PROBE_FRACTION] of its
+ * Mid-tier iteration count, and the iteration count is extrapolated linearly from the probe
+ * time. Results are clamped between [minimum] (e.g. the Test tier, so a fast probe can't leave a
+ * run too short for the millisecond timer) and [maximum] (the Flagship tier, so a slow probe
+ * can't produce an unbounded workload); everything other than the iteration counts keeps its
+ * Mid-tier value.
+ */
+suspend fun WorkloadParams.Companion.forDurationTarget(
+        targetSecsPerBenchmark: Double,
+        maximum: WorkloadParams,
+        minimum: WorkloadParams? = null
+): WorkloadParams {
+        require(targetSecsPerBenchmark > 0.0 && targetSecsPerBenchmark.isFinite()) {
+                "Target duration must be positive, got $targetSecsPerBenchmark"
+        }
+
+        val mid = WorkloadParams()
+        var sized = mid
+        for (knob in workloadKnobs) {
+                if (BenchmarkControl.isCancelled()) break
+
+                val probeValue = (knob.get(mid) * AUTO_SIZE_PROBE_FRACTION).toLong().coerceAtLeast(1L)
+                val probe = knob.run(knob.set(mid, probeValue), true)
+                // Millisecond timer: treat sub-millisecond probes as 1 ms
+                val probeMs = probe.executionTimeMs.coerceAtLeast(1.0)
+                val upper = knob.get(maximum)
+                val lower = minimum?.let { knob.get(it).coerceIn(1L, upper) } ?: 1L
+                val target =
+                        (probeValue * targetSecsPerBenchmark * 1000.0 / probeMs)
+                                .toLong()
+                                .coerceIn(lower, upper)
+                Log.d(TAG, "${knob.name}: probe $probeValue took ${probeMs}ms -> $target")
+                sized = knob.set(sized, target)
+        }
+        return sized
+}
@@ -0,0 +1,133 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import com.ivarna.finalbenchmark2.cpuBenchmark.algorithms.BenchmarkHelpers
+
+/** A reason a [WorkloadParams] can't be run as given */
+sealed class WorkloadParamError {
+    /** Matrices beyond [max] no longer fit the cache-resident strategy and take minutes per run */
+    data class MatrixSizeTooLarge(val size: Int, val max: Int) : WorkloadParamError()
+
+    /** Solve time grows exponentially with board size */
+    data class NQueensSizeTooLarge(val size: Int, val max: Int) : WorkloadParamError()
+
+    data class NQueensSizeTooSmall(val size: Int, val min: Int) : WorkloadParamError()
+
+    data class FibonacciRangeInverted(val start: Int, val end: Int) : WorkloadParamError()
+
+    /** F(n) for n > [max] overflows a Long */
+    data class FibonacciNTooLarge(val n: Int, val max: Int) : WorkloadParamError()
+
+    data class FftSizeNotPowerOfTwo(val size: Int) : WorkloadParamError()
+
+    data class WaveletFrameSizeNotPowerOfTwo(val size: Int) : WorkloadParamError()
+
+    /** At least one string has to be unique, so the fraction must be in [0, 1) */
+    data class DedupDuplicateFractionOutOfRange(val fraction: Double) : WorkloadParamError()
+
+    /** Patterns can't be longer than the fixed-length strings in the search corpus */
+    data class StringSearchPatternTooLong(val length: Int, val max: Int) : WorkloadParamError()
+
+    /** A size or iteration count that must be at least 1 */
+    data class NonPositive(val field: String, val value: Long) : WorkloadParamError()
+}
+
+const val MAX_MATRIX_SIZE = 2_048
+const val MIN_NQUEENS_SIZE = 4
+const val MAX_NQUEENS_SIZE = 18
+const val MAX_STRING_SEARCH_PATTERN_LEN = 16
+
+/**
+ * Check [params] for values the benchmarks would reject, silently clamp, or take unreasonably
+ * long on
+ *
+ * Kernels don't validate their own params; the registry, the scheduler and the manager's suite
+ * entry points check them before anything runs.
+ *
+ * @return Every problem found; empty if the params are valid
+ */
+fun validateWorkloadParams(params: WorkloadParams): List<WorkloadParamError> {
+    val errors = mutableListOf<WorkloadParamError>()
+
+    fun requirePositive(field: String, value: Long) {
+        if (value < 1) errors.add(WorkloadParamError.NonPositive(field, value))
+    }
+
+    with(params) {
+        requirePositive("primeRange", primeRange.toLong())
+        requirePositive("fibonacciIterations", fibonacciIterations.toLong())
+        requirePositive("matrixSize", matrixSize.toLong())
+        requirePositive("matrixIterations", matrixIterations.toLong())
+        requirePositive("hashDataSizeMb", hashDataSizeMb.toLong())
+        requirePositive("hashIterations", hashIterations.toLong())
+        requirePositive("stringSortCount", stringSortCount.toLong())
+        requirePositive("stringSortIterations", stringSortIterations.toLong())
+        requirePositive("rayTracingIterations", rayTracingIterations.toLong())
+        requirePositive("rayTracingResolution.width", rayTracingResolution.first.toLong())
+        requirePositive("rayTracingResolution.height", rayTracingResolution.second.toLong())
+        requirePositive("rayTracingDepth", rayTracingDepth.toLong())
+        requirePositive("compressionDataSizeMb", compressionDataSizeMb.toLong())
+        requirePositive("compressionIterations", compressionIterations.toLong())
+        requirePositive("monteCarloSamples", monteCarloSamples)
+        requirePositive("jsonDataSizeMb", jsonDataSizeMb.toLong())
+        requirePositive("jsonParsingIterations", jsonParsingIterations.toLong())
+        requirePositive("scanElementCount", scanElementCount.toLong())
+        requirePositive("scanIterations", scanIterations.toLong())
+        requirePositive("stringSearchCount", stringSearchCount.toLong())
+        requirePositive("stringSearchPatternLen", stringSearchPatternLen.toLong())
+        requirePositive("stringSearchIterations", stringSearchIterations.toLong())
+        requirePositive("graphNodeCount", graphNodeCount.toLong())
+        requirePositive("graphBfsIterations", graphBfsIterations.toLong())
+        requirePositive("fftIterations", fftIterations.toLong())
+        requirePositive("aesDataSizeMb", aesDataSizeMb.toLong())
+        requirePositive("regexCorpusSize", regexCorpusSize.toLong())
+        requirePositive("hashChainDepth", hashChainDepth.toLong())
+        requirePositive("sortElementCount", sortElementCount.toLong())
+        requirePositive("levenshteinPairCount", levenshteinPairCount.toLong())
+        requirePositive("knapsackItems", knapsackItems.toLong())
+        requirePositive("polynomialDegree", polynomialDegree.toLong())
+        requirePositive("polynomialEvalPoints", polynomialEvalPoints.toLong())
+        requirePositive("integerOpsCount", integerOpsCount)
+        requirePositive("rayTracingSpp", rayTracingSpp.toLong())
+
+        if (matrixSize > MAX_MATRIX_SIZE) {
+            errors.add(WorkloadParamError.MatrixSizeTooLarge(matrixSize, MAX_MATRIX_SIZE))
+        }
+        if (nqueensSize > MAX_NQUEENS_SIZE) {
+            errors.add(WorkloadParamError.NQueensSizeTooLarge(nqueensSize, MAX_NQUEENS_SIZE))
+        } else if (nqueensSize < MIN_NQUEENS_SIZE) {
+            errors.add(WorkloadParamError.NQueensSizeTooSmall(nqueensSize, MIN_NQUEENS_SIZE))
+        }
+
+        val (fibStart, fibEnd) = fibonacciNRange
+        if (fibStart > fibEnd) {
+            errors.add(WorkloadParamError.FibonacciRangeInverted(fibStart, fibEnd))
+        }
+        if (maxOf(fibStart, fibEnd) > BenchmarkHelpers.MAX_FIBONACCI_N) {
+            errors.add(WorkloadParamError.FibonacciNTooLarge(maxOf(fibStart, fibEnd), BenchmarkHelpers.MAX_FIBONACCI_N))
+        }
+
+        if (fftSize < 2 || fftSize and (fftSize - 1) != 0) {
+            errors.add(WorkloadParamError.FftSizeNotPowerOfTwo(fftSize))
+        }
+        if (waveletFrameSize < 2 || waveletFrameSize and (waveletFrameSize - 1) != 0) {
+            errors.add(WorkloadParamError.WaveletFrameSizeNotPowerOfTwo(waveletFrameSize))
+        }
+        // Written as a negated range check so NaN is rejected too
+        if (!(dedupDuplicateFraction >= 0.0 && dedupDuplicateFraction < 1.0)) {
+            errors.add(WorkloadParamError.DedupDuplicateFractionOutOfRange(dedupDuplicateFraction))
+        }
+        if (stringSearchPatternLen > MAX_STRING_SEARCH_PATTERN_LEN) {
+            errors.add(
+                WorkloadParamError.StringSearchPatternTooLong(stringSearchPatternLen, MAX_STRING_SEARCH_PATTERN_LEN)
+            )
+        }
+    }
+
+    return errors
+}
+
+/** @throws BenchmarkError.InvalidParams listing every problem [validateWorkloadParams] finds */
+fun requireValidWorkloadParams(params: WorkloadParams) {
+    val errors = validateWorkloadParams(params)
+    if (errors.isNotEmpty()) throw BenchmarkError.InvalidParams(errors)
+}
@@ -1,8 +1,15 @@
 package com.ivarna.finalbenchmark2.cpuBenchmark.algorithms
 
+import com.ivarna.finalbenchmark2.cpuBenchmark.BenchmarkControl
+import com.ivarna.finalbenchmark2.cpuBenchmark.PerfReadings
+import java.math.BigInteger
 import java.util.Arrays
+import java.util.concurrent.BlockingQueue
 import java.util.concurrent.ThreadLocalRandom
+import java.util.concurrent.atomic.AtomicLong
 import kotlinx.coroutines.*
+import org.json.JSONArray
+import org.json.JSONObject
 
 object BenchmarkHelpers {
 
@@ -15,6 +22,21 @@ object BenchmarkHelpers {
         return Pair(result, durationMs)
     }
 
+    /**
+     * [measureBenchmark] that also counts hardware events (see [PerfReadings]) into [perf]
+     *
+     * Counters follow the calling thread, so call this inside the kernel's pinned, non-suspending
+     * section; they then cover the timed work and nothing else.
+     */
+    inline fun <T> measureBenchmark(perf: PerfReadings, block: () -> T): Pair<T, Long> {
+        val counters = perf.start()
+        try {
+            return measureBenchmark(block)
+        } finally {
+            perf.stop(counters)
+        }
+    }
+
     /**
      * Run a suspend benchmark function and measure execution time Allows yielding to prevent UI
      * freeze
@@ -29,6 +51,27 @@ object BenchmarkHelpers {
         return Pair(result, durationMs)
     }
 
+    /**
+     * Run [block] [iterations] times, timing every call separately, so outlier iterations (e.g. a
+     * thread migrated to a little core mid-run) show up instead of vanishing into the total
+     *
+     * Call results are summed rather than kept, so the timed loop doesn't box or allocate. Stops
+     * early at an iteration boundary if cancelled through [BenchmarkControl].
+     *
+     * @return Times in milliseconds of the iterations that ran, and the sum of their results
+     */
+    inline fun runBenchmarkTimedIterations(iterations: Int, block: (iteration: Int) -> Int): Pair<DoubleArray, Long> {
+        val timesMs = DoubleArray(iterations)
+        var resultSum = 0L
+        val completed =
+            BenchmarkControl.repeatUntilCancelled(iterations) { i ->
+                val startTime = System.nanoTime()
+                resultSum += block(i)
+                timesMs[i] = (System.nanoTime() - startTime) / 1_000_000.0
+            }
+        return Pair(if (completed < iterations) timesMs.copyOf(completed) else timesMs, resultSum)
+    }
+
     /**
      * Generate random string of specified length - OPTIMIZED for performance Uses static character
      * set and efficient string building
@@ -355,7 +398,7 @@ object BenchmarkHelpers {
         val b = Array(size) { DoubleArray(size) }
         val c = Array(size) { DoubleArray(size) }
         
-        repeat(repetitions) { rep ->
+        BenchmarkControl.repeatUntilCancelled(repetitions) { rep ->
             // DETERMINISTIC INITIALIZATION: Eliminates ALL RNG overhead
             // Makes benchmark purely test FPU/matrix computation
             // Pattern ensures no compiler optimizations (different values each iteration)
@@ -414,7 +457,7 @@ object BenchmarkHelpers {
         var h6 = 0x1f83d9ab.toInt()
         var h7 = 0x5be0cd19.toInt()
         
-        repeat(iterations) { i ->
+        BenchmarkControl.repeatUntilCancelled(iterations, checkEvery = BenchmarkControl.FINE_GRAINED_CHECK_STRIDE) { i ->
             // SHA-256-like compression function
             // Σ0 = ROTR(2) XOR ROTR(13) XOR ROTR(22)
             val s0 = ((h0 ushr 2) or (h0 shl 30)) xor 
@@ -497,7 +540,7 @@ object BenchmarkHelpers {
     fun runStringSortWorkload(sourceList: List<String>, iterations: Int): Int {
         var checkSum = 0
         // Reuse specific small size to keep data in L2 CPU Cache
-        repeat(iterations) {
+        BenchmarkControl.repeatUntilCancelled(iterations) {
             // Create copy to ensure we are actually sorting (O(N) copy + O(N log N) sort)
             val workingList = ArrayList(sourceList)
             workingList.sort()
@@ -517,7 +560,7 @@ object BenchmarkHelpers {
      *
      * @param bufferSize Size of data buffer in bytes (2MB recommended)
      * @param iterations Number of compression iterations to perform
-     * @return Total bytes processed (bufferSize * iterations)
+     * @return Total bytes processed (bufferSize * iterations completed before any cancellation)
      */
     fun performCompression(bufferSize: Int, iterations: Int): Long {
         // Generate data ONCE outside the compression loop
@@ -527,12 +570,28 @@ object BenchmarkHelpers {
         var totalCompressedSize = 0L
 
         // Perform compression iterations
-        repeat(iterations) {
-            val compressedSize = compressRLE(data, outputBuffer)
-            totalCompressedSize += compressedSize
-        }
+        val completed =
+            BenchmarkControl.repeatUntilCancelled(iterations) {
+                val compressedSize = compressRLE(data, outputBuffer)
+                totalCompressedSize += compressedSize
+            }
+
+        return bufferSize.toLong() * completed
+    }
+
+    /**
+     * [performCompression] with every iteration timed via [runBenchmarkTimedIterations]
+     *
+     * @return Total bytes processed and per-iteration times in milliseconds
+     */
+    fun performCompressionTimed(bufferSize: Int, iterations: Int): Pair<Long, DoubleArray> {
+        val data = ByteArray(bufferSize) { (it % 256).toByte() }
+        val outputBuffer = ByteArray(bufferSize * 2)
 
-        return bufferSize.toLong() * iterations
+        val (timesMs, _) = runBenchmarkTimedIterations(iterations) { compressRLE(data, outputBuffer) }
+
+        // Cancellation can stop the loop early; count only the passes that ran
+        return Pair(bufferSize.toLong() * timesMs.size, timesMs)
     }
 
     /**
@@ -641,7 +700,7 @@ object BenchmarkHelpers {
         var totalNoise = 0.0
         val scale = 0.1  // Noise frequency
         
-        repeat(iterations) { iter ->
+        BenchmarkControl.repeatUntilCancelled(iterations) { iter ->
             for (z in 0 until depth) {
                 for (y in 0 until height) {
                     for (x in 0 until width) {
@@ -1029,7 +1088,7 @@ object BenchmarkHelpers {
         val lY = 0.57735
         val lZ = 0.57735
 
-        repeat(iterations) {
+        BenchmarkControl.repeatUntilCancelled(iterations) {
             var frameEnergy = 0.0
 
             // Y-Loop (Rows)
@@ -1209,7 +1268,7 @@ object BenchmarkHelpers {
         val binaryData = convertJsonToBinary(jsonData)
         var totalChecksum = 0L
         
-        repeat(iterations) { iter ->
+        BenchmarkControl.repeatUntilCancelled(iterations) { iter ->
             var checksum = 0L
             var i = 0
             
@@ -1409,4 +1468,3020 @@ object BenchmarkHelpers {
 
         return Pair(solutionCount, iterationCount)
     }
+
+    /**
+     * Generate deterministic input for the prefix-sum (scan) benchmark
+     *
+     * Values stay in a small range so the running total keeps enough precision for validation.
+     *
+     * @param count Number of elements
+     * @return DoubleArray of pseudo-random values in [0, 1)
+     */
+    fun generateScanInput(count: Int): DoubleArray {
+        val rng = XorShift128Plus(0x5CA9L)
+        return DoubleArray(count) { rng.nextDouble() }
+    }
+
+    /**
+     * Sequential inclusive prefix sum - reference result for scan validation
+     *
+     * @param input Source values
+     * @return Total of all elements (equal to the last element of the inclusive scan)
+     */
+    fun sequentialScanTotal(input: DoubleArray): Double {
+        var running = 0.0
+        for (value in input) {
+            running += value
+        }
+        return running
+    }
+
+    /**
+     * Work-Efficient Two-Pass Parallel Prefix Sum (inclusive scan)
+     *
+     * ALGORITHM:
+     * 1. Phase 1 (reduce): each chunk computes its local total in parallel
+     * 2. Exclusive scan of the chunk totals (numChunks values, sequential)
+     * 3. Phase 2 (propagate): each chunk scans its elements starting from its prefix offset
+     *
+     * Each element is read twice and written once, so the benchmark exposes memory bandwidth
+     * and the synchronization barrier between the two phases.
+     *
+     * @param input Source values
+     * @param output Destination buffer (same size as input)
+     * @param numChunks Number of chunks (one per thread)
+     * @param dispatcher CoroutineDispatcher for parallel execution
+     * @return Last element of the inclusive scan
+     */
+    suspend fun parallelPrefixSum(
+        input: DoubleArray,
+        output: DoubleArray,
+        numChunks: Int,
+        dispatcher: kotlinx.coroutines.CoroutineDispatcher
+    ): Double = kotlinx.coroutines.coroutineScope {
+        val n = input.size
+        if (n == 0) return@coroutineScope 0.0
+
+        val chunkSize = (n + numChunks - 1) / numChunks
+        val chunkCount = (n + chunkSize - 1) / chunkSize
+
+        // Phase 1: reduce each chunk
+        val chunkTotals = (0 until chunkCount).map { chunk ->
+            async(dispatcher) {
+                val start = chunk * chunkSize
+                val end = kotlin.math.min(start + chunkSize, n)
+                var sum = 0.0
+                for (i in start until end) {
+                    sum += input[i]
+                }
+                sum
+            }
+        }.awaitAll()
+
+        // Exclusive scan of chunk totals
+        val offsets = DoubleArray(chunkCount)
+        var running = 0.0
+        for (chunk in 0 until chunkCount) {
+            offsets[chunk] = running
+            running += chunkTotals[chunk]
+        }
+
+        // Phase 2: local scan seeded with the chunk prefix
+        (0 until chunkCount).map { chunk ->
+            async(dispatcher) {
+                val start = chunk * chunkSize
+                val end = kotlin.math.min(start + chunkSize, n)
+                var acc = offsets[chunk]
+                for (i in start until end) {
+                    acc += input[i]
+                    output[i] = acc
+                }
+            }
+        }.awaitAll()
+
+        output[n - 1]
+    }
+
+    /**
+     * Generate a newline-separated search corpus for the string search benchmark
+     *
+     * Uses a 4-letter alphabet (DNA-like) so short patterns occur often enough to produce
+     * a meaningful match count. Seeded for reproducible match counts across runs.
+     *
+     * @param count Number of strings in the corpus
+     * @param length Length of each string
+     * @return Corpus bytes, each record is [length] letters followed by '\n'
+     */
+    fun generateSearchCorpus(count: Int, length: Int = 16): ByteArray {
+        val alphabet = byteArrayOf('A'.code.toByte(), 'C'.code.toByte(), 'G'.code.toByte(), 'T'.code.toByte())
+        val rng = XorShift128Plus(0x5EA4CL)
+        val recordSize = length + 1
+        val corpus = ByteArray(count * recordSize)
+        for (record in 0 until count) {
+            val base = record * recordSize
+            for (i in 0 until length) {
+                corpus[base + i] = alphabet[rng.nextInt(alphabet.size)]
+            }
+            corpus[base + length] = '\n'.code.toByte()
+        }
+        return corpus
+    }
+
+    /**
+     * Build the Boyer-Moore-Horspool bad-character shift table
+     *
+     * @param pattern Search pattern
+     * @return 256-entry table of shift distances indexed by byte value
+     */
+    fun buildHorspoolShiftTable(pattern: ByteArray): IntArray {
+        val shift = IntArray(256) { pattern.size }
+        for (i in 0 until pattern.size - 1) {
+            shift[pattern[i].toInt() and 0xFF] = pattern.size - 1 - i
+        }
+        return shift
+    }
+
+    /**
+     * Boyer-Moore-Horspool substring search
+     *
+     * Compares the pattern right-to-left and skips ahead using the shift of the byte aligned
+     * with the last pattern position. Sublinear on average, with irregular forward jumps.
+     *
+     * @param text Corpus to search
+     * @param from Start offset (inclusive)
+     * @param to End offset (exclusive)
+     * @param pattern Pattern to find
+     * @param shift Table from [buildHorspoolShiftTable]
+     * @return Number of (possibly overlapping) occurrences in text[from, to)
+     */
+    fun countOccurrencesHorspool(
+        text: ByteArray,
+        from: Int,
+        to: Int,
+        pattern: ByteArray,
+        shift: IntArray
+    ): Int {
+        val m = pattern.size
+        if (m == 0 || to - from < m) return 0
+
+        var matches = 0
+        var pos = from
+        val last = m - 1
+        while (pos <= to - m) {
+            var j = last
+            while (j >= 0 && text[pos + j] == pattern[j]) {
+                j--
+            }
+            if (j < 0) matches++
+            pos += shift[text[pos + last].toInt() and 0xFF]
+        }
+        return matches
+    }
+
+    /**
+     * Generate a random sparse directed graph in CSR (compressed sparse row) form
+     *
+     * Each node gets an out-degree drawn uniformly from [0, 2 * avgOutDegree], so the mean
+     * out-degree is [avgOutDegree]. Seeded so every run traverses the same graph.
+     *
+     * @param nodeCount Number of nodes
+     * @param avgOutDegree Average number of outgoing edges per node
+     * @return Pair of (offsets, targets): edges of node v are targets[offsets[v] until offsets[v + 1]]
+     */
+    fun generateRandomGraph(nodeCount: Int, avgOutDegree: Int = 8): Pair<IntArray, IntArray> {
+        val rng = XorShift128Plus(0x6BF5L)
+        val offsets = IntArray(nodeCount + 1)
+        for (v in 0 until nodeCount) {
+            offsets[v + 1] = offsets[v] + rng.nextInt(2 * avgOutDegree + 1)
+        }
+        val targets = IntArray(offsets[nodeCount])
+        for (e in targets.indices) {
+            targets[e] = rng.nextInt(nodeCount)
+        }
+        return Pair(offsets, targets)
+    }
+
+    /**
+     * Sequential breadth-first search over a CSR graph
+     *
+     * @param offsets CSR row offsets from [generateRandomGraph]
+     * @param targets CSR edge targets from [generateRandomGraph]
+     * @param root Start node
+     * @return Number of nodes reached from [root] (including the root)
+     */
+    fun breadthFirstSearch(offsets: IntArray, targets: IntArray, root: Int): Int {
+        val nodeCount = offsets.size - 1
+        val visited = BooleanArray(nodeCount)
+        val queue = IntArray(nodeCount)
+        var head = 0
+        var tail = 0
+
+        visited[root] = true
+        queue[tail++] = root
+        while (head < tail) {
+            val v = queue[head++]
+            for (e in offsets[v] until offsets[v + 1]) {
+                val w = targets[e]
+                if (!visited[w]) {
+                    visited[w] = true
+                    queue[tail++] = w
+                }
+            }
+        }
+        return tail
+    }
+
+    /**
+     * Level-synchronous parallel breadth-first search over a CSR graph
+     *
+     * The current frontier is split into chunks, one per worker. Each worker claims unvisited
+     * neighbours with a compare-and-set and collects them into a local buffer; the buffers are
+     * concatenated into the next frontier after every level (one barrier per level).
+     *
+     * @param offsets CSR row offsets from [generateRandomGraph]
+     * @param targets CSR edge targets from [generateRandomGraph]
+     * @param root Start node
+     * @param numChunks Number of parallel chunks per level
+     * @param dispatcher Coroutine dispatcher for parallel execution
+     * @return Number of nodes reached from [root] (including the root)
+     */
+    suspend fun parallelBreadthFirstSearch(
+        offsets: IntArray,
+        targets: IntArray,
+        root: Int,
+        numChunks: Int,
+        dispatcher: kotlinx.coroutines.CoroutineDispatcher
+    ): Int = kotlinx.coroutines.coroutineScope {
+        val nodeCount = offsets.size - 1
+        val visited = java.util.concurrent.atomic.AtomicIntegerArray(nodeCount)
+        visited.set(root, 1)
+
+        var frontier = intArrayOf(root)
+        var reached = 1
+        while (frontier.isNotEmpty()) {
+            val current = frontier
+            val chunkSize = (current.size + numChunks - 1) / numChunks
+            val chunkCount = (current.size + chunkSize - 1) / chunkSize
+
+            val nextParts = (0 until chunkCount).map { chunk ->
+                async(dispatcher) {
+                    val start = chunk * chunkSize
+                    val end = kotlin.math.min(start + chunkSize, current.size)
+                    var local = IntArray(kotlin.math.max(16, (end - start) * 2))
+                    var count = 0
+                    for (i in start until end) {
+                        val v = current[i]
+                        for (e in offsets[v] until offsets[v + 1]) {
+                            val w = targets[e]
+                            if (visited.get(w) == 0 && visited.compareAndSet(w, 0, 1)) {
+                                if (count == local.size) local = local.copyOf(count * 2)
+                                local[count++] = w
+                            }
+                        }
+                    }
+                    local.copyOf(count)
+                }
+            }.awaitAll()
+
+            val nextSize = nextParts.sumOf { it.size }
+            val next = IntArray(nextSize)
+            var pos = 0
+            for (part in nextParts) {
+                System.arraycopy(part, 0, next, pos, part.size)
+                pos += part.size
+            }
+            reached += nextSize
+            frontier = next
+        }
+        reached
+    }
+
+    /**
+     * In-place iterative radix-2 Cooley-Tukey FFT
+     *
+     * Complex values are stored as separate real and imaginary arrays. The inverse transform
+     * includes the 1/N scaling, so fft(inverse = true) undoes fft(inverse = false).
+     *
+     * @param re Real parts (size must be a power of two)
+     * @param im Imaginary parts (same size as [re])
+     * @param inverse True for the inverse transform
+     */
+    fun fftInPlace(re: DoubleArray, im: DoubleArray, inverse: Boolean) {
+        val n = re.size
+        require(n > 0 && n and (n - 1) == 0) { "FFT size must be a power of two, got $n" }
+
+        // Bit-reversal permutation
+        var j = 0
+        for (i in 1 until n) {
+            var bit = n shr 1
+            while (j and bit != 0) {
+                j = j xor bit
+                bit = bit shr 1
+            }
+            j = j xor bit
+            if (i < j) {
+                val tr = re[i]; re[i] = re[j]; re[j] = tr
+                val ti = im[i]; im[i] = im[j]; im[j] = ti
+            }
+        }
+
+        // Butterflies
+        var len = 2
+        while (len <= n) {
+            val angle = (if (inverse) 2.0 else -2.0) * Math.PI / len
+            val wLenRe = kotlin.math.cos(angle)
+            val wLenIm = kotlin.math.sin(angle)
+            val half = len shr 1
+            var start = 0
+            while (start < n) {
+                var wRe = 1.0
+                var wIm = 0.0
+                for (k in 0 until half) {
+                    val a = start + k
+                    val b = a + half
+                    val vRe = re[b] * wRe - im[b] * wIm
+                    val vIm = re[b] * wIm + im[b] * wRe
+                    re[b] = re[a] - vRe
+                    im[b] = im[a] - vIm
+                    re[a] += vRe
+                    im[a] += vIm
+                    val nextWRe = wRe * wLenRe - wIm * wLenIm
+                    wIm = wRe * wLenIm + wIm * wLenRe
+                    wRe = nextWRe
+                }
+                start += len
+            }
+            len = len shl 1
+        }
+
+        if (inverse) {
+            val scale = 1.0 / n
+            for (i in 0 until n) {
+                re[i] *= scale
+                im[i] *= scale
+            }
+        }
+    }
+
+    /**
+     * Run [iterations] forward + inverse FFT round trips on a copy of the signal
+     *
+     * @param signalRe Real parts of the input signal (not modified)
+     * @param signalIm Imaginary parts of the input signal (not modified)
+     * @param iterations Number of round trips
+     * @return Maximum absolute deviation from the input after the last round trip
+     */
+    fun performFftRoundTrips(signalRe: DoubleArray, signalIm: DoubleArray, iterations: Int): Double {
+        val re = signalRe.copyOf()
+        val im = signalIm.copyOf()
+        BenchmarkControl.repeatUntilCancelled(iterations) {
+            fftInPlace(re, im, inverse = false)
+            fftInPlace(re, im, inverse = true)
+        }
+        var maxError = 0.0
+        for (i in re.indices) {
+            maxError = kotlin.math.max(maxError, kotlin.math.abs(re[i] - signalRe[i]))
+            maxError = kotlin.math.max(maxError, kotlin.math.abs(im[i] - signalIm[i]))
+        }
+        return maxError
+    }
+
+    /**
+     * Floating-point operations for one radix-2 FFT of size n (standard 5 * N * log2(N) estimate)
+     */
+    fun fftFlops(n: Int): Double {
+        val log2n = 31 - Integer.numberOfLeadingZeros(n)
+        return 5.0 * n * log2n
+    }
+
+    /**
+     * Generate a seeded complex test signal in [-1, 1) for the FFT benchmark
+     *
+     * @return Pair of (real parts, imaginary parts)
+     */
+    fun generateFftSignal(n: Int): Pair<DoubleArray, DoubleArray> {
+        val rng = XorShift128Plus(0xFF7L)
+        val re = DoubleArray(n) { rng.nextDouble() * 2.0 - 1.0 }
+        val im = DoubleArray(n) { rng.nextDouble() * 2.0 - 1.0 }
+        return Pair(re, im)
+    }
+
+    /**
+     * AES-128-CBC encrypt + decrypt round trips using javax.crypto
+     *
+     * Works on a 1 MB buffer, re-encrypting it [sizeMb] times so large data sizes don't need
+     * large allocations. The JCE provider uses ARMv8 AES instructions when the CPU has them.
+     *
+     * @param sizeMb Megabytes to encrypt (and decrypt)
+     * @param seed Seed for the plaintext, so parallel callers work on independent buffers
+     * @return True if every decrypted chunk matched the plaintext
+     */
+    fun performAesCbcRoundTrips(sizeMb: Int, seed: Long): Boolean {
+        val chunkSize = 1024 * 1024
+        val rng = XorShift128Plus(seed)
+        val plain = ByteArray(chunkSize) { rng.nextInt().toByte() }
+        val encrypted = ByteArray(chunkSize)
+        val decrypted = ByteArray(chunkSize)
+
+        val key = javax.crypto.spec.SecretKeySpec(ByteArray(16) { it.toByte() }, "AES")
+        val iv = javax.crypto.spec.IvParameterSpec(ByteArray(16) { (15 - it).toByte() })
+        val encryptor = javax.crypto.Cipher.getInstance("AES/CBC/NoPadding")
+        val decryptor = javax.crypto.Cipher.getInstance("AES/CBC/NoPadding")
+
+        var valid = true
+        repeat(sizeMb) {
+            encryptor.init(javax.crypto.Cipher.ENCRYPT_MODE, key, iv)
+            encryptor.doFinal(plain, 0, chunkSize, encrypted, 0)
+            decryptor.init(javax.crypto.Cipher.DECRYPT_MODE, key, iv)
+            decryptor.doFinal(encrypted, 0, chunkSize, decrypted, 0)
+            if (decrypted[0] != plain[0] || decrypted[chunkSize - 1] != plain[chunkSize - 1]) {
+                valid = false
+            }
+        }
+        return valid && decrypted.contentEquals(plain)
+    }
+
+    /** Largest n whose Fibonacci number fits in a signed Long (F(92) = 7540113804746346429) */
+    const val MAX_FIBONACCI_N = 92
+
+    /**
+     * Plain Fibonacci number by iteration - O(n) time, O(1) space, no recursion
+     *
+     * @param n Index, clamped to [0, MAX_FIBONACCI_N]
+     * @return F(n)
+     */
+    fun fibonacciLoop(n: Int): Long {
+        val target = n.coerceIn(0, MAX_FIBONACCI_N)
+        var a = 0L
+        var b = 1L
+        repeat(target) {
+            val next = a + b
+            a = b
+            b = next
+        }
+        return a
+    }
+
+    /**
+     * Fibonacci number by bottom-up dynamic programming - fills table[0..n]
+     *
+     * @param n Index, clamped to [0, MAX_FIBONACCI_N]
+     * @param table Scratch table of at least MAX_FIBONACCI_N + 1 entries (reused between calls)
+     * @return F(n)
+     */
+    fun fibonacciDp(n: Int, table: LongArray): Long {
+        val target = n.coerceIn(0, MAX_FIBONACCI_N)
+        table[0] = 0L
+        if (target == 0) return 0L
+        table[1] = 1L
+        for (i in 2..target) {
+            table[i] = table[i - 1] + table[i - 2]
+        }
+        return table[target]
+    }
+
+    /** Random 32-bit keys for radix sorting (fixed seed so every device sorts the same data) */
+    fun generateRadixSortInput(count: Int, seed: Long = 0x4AD1L): IntArray {
+        val rng = XorShift128Plus(seed)
+        return IntArray(count) { rng.nextInt() }
+    }
+
+    /**
+     * LSD radix sort of 32-bit keys as unsigned values - 8 bits per pass, 4 passes
+     *
+     * @param data Keys to sort in place
+     * @param scratch Buffer of at least data.size entries
+     */
+    fun radixSortLsd(data: IntArray, scratch: IntArray) {
+        val n = data.size
+        val counts = IntArray(256)
+        var src = data
+        var dst = scratch
+        for (shift in 0 until 32 step 8) {
+            counts.fill(0)
+            for (i in 0 until n) {
+                counts[(src[i] ushr shift) and 0xFF]++
+            }
+            var offset = 0
+            for (digit in 0 until 256) {
+                val count = counts[digit]
+                counts[digit] = offset
+                offset += count
+            }
+            for (i in 0 until n) {
+                val value = src[i]
+                dst[counts[(value ushr shift) and 0xFF]++] = value
+            }
+            val tmp = src
+            src = dst
+            dst = tmp
+        }
+        // 4 passes is even, so the sorted keys end up back in data
+    }
+
+    /**
+     * Parallel LSD radix sort (unsigned, 8 bits per pass, 4 passes)
+     *
+     * Each pass: every chunk counts its digits in parallel, the per-chunk histograms are merged
+     * into global scatter offsets (digit-major, chunk-minor to keep the sort stable), then every
+     * chunk scatters its keys in parallel.
+     *
+     * @param data Keys to sort in place
+     * @param scratch Buffer of at least data.size entries
+     */
+    suspend fun parallelRadixSort(
+        data: IntArray,
+        scratch: IntArray,
+        numChunks: Int,
+        dispatcher: kotlinx.coroutines.CoroutineDispatcher
+    ) = kotlinx.coroutines.coroutineScope {
+        val n = data.size
+        if (n == 0) return@coroutineScope
+
+        val chunkSize = (n + numChunks - 1) / numChunks
+        val chunkCount = (n + chunkSize - 1) / chunkSize
+        val histograms = Array(chunkCount) { IntArray(256) }
+        var src = data
+        var dst = scratch
+
+        for (shift in 0 until 32 step 8) {
+            val input = src
+            val output = dst
+
+            // Count phase
+            (0 until chunkCount).map { chunk ->
+                async(dispatcher) {
+                    val histogram = histograms[chunk]
+                    histogram.fill(0)
+                    val end = kotlin.math.min((chunk + 1) * chunkSize, n)
+                    for (i in chunk * chunkSize until end) {
+                        histogram[(input[i] ushr shift) and 0xFF]++
+                    }
+                }
+            }.awaitAll()
+
+            // Merge histograms into per-chunk scatter offsets
+            var offset = 0
+            for (digit in 0 until 256) {
+                for (chunk in 0 until chunkCount) {
+                    val count = histograms[chunk][digit]
+                    histograms[chunk][digit] = offset
+                    offset += count
+                }
+            }
+
+            // Scatter phase
+            (0 until chunkCount).map { chunk ->
+                async(dispatcher) {
+                    val offsets = histograms[chunk]
+                    val end = kotlin.math.min((chunk + 1) * chunkSize, n)
+                    for (i in chunk * chunkSize until end) {
+                        val value = input[i]
+                        output[offsets[(value ushr shift) and 0xFF]++] = value
+                    }
+                }
+            }.awaitAll()
+
+            src = output
+            dst = input
+        }
+    }
+
+    /** Check keys are in ascending unsigned order */
+    fun isSortedUnsigned(data: IntArray): Boolean {
+        for (i in 1 until data.size) {
+            if (Integer.compareUnsigned(data[i - 1], data[i]) > 0) return false
+        }
+        return true
+    }
+
+    /** Email-like pattern used by the regex benchmark */
+    const val EMAIL_PATTERN = "\\b[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\\.[A-Z|a-z]{2,}\\b"
+
+    private val REGEX_WORDS = arrayOf(
+        "the", "quick", "brown", "fox", "jumps", "over", "lazy", "dog", "benchmark", "processor",
+        "cache", "memory", "thread", "kernel", "device", "battery", "signal", "vector", "matrix",
+        "compile", "schedule", "latency", "throughput", "contact", "support", "invoice", "meeting"
+    )
+    private val REGEX_DOMAINS = arrayOf("example.com", "mail.example.org", "test.net", "corp.example.io")
+
+    /**
+     * Random sentences of about 80-120 characters built from a fixed vocabulary, with an email address
+     * mixed into roughly one sentence in five (fixed seed so every device scans the same text)
+     *
+     * @return Sentences and the number of email addresses they contain
+     */
+    fun generateRegexCorpus(count: Int, seed: Long = 0x4E6EL): Pair<List<String>, Int> {
+        val rng = XorShift128Plus(seed)
+        var emails = 0
+        val sentences = List(count) {
+            val targetLength = 80 + rng.nextInt(41)
+            val emailAt = if (rng.nextInt(5) == 0) rng.nextInt(targetLength) else -1
+            var emailPending = emailAt >= 0
+            val sentence = StringBuilder(targetLength + 32)
+            while (sentence.length < targetLength || emailPending) {
+                if (sentence.isNotEmpty()) sentence.append(' ')
+                if (emailPending && sentence.length >= emailAt) {
+                    sentence.append(REGEX_WORDS[rng.nextInt(REGEX_WORDS.size)])
+                        .append('.')
+                        .append(rng.nextInt(1000))
+                        .append('@')
+                        .append(REGEX_DOMAINS[rng.nextInt(REGEX_DOMAINS.size)])
+                    emails++
+                    emailPending = false
+                } else {
+                    sentence.append(REGEX_WORDS[rng.nextInt(REGEX_WORDS.size)])
+                }
+            }
+            sentence.toString()
+        }
+        return Pair(sentences, emails)
+    }
+
+    /** Count regex matches across sentences[from until to] */
+    fun countRegexMatches(sentences: List<String>, from: Int, to: Int, pattern: java.util.regex.Pattern): Int {
+        var matches = 0
+        val matcher = pattern.matcher("")
+        for (i in from until to) {
+            matcher.reset(sentences[i])
+            while (matcher.find()) {
+                matches++
+            }
+        }
+        return matches
+    }
+
+    /**
+     * Sequential SHA-256 hash chain: H(H(...H(seed)...)), [depth] rounds
+     *
+     * The first round hashes the whole seed; every later round hashes the previous 32-byte digest.
+     * Each round depends on the last, so the chain can't be parallelized.
+     */
+    fun sha256Chain(seed: ByteArray, depth: Int): ByteArray {
+        val digest = java.security.MessageDigest.getInstance("SHA-256")
+        val buffer = seed.copyOf(maxOf(seed.size, 32))
+        var length = seed.size
+        repeat(depth) {
+            digest.update(buffer, 0, length)
+            digest.digest(buffer, 0, 32)
+            length = 32
+        }
+        return buffer.copyOf(if (depth > 0) 32 else seed.size)
+    }
+
+    /** Passes over the buffer per CRC-32 measurement (the checksum is too fast for one pass to time) */
+    const val CRC32_PASSES = 64
+
+    /** CRC-32 chunk size for the incremental variant */
+    const val CRC32_CHUNK_BYTES = 64 * 1024
+
+    /** CRC-32 of data[from until to] in a single update call */
+    fun crc32(data: ByteArray, from: Int = 0, to: Int = data.size): Long {
+        val crc = java.util.zip.CRC32()
+        crc.update(data, from, to - from)
+        return crc.value
+    }
+
+    /** CRC-32 of data[from until to], updated in chunks of [chunkBytes] */
+    fun crc32Incremental(data: ByteArray, from: Int = 0, to: Int = data.size, chunkBytes: Int = CRC32_CHUNK_BYTES): Long {
+        val crc = java.util.zip.CRC32()
+        var offset = from
+        while (offset < to) {
+            val length = minOf(chunkBytes, to - offset)
+            crc.update(data, offset, length)
+            offset += length
+        }
+        return crc.value
+    }
+
+    /** Encode/decode passes over the buffer per Base64 measurement */
+    const val BASE64_PASSES = 8
+
+    /**
+     * Base64-encode data[from until to] (no line wrapping) and decode it again
+     *
+     * @return The decoded bytes, equal to data[from until to] on a correct round trip
+     */
+    fun base64RoundTrip(data: ByteArray, from: Int = 0, to: Int = data.size): ByteArray {
+        val encoded = android.util.Base64.encode(data, from, to - from, android.util.Base64.NO_WRAP)
+        return android.util.Base64.decode(encoded, android.util.Base64.NO_WRAP)
+    }
+
+    /** Check decoded matches data[from until to] byte for byte */
+    fun rangeEquals(data: ByteArray, from: Int, to: Int, decoded: ByteArray): Boolean {
+        if (decoded.size != to - from) return false
+        for (i in decoded.indices) {
+            if (decoded[i] != data[from + i]) return false
+        }
+        return true
+    }
+
+    /** Below this many elements the parallel merge sort recurses sequentially */
+    const val PARALLEL_MERGE_SORT_THRESHOLD = 4096
+
+    /**
+     * Classic top-down merge sort of data[from until to]
+     *
+     * @param scratch Buffer of at least data.size entries
+     */
+    fun mergeSort(data: IntArray, scratch: IntArray, from: Int = 0, to: Int = data.size) {
+        if (to - from < 2) return
+        val mid = (from + to) ushr 1
+        mergeSort(data, scratch, from, mid)
+        mergeSort(data, scratch, mid, to)
+        mergeRuns(data, scratch, from, mid, to)
+    }
+
+    /**
+     * Merge sort that sorts both halves in parallel above [threshold] elements
+     *
+     * @param scratch Buffer of at least data.size entries (halves use disjoint ranges)
+     */
+    suspend fun parallelMergeSort(
+        data: IntArray,
+        scratch: IntArray,
+        dispatcher: kotlinx.coroutines.CoroutineDispatcher,
+        from: Int = 0,
+        to: Int = data.size,
+        threshold: Int = PARALLEL_MERGE_SORT_THRESHOLD
+    ) {
+        if (to - from <= threshold) {
+            mergeSort(data, scratch, from, to)
+            return
+        }
+        val mid = (from + to) ushr 1
+        kotlinx.coroutines.coroutineScope {
+            val left = async(dispatcher) { parallelMergeSort(data, scratch, dispatcher, from, mid, threshold) }
+            parallelMergeSort(data, scratch, dispatcher, mid, to, threshold)
+            left.await()
+        }
+        mergeRuns(data, scratch, from, mid, to)
+    }
+
+    /** Merge sorted runs data[from until mid] and data[mid until to] in place via scratch */
+    private fun mergeRuns(data: IntArray, scratch: IntArray, from: Int, mid: Int, to: Int) {
+        if (data[mid - 1] <= data[mid]) return
+        var i = from
+        var j = mid
+        var k = from
+        while (i < mid && j < to) {
+            scratch[k++] = if (data[i] <= data[j]) data[i++] else data[j++]
+        }
+        while (i < mid) scratch[k++] = data[i++]
+        while (j < to) scratch[k++] = data[j++]
+        System.arraycopy(scratch, from, data, from, to - from)
+    }
+
+    /** Check keys are in ascending (signed) order */
+    fun isSorted(data: IntArray): Boolean {
+        for (i in 1 until data.size) {
+            if (data[i - 1] > data[i]) return false
+        }
+        return true
+    }
+
+    /** Distinct random strings that Levenshtein pairs are drawn from */
+    private const val LEVENSHTEIN_POOL_SIZE = 1024
+
+    /**
+     * Random lowercase strings of length 30-50 and pairs of pool indices to compare
+     * (fixed seed so every device computes the same distances)
+     *
+     * @return String pool and 2 × pairCount indices (pair k is pool[idx[2k]], pool[idx[2k + 1]])
+     */
+    fun generateLevenshteinPairs(pairCount: Int, seed: Long = 0x1E7EL): Pair<Array<ByteArray>, IntArray> {
+        val rng = XorShift128Plus(seed)
+        val pool = Array(LEVENSHTEIN_POOL_SIZE) {
+            ByteArray(30 + rng.nextInt(21)) { ('a' + rng.nextInt(26)).code.toByte() }
+        }
+        val indices = IntArray(pairCount * 2) { rng.nextInt(LEVENSHTEIN_POOL_SIZE) }
+        return Pair(pool, indices)
+    }
+
+    /**
+     * Levenshtein distance by Wagner-Fischer DP, keeping one row of the table
+     *
+     * @param row Scratch row of at least b.size + 1 entries (reused between calls)
+     */
+    fun levenshtein(a: ByteArray, b: ByteArray, row: IntArray): Int {
+        for (j in 0..b.size) row[j] = j
+        for (i in 1..a.size) {
+            var diagonal = row[0]
+            row[0] = i
+            val ai = a[i - 1]
+            for (j in 1..b.size) {
+                val above = row[j]
+                val substitution = diagonal + if (ai == b[j - 1]) 0 else 1
+                row[j] = minOf(substitution, above + 1, row[j - 1] + 1)
+                diagonal = above
+            }
+        }
+        return row[b.size]
+    }
+
+    /** Sum of Levenshtein distances for pairs [from, to) */
+    fun sumLevenshteinDistances(pool: Array<ByteArray>, indices: IntArray, from: Int, to: Int): Long {
+        val row = IntArray(64)
+        var sum = 0L
+        for (k in from until to) {
+            sum += levenshtein(pool[indices[2 * k]], pool[indices[2 * k + 1]], row)
+        }
+        return sum
+    }
+
+    /** Known-answer checks: identical strings are 0 apart, kitten -> sitting is 3 */
+    fun levenshteinSelfCheck(pool: Array<ByteArray>): Boolean {
+        val row = IntArray(64)
+        return pool.all { levenshtein(it, it, row) == 0 } &&
+            levenshtein("kitten".toByteArray(), "sitting".toByteArray(), row) == 3
+    }
+
+    /**
+     * Random square sparse matrix in CSR form, [nnzPerRow] randomly placed non-zeros per row
+     * (values in [0, 1); seeded so every run multiplies the same matrix)
+     *
+     * @return Triple of (rowOffsets, columns, values): row r is columns/values[rowOffsets[r] until rowOffsets[r + 1]]
+     */
+    fun generateSparseMatrix(size: Int, nnzPerRow: Int = 10): Triple<IntArray, IntArray, DoubleArray> {
+        val rng = XorShift128Plus(0x5A7EL)
+        val rowOffsets = IntArray(size + 1) { it * nnzPerRow }
+        val columns = IntArray(size * nnzPerRow) { rng.nextInt(size) }
+        val values = DoubleArray(size * nnzPerRow) { rng.nextDouble() }
+        return Triple(rowOffsets, columns, values)
+    }
+
+    /** y[rows] = A[rows] · x for a CSR matrix, restricted to rows [fromRow, toRow) */
+    fun sparseMatVec(
+        rowOffsets: IntArray,
+        columns: IntArray,
+        values: DoubleArray,
+        x: DoubleArray,
+        y: DoubleArray,
+        fromRow: Int = 0,
+        toRow: Int = rowOffsets.size - 1
+    ) {
+        for (row in fromRow until toRow) {
+            var sum = 0.0
+            for (k in rowOffsets[row] until rowOffsets[row + 1]) {
+                sum += values[k] * x[columns[k]]
+            }
+            y[row] = sum
+        }
+    }
+
+    /** Check y against A · x computed by scattering the non-zeros (independent of row order) */
+    fun verifySparseMatVec(
+        rowOffsets: IntArray,
+        columns: IntArray,
+        values: DoubleArray,
+        x: DoubleArray,
+        y: DoubleArray
+    ): Boolean {
+        val expected = DoubleArray(y.size)
+        for (row in 0 until rowOffsets.size - 1) {
+            for (k in rowOffsets[row] until rowOffsets[row + 1]) {
+                expected[row] += values[k] * x[columns[k]]
+            }
+        }
+        return expected.indices.all { kotlin.math.abs(expected[it] - y[it]) <= 1e-9 * (1.0 + kotlin.math.abs(expected[it])) }
+    }
+
+    /**
+     * Random 0/1 knapsack instance: weights in [1, 100], values in [1, 1000]
+     *
+     * @return Pair of (weights, values)
+     */
+    fun generateKnapsackInstance(items: Int, seed: Long = 0x4A9L): Pair<IntArray, IntArray> {
+        val rng = XorShift128Plus(seed)
+        val weights = IntArray(items) { 1 + rng.nextInt(100) }
+        val values = IntArray(items) { 1 + rng.nextInt(1000) }
+        return Pair(weights, values)
+    }
+
+    /**
+     * Optimal 0/1 knapsack value by DP over a single capacity row (items × capacity cell updates)
+     */
+    fun knapsack01(weights: IntArray, values: IntArray, capacity: Int): Int {
+        val best = IntArray(capacity + 1)
+        for (item in weights.indices) {
+            val weight = weights[item]
+            val value = values[item]
+            // Descending capacity so each item is used at most once
+            for (c in capacity downTo weight) {
+                val candidate = best[c - weight] + value
+                if (candidate > best[c]) best[c] = candidate
+            }
+        }
+        return best[capacity]
+    }
+
+    /** Random edge weights in [1, 100] for a graph from [generateRandomGraph] */
+    fun generateEdgeWeights(edgeCount: Int, seed: Long = 0xD1E5L): IntArray {
+        val rng = XorShift128Plus(seed)
+        return IntArray(edgeCount) { 1 + rng.nextInt(100) }
+    }
+
+    /**
+     * Single-source shortest paths (Dijkstra) with a binary heap and lazy deletion
+     *
+     * @param dist Output distances, one per node; Long.MAX_VALUE for unreachable nodes
+     * @return Number of nodes settled (reachable from [source])
+     */
+    fun dijkstra(offsets: IntArray, targets: IntArray, weights: IntArray, source: Int, dist: LongArray): Int {
+        dist.fill(Long.MAX_VALUE)
+        val settled = BooleanArray(dist.size)
+        // Heap of (distance, node); stale entries are skipped when popped
+        var heapKeys = LongArray(1024)
+        var heapNodes = IntArray(1024)
+        var size = 0
+
+        fun push(key: Long, node: Int) {
+            if (size == heapKeys.size) {
+                heapKeys = heapKeys.copyOf(size * 2)
+                heapNodes = heapNodes.copyOf(size * 2)
+            }
+            var i = size++
+            while (i > 0) {
+                val parent = (i - 1) ushr 1
+                if (heapKeys[parent] <= key) break
+                heapKeys[i] = heapKeys[parent]
+                heapNodes[i] = heapNodes[parent]
+                i = parent
+            }
+            heapKeys[i] = key
+            heapNodes[i] = node
+        }
+
+        dist[source] = 0L
+        push(0L, source)
+        var settledCount = 0
+        while (size > 0) {
+            val key = heapKeys[0]
+            val node = heapNodes[0]
+            // Pop: move the last entry to the root and sift it down
+            size--
+            val lastKey = heapKeys[size]
+            val lastNode = heapNodes[size]
+            var i = 0
+            while (true) {
+                var child = 2 * i + 1
+                if (child >= size) break
+                if (child + 1 < size && heapKeys[child + 1] < heapKeys[child]) child++
+                if (heapKeys[child] >= lastKey) break
+                heapKeys[i] = heapKeys[child]
+                heapNodes[i] = heapNodes[child]
+                i = child
+            }
+            heapKeys[i] = lastKey
+            heapNodes[i] = lastNode
+
+            if (settled[node]) continue
+            settled[node] = true
+            settledCount++
+            for (e in offsets[node] until offsets[node + 1]) {
+                val next = targets[e]
+                val candidate = key + weights[e]
+                if (candidate < dist[next]) {
+                    dist[next] = candidate
+                    push(candidate, next)
+                }
+            }
+        }
+        return settledCount
+    }
+
+    /**
+     * Check Dijkstra output: source at distance 0, no negative distances, and no edge from a
+     * reached node that would still shorten a path
+     */
+    fun verifyShortestPaths(offsets: IntArray, targets: IntArray, weights: IntArray, source: Int, dist: LongArray): Boolean {
+        if (dist[source] != 0L) return false
+        for (node in dist.indices) {
+            val d = dist[node]
+            if (d < 0) return false
+            if (d == Long.MAX_VALUE) continue
+            for (e in offsets[node] until offsets[node + 1]) {
+                if (d + weights[e] < dist[targets[e]]) return false
+            }
+        }
+        return true
+    }
+
+    /**
+     * Random 2D points with 20-bit integer coordinates, packed as (x shl 32) or y so a plain
+     * LongArray sort orders them by x, then y
+     */
+    fun generateHullPoints(count: Int, seed: Long = 0xC0411L): LongArray {
+        val rng = XorShift128Plus(seed)
+        return LongArray(count) {
+            (rng.nextInt(1 shl 20).toLong() shl 32) or rng.nextInt(1 shl 20).toLong()
+        }
+    }
+
+    private fun hullX(point: Long): Long = point ushr 32
+
+    private fun hullY(point: Long): Long = point and 0xFFFFFFFFL
+
+    /** Cross product of (a - o) × (b - o); positive for a counter-clockwise turn */
+    private fun cross(o: Long, a: Long, b: Long): Long =
+        (hullX(a) - hullX(o)) * (hullY(b) - hullY(o)) - (hullY(a) - hullY(o)) * (hullX(b) - hullX(o))
+
+    /**
+     * Convex hull by Andrew's monotone chain
+     *
+     * @param points Packed points from [generateHullPoints]; sorted in place
+     * @return Hull vertices in counter-clockwise order, without collinear points
+     */
+    fun convexHull(points: LongArray): LongArray {
+        points.sort()
+        val n = points.size
+        if (n < 3) return points.copyOf()
+        val hull = LongArray(2 * n)
+        var k = 0
+        // Lower hull
+        for (i in 0 until n) {
+            while (k >= 2 && cross(hull[k - 2], hull[k - 1], points[i]) <= 0) k--
+            hull[k++] = points[i]
+        }
+        // Upper hull
+        val lowerSize = k + 1
+        for (i in n - 2 downTo 0) {
+            while (k >= lowerSize && cross(hull[k - 2], hull[k - 1], points[i]) <= 0) k--
+            hull[k++] = points[i]
+        }
+        // The last point repeats the first
+        return hull.copyOf(k - 1)
+    }
+
+    /** Check every consecutive triple of hull vertices turns strictly counter-clockwise */
+    fun isConvexCounterClockwise(hull: LongArray): Boolean {
+        val n = hull.size
+        if (n < 3) return false
+        for (i in 0 until n) {
+            if (cross(hull[i], hull[(i + 1) % n], hull[(i + 2) % n]) <= 0) return false
+        }
+        return true
+    }
+
+    /** Query passes per Bloom filter measurement (one pass over the keys is too short to time) */
+    const val BLOOM_QUERY_PASSES = 100
+
+    /** Bloom filter bits per inserted key (about 1.7% false positives with 3 hashes) */
+    const val BLOOM_BITS_PER_KEY = 10
+
+    /**
+     * Keys for the Bloom filter benchmark: [count] random 16-letter keys to insert, and [count]
+     * query keys of which the first half are inserted keys and the second half are new
+     *
+     * @return Pair of (inserted, queries)
+     */
+    fun generateBloomKeys(count: Int, seed: Long = 0xB100L): Pair<Array<ByteArray>, Array<ByteArray>> {
+        val rng = XorShift128Plus(seed)
+        fun randomKey() = ByteArray(16) { ('a' + rng.nextInt(26)).code.toByte() }
+        val inserted = Array(count) { randomKey() }
+        val queries = Array(count) { i -> if (i < count / 2) inserted[rng.nextInt(count)] else randomKey() }
+        return Pair(inserted, queries)
+    }
+
+    /**
+     * Query keys [from, to) [passes] times
+     *
+     * @return Positive answers in the last pass among (member queries, non-member queries),
+     * split at [memberCount] (queries below that index are known members)
+     */
+    fun queryBloomFilter(
+        filter: BloomFilter,
+        queries: Array<ByteArray>,
+        from: Int,
+        to: Int,
+        memberCount: Int,
+        passes: Int
+    ): Pair<Int, Int> {
+        var memberHits = 0
+        var falsePositives = 0
+        BenchmarkControl.repeatUntilCancelled(passes) {
+            memberHits = 0
+            falsePositives = 0
+            for (i in from until to) {
+                if (filter.mightContain(queries[i])) {
+                    if (i < memberCount) memberHits++ else falsePositives++
+                }
+            }
+        }
+        return Pair(memberHits, falsePositives)
+    }
+
+    /** Validation passes over the buffer per UTF-8 measurement */
+    const val UTF8_PASSES = 16
+
+    /**
+     * [size] bytes of valid UTF-8: about 70% ASCII, the rest 2-, 3- and 4-byte sequences
+     * (Cyrillic, CJK, emoji); seeded so every device validates the same text
+     */
+    fun generateUtf8Buffer(size: Int, seed: Long = 0x07F8L): ByteArray {
+        val rng = XorShift128Plus(seed)
+        val out = ByteArray(size)
+        var i = 0
+        while (i < size) {
+            val kind = rng.nextInt(10)
+            when {
+                kind < 7 || size - i < 4 -> out[i++] = (0x20 + rng.nextInt(95)).toByte()
+                kind == 7 -> {
+                    val cp = 0x0400 + rng.nextInt(0x100)
+                    out[i++] = (0xC0 or (cp shr 6)).toByte()
+                    out[i++] = (0x80 or (cp and 0x3F)).toByte()
+                }
+                kind == 8 -> {
+                    val cp = 0x4E00 + rng.nextInt(0x5000)
+                    out[i++] = (0xE0 or (cp shr 12)).toByte()
+                    out[i++] = (0x80 or ((cp shr 6) and 0x3F)).toByte()
+                    out[i++] = (0x80 or (cp and 0x3F)).toByte()
+                }
+                else -> {
+                    val cp = 0x1F600 + rng.nextInt(0x50)
+                    out[i++] = (0xF0 or (cp shr 18)).toByte()
+                    out[i++] = (0x80 or ((cp shr 12) and 0x3F)).toByte()
+                    out[i++] = (0x80 or ((cp shr 6) and 0x3F)).toByte()
+                    out[i++] = (0x80 or (cp and 0x3F)).toByte()
+                }
+            }
+        }
+        return out
+    }
+
+    /**
+     * Validate data[from until to] as UTF-8 with a strict (REPORT) decoder
+     *
+     * @param out Scratch buffer of at least (to - from) chars (reused between calls)
+     */
+    fun isValidUtf8(
+        data: ByteArray,
+        from: Int,
+        to: Int,
+        decoder: java.nio.charset.CharsetDecoder,
+        out: java.nio.CharBuffer
+    ): Boolean {
+        decoder.reset()
+        out.clear()
+        val input = java.nio.ByteBuffer.wrap(data, from, to - from)
+        val result = decoder.decode(input, out, true)
+        return !result.isError && !decoder.flush(out).isError && !input.hasRemaining()
+    }
+
+    /** A UTF-8 decoder that reports malformed or unmappable input instead of replacing it */
+    fun strictUtf8Decoder(): java.nio.charset.CharsetDecoder =
+        Charsets.UTF_8.newDecoder()
+            .onMalformedInput(java.nio.charset.CodingErrorAction.REPORT)
+            .onUnmappableCharacter(java.nio.charset.CodingErrorAction.REPORT)
+
+    /** Move [index] forward past continuation bytes so it starts a UTF-8 sequence */
+    fun alignToUtf8Boundary(data: ByteArray, index: Int): Int {
+        var i = index
+        while (i < data.size && (data[i].toInt() and 0xC0) == 0x80) i++
+        return i
+    }
+
+    /** Serializations of the whole document per JSON serialization measurement */
+    const val JSON_SERIALIZATION_PASSES = 20
+
+    /**
+     * In-memory counterpart of [generateComplexJson]: a {"data": [...]} document holding the same
+     * objects, sized so its serialized form is the same length as the generated string
+     */
+    fun buildComplexJsonDocument(sizeTarget: Int): JSONObject {
+        val data = JSONArray()
+        var currentSize = "{\"data\":[".length
+        var counter = 0
+
+        while (currentSize < sizeTarget) {
+            val objectSize =
+                    "{\"id\":$counter,\"name\":\"obj$counter\",\"nested\":{\"value\":${counter % 1000},\"array\":[1,2,3,4,5]}},".length
+            if (currentSize + objectSize > sizeTarget) break
+
+            data.put(
+                    JSONObject()
+                            .put("id", counter)
+                            .put("name", "obj$counter")
+                            .put(
+                                    "nested",
+                                    JSONObject()
+                                            .put("value", counter % 1000)
+                                            .put("array", JSONArray().put(1).put(2).put(3).put(4).put(5))
+                            )
+            )
+            currentSize += objectSize
+            counter++
+        }
+
+        return JSONObject().put("data", data)
+    }
+
+    /**
+     * Serialize [document] [passes] times
+     *
+     * @return Total characters of serialized output and the last serialized string
+     */
+    fun serializeJsonDocument(document: JSONObject, passes: Int): Pair<Long, String> {
+        var totalChars = 0L
+        var last = ""
+        BenchmarkControl.repeatUntilCancelled(passes) {
+            last = document.toString()
+            totalChars += last.length
+        }
+        return Pair(totalChars, last)
+    }
+
+    /** Random polynomials evaluated per polynomial evaluation run */
+    const val POLYNOMIAL_COUNT = 16
+
+    /**
+     * [count] polynomials of [degree] with coefficients in [-1, 1); coeffs[i] multiplies x^i
+     */
+    fun generatePolynomials(count: Int, degree: Int, seed: Long = 0x9017L): Array<DoubleArray> {
+        val rng = XorShift128Plus(seed)
+        return Array(count) { DoubleArray(degree + 1) { rng.nextDouble() * 2.0 - 1.0 } }
+    }
+
+    /** [count] evaluation points in [-1, 1), where degree-1000 polynomials stay well-conditioned */
+    fun generateEvalPoints(count: Int, seed: Long = 0x9018L): DoubleArray {
+        val rng = XorShift128Plus(seed)
+        return DoubleArray(count) { rng.nextDouble() * 2.0 - 1.0 }
+    }
+
+    /** P(x) by Horner's method: one multiply-add per coefficient */
+    fun hornerEval(coeffs: DoubleArray, x: Double): Double {
+        var result = coeffs[coeffs.size - 1]
+        for (i in coeffs.size - 2 downTo 0) {
+            result = result * x + coeffs[i]
+        }
+        return result
+    }
+
+    /**
+     * Evaluate every polynomial at points[from until to]
+     *
+     * @return Sum of all values (checksum)
+     */
+    fun evaluatePolynomials(polys: Array<DoubleArray>, points: DoubleArray, from: Int = 0, to: Int = points.size): Double {
+        var sum = 0.0
+        for (poly in polys) {
+            for (i in from until to) {
+                sum += hornerEval(poly, points[i])
+            }
+        }
+        return sum
+    }
+
+    /** Check Horner against the naive power sum at the first few points (relative tolerance) */
+    fun verifyHorner(polys: Array<DoubleArray>, points: DoubleArray, samples: Int = 8): Boolean {
+        for (poly in polys) {
+            for (i in 0 until minOf(samples, points.size)) {
+                val x = points[i]
+                var naive = 0.0
+                var power = 1.0
+                var magnitude = 0.0
+                for (c in poly) {
+                    naive += c * power
+                    magnitude += Math.abs(c * power)
+                    power *= x
+                }
+                if (Math.abs(hornerEval(poly, x) - naive) > 1e-9 * (magnitude + 1.0)) return false
+            }
+        }
+        return true
+    }
+
+    /** Trial division bound before falling back to Pollard's rho */
+    const val TRIAL_DIVISION_LIMIT = 10_000L
+
+    /** Random values in [10^9, 10^15] to factorize */
+    fun generateFactorizationInputs(count: Int, seed: Long = 0xFAC7L): LongArray {
+        val rng = XorShift128Plus(seed)
+        val low = 1_000_000_000L
+        val span = 1_000_000_000_000_000L - low + 1
+        return LongArray(count) { low + Math.floorMod(rng.nextLong(), span) }
+    }
+
+    /**
+     * (a * b) mod m without 128-bit arithmetic
+     *
+     * The quotient is estimated in double precision and the remainder corrected with wrapping
+     * Long arithmetic; exact for m < 2^52, which covers the factorization inputs.
+     */
+    fun mulMod(a: Long, b: Long, m: Long): Long {
+        val q = (a.toDouble() * b / m).toLong()
+        var r = a * b - q * m
+        while (r < 0) r += m
+        while (r >= m) r -= m
+        return r
+    }
+
+    private fun powMod(base: Long, exponent: Long, m: Long): Long {
+        var result = 1L
+        var b = base % m
+        var e = exponent
+        while (e > 0) {
+            if (e and 1L == 1L) result = mulMod(result, b, m)
+            b = mulMod(b, b, m)
+            e = e shr 1
+        }
+        return result
+    }
+
+    /** Deterministic Miller-Rabin (the first 12 prime bases cover every n < 3.3 * 10^24) */
+    fun isPrimeMillerRabin(n: Long): Boolean {
+        if (n < 2) return false
+        val bases = longArrayOf(2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37)
+        for (p in bases) {
+            if (n % p == 0L) return n == p
+        }
+        var d = n - 1
+        var s = 0
+        while (d and 1L == 0L) {
+            d = d shr 1
+            s++
+        }
+        for (a in bases) {
+            var x = powMod(a, d, n)
+            if (x == 1L || x == n - 1) continue
+            var composite = true
+            for (r in 1 until s) {
+                x = mulMod(x, x, n)
+                if (x == n - 1) {
+                    composite = false
+                    break
+                }
+            }
+            if (composite) return false
+        }
+        return true
+    }
+
+    /** A non-trivial factor of composite odd [n] by Brent's variant of Pollard's rho */
+    private fun pollardBrent(n: Long, rng: XorShift128Plus): Long {
+        while (true) {
+            val c = 1 + Math.floorMod(rng.nextLong(), n - 1)
+            var y = Math.floorMod(rng.nextLong(), n)
+            var x = y
+            var ys = y
+            var g = 1L
+            var q = 1L
+            var r = 1L
+            val batch = 128
+            while (g == 1L) {
+                x = y
+                repeat(r.toInt()) { y = (mulMod(y, y, n) + c) % n }
+                var k = 0L
+                while (k < r && g == 1L) {
+                    ys = y
+                    val steps = minOf(batch.toLong(), r - k).toInt()
+                    repeat(steps) {
+                        y = (mulMod(y, y, n) + c) % n
+                        q = mulMod(q, Math.abs(x - y), n)
+                    }
+                    g = gcd(q, n)
+                    k += steps
+                }
+                r *= 2
+            }
+            if (g == n) {
+                // The batched product hit 0 mod n; replay one step at a time
+                do {
+                    ys = (mulMod(ys, ys, n) + c) % n
+                    g = gcd(Math.abs(x - ys), n)
+                } while (g == 1L)
+            }
+            if (g != n) return g
+        }
+    }
+
+    /**
+     * Prime factors of [n] (with multiplicity, unordered): trial division up to
+     * [TRIAL_DIVISION_LIMIT], then Pollard's rho with Miller-Rabin on the cofactor
+     */
+    fun factorize(n: Long, rng: XorShift128Plus): LongArray {
+        val factors = ArrayList<Long>()
+        var m = n
+        while (m and 1L == 0L) {
+            factors.add(2L)
+            m = m shr 1
+        }
+        var d = 3L
+        while (d <= TRIAL_DIVISION_LIMIT && d * d <= m) {
+            while (m % d == 0L) {
+                factors.add(d)
+                m /= d
+            }
+            d += 2
+        }
+
+        val pending = ArrayList<Long>()
+        if (m > 1) pending.add(m)
+        while (pending.isNotEmpty()) {
+            val value = pending.removeAt(pending.size - 1)
+            if (value < TRIAL_DIVISION_LIMIT * TRIAL_DIVISION_LIMIT || isPrimeMillerRabin(value)) {
+                // No factor below the trial bound remains, so anything under its square is prime
+                factors.add(value)
+            } else {
+                val factor = pollardBrent(value, rng)
+                pending.add(factor)
+                pending.add(value / factor)
+            }
+        }
+        return factors.toLongArray()
+    }
+
+    /**
+     * Factorize values[from until to]
+     *
+     * @return Total prime factors found, or -1 if any factorization's product doesn't match its input
+     */
+    fun factorizeAll(values: LongArray, from: Int = 0, to: Int = values.size, seed: Long = 0x7240L): Long {
+        val rng = XorShift128Plus(seed)
+        var totalFactors = 0L
+        for (i in from until to) {
+            val factors = factorize(values[i], rng)
+            var product = 1L
+            for (f in factors) product *= f
+            if (product != values[i]) return -1
+            totalFactors += factors.size
+        }
+        return totalFactors
+    }
+
+    /**
+     * Bitwise workload: popcount, parity and bit reversal on a 64-bit value for [iterations]
+     *
+     * The value is rotated by its own popcount and mixed with its reversal each iteration, so
+     * every step depends on the previous one and nothing can be constant-folded.
+     *
+     * @return Checksum, minimum and maximum popcount observed
+     */
+    fun bitwiseOperations(iterations: Long, seed: Long = 0xB175L): Triple<Long, Int, Int> {
+        var x = XorShift128Plus(seed).nextLong()
+        var checksum = 0L
+        var minPopcount = 64
+        var maxPopcount = 0
+        var i = 0L
+        while (i < iterations) {
+            val popcount = java.lang.Long.bitCount(x)
+            val parity = popcount and 1
+            val reversed = java.lang.Long.reverse(x)
+            if (popcount < minPopcount) minPopcount = popcount
+            if (popcount > maxPopcount) maxPopcount = popcount
+            checksum += (reversed xor parity.toLong()) + popcount
+            x = java.lang.Long.rotateLeft(x, popcount) + (reversed xor -0x61C8864680B583EBL)
+            i++
+        }
+        return Triple(checksum, minPopcount, maxPopcount)
+    }
+
+    /** ∫₀¹ exp(-x²) dx = (√π / 2) erf(1) */
+    const val GAUSSIAN_INTEGRAL_EXACT = 0.7468241328124271
+
+    /** ∫₀¹ sin(1/x) dx = sin(1) - Ci(1) */
+    const val SIN_RECIPROCAL_INTEGRAL_EXACT = 0.5040670619069283
+
+    /** Largest accepted |estimate - exact| for the Monte Carlo integrals */
+    const val MONTE_CARLO_INTEGRATION_TOLERANCE = 0.01
+
+    /**
+     * Hit-or-miss sampling of exp(-x²) over the unit square
+     *
+     * @return Points under the curve; estimate = hits / samples
+     */
+    fun sampleGaussianIntegral(samples: Long, seed: Long = 0x6A55L): Long {
+        val rng = XorShift128Plus(seed)
+        var hits = 0L
+        var i = 0L
+        while (i < samples) {
+            val x = rng.nextDouble()
+            val y = rng.nextDouble()
+            if (y < Math.exp(-x * x)) hits++
+            i++
+        }
+        return hits
+    }
+
+    /**
+     * Signed hit-or-miss sampling of sin(1/x) over [0, 1] × [-1, 1]: points between the axis and
+     * the curve count +1 above the axis and -1 below it
+     *
+     * @return Signed hit count; estimate = 2 × hits / samples
+     */
+    fun sampleSinReciprocalIntegral(samples: Long, seed: Long = 0x5191L): Long {
+        val rng = XorShift128Plus(seed)
+        var hits = 0L
+        var i = 0L
+        while (i < samples) {
+            // (0, 1] so 1/x stays finite
+            val x = 1.0 - rng.nextDouble()
+            val y = rng.nextDouble() * 2.0 - 1.0
+            val f = Math.sin(1.0 / x)
+            if (y >= 0.0 && y < f) hits++ else if (y < 0.0 && y > f) hits--
+            i++
+        }
+        return hits
+    }
+
+    /** Largest n whose F(n) fits in an unsigned 128-bit integer */
+    const val MAX_FIBONACCI_N_U128 = 186
+
+    /** Largest n for the arbitrary-precision Fibonacci benchmark */
+    const val MAX_FIBONACCI_N_BIGINT = 500
+
+    /** Arbitrary-precision runs are ~100x slower per call than the Long loop */
+    const val WIDE_FIBONACCI_ITERATION_DIVISOR = 100
+
+    /**
+     * F(n) as an unsigned 128-bit integer held in two Longs, by iterative addition with carry
+     *
+     * @param n Index, clamped to [0, MAX_FIBONACCI_N_U128]
+     * @param out Receives (high, low) words
+     */
+    fun fibonacciU128(n: Int, out: LongArray) {
+        val target = n.coerceIn(0, MAX_FIBONACCI_N_U128)
+        var aHi = 0L
+        var aLo = 0L
+        var bHi = 0L
+        var bLo = 1L
+        repeat(target) {
+            val lo = aLo + bLo
+            val carry = if (java.lang.Long.compareUnsigned(lo, aLo) < 0) 1L else 0L
+            val hi = aHi + bHi + carry
+            aHi = bHi
+            aLo = bLo
+            bHi = hi
+            bLo = lo
+        }
+        out[0] = aHi
+        out[1] = aLo
+    }
+
+    /** The unsigned 128-bit value (high, low) as a BigInteger */
+    fun u128ToBigInteger(high: Long, low: Long): BigInteger =
+        BigInteger(java.lang.Long.toUnsignedString(high))
+            .shiftLeft(64)
+            .or(BigInteger(java.lang.Long.toUnsignedString(low)))
+
+    /**
+     * High 64 bits of the unsigned 128-bit product x·y, from 32-bit partial products
+     * (Math.unsignedMultiplyHigh needs API 35)
+     */
+    private fun unsignedMultiplyHigh(x: Long, y: Long): Long {
+        val mask = 0xFFFFFFFFL
+        val x0 = x and mask
+        val x1 = x ushr 32
+        val y0 = y and mask
+        val y1 = y ushr 32
+        val p01 = x0 * y1
+        val p10 = x1 * y0
+        val middle = ((x0 * y0) ushr 32) + (p01 and mask) + (p10 and mask)
+        return x1 * y1 + (p01 ushr 32) + (p10 ushr 32) + (middle ushr 32)
+    }
+
+    /**
+     * out = x·y for 2×2 matrices of unsigned 128-bit entries, modulo 2^128
+     *
+     * Entry (i, j) is stored as (high, low) at [4i + 2j, 4i + 2j + 1].
+     */
+    private fun multiplyMatrixU128(x: LongArray, y: LongArray, out: LongArray) {
+        for (i in 0 until 2) {
+            for (j in 0 until 2) {
+                var hi = 0L
+                var lo = 0L
+                for (k in 0 until 2) {
+                    val a = 4 * i + 2 * k
+                    val b = 4 * k + 2 * j
+                    val productLo = x[a + 1] * y[b + 1]
+                    val productHi = unsignedMultiplyHigh(x[a + 1], y[b + 1]) + x[a + 1] * y[b] + x[a] * y[b + 1]
+                    val sumLo = lo + productLo
+                    hi += productHi + if (java.lang.Long.compareUnsigned(sumLo, lo) < 0) 1L else 0L
+                    lo = sumLo
+                }
+                out[4 * i + 2 * j] = hi
+                out[4 * i + 2 * j + 1] = lo
+            }
+        }
+    }
+
+    /**
+     * F(n) as an unsigned 128-bit integer by matrix exponentiation:
+     * [[1, 1], [1, 0]]^n = [[F(n+1), F(n)], [F(n), F(n-1)]], with O(log n) 2×2 multiplications
+     *
+     * Entries are computed modulo 2^128; F(n+1) overflows at n = 186, but F(n) itself is exact.
+     *
+     * @param n Index, clamped to [0, MAX_FIBONACCI_N_U128]
+     * @param out Receives (high, low) words
+     */
+    fun fibonacciMatrixU128(n: Int, out: LongArray) {
+        var remaining = n.coerceIn(0, MAX_FIBONACCI_N_U128)
+        val result = longArrayOf(0, 1, 0, 0, 0, 0, 0, 1) // Identity
+        val base = longArrayOf(0, 1, 0, 1, 0, 1, 0, 0)
+        val product = LongArray(8)
+        while (remaining > 0) {
+            if (remaining and 1 == 1) {
+                multiplyMatrixU128(result, base, product)
+                product.copyInto(result)
+            }
+            remaining = remaining shr 1
+            if (remaining > 0) {
+                multiplyMatrixU128(base, base, product)
+                product.copyInto(base)
+            }
+        }
+        out[0] = result[2]
+        out[1] = result[3]
+    }
+
+    /**
+     * F(n) as a BigInteger by fast doubling:
+     * F(2k) = F(k)(2F(k+1) - F(k)), F(2k+1) = F(k)² + F(k+1)²
+     *
+     * @param n Index, clamped to [0, MAX_FIBONACCI_N_BIGINT]
+     * @return F(n) and the number of BigInteger multiplications performed
+     */
+    fun fibonacciBigInt(n: Int): Pair<BigInteger, Int> {
+        val target = n.coerceIn(0, MAX_FIBONACCI_N_BIGINT)
+        var a = BigInteger.ZERO // F(k)
+        var b = BigInteger.ONE // F(k+1)
+        var multiplications = 0
+        for (bit in 31 - Integer.numberOfLeadingZeros(target.coerceAtLeast(1)) downTo 0) {
+            val c = a.multiply(b.shiftLeft(1).subtract(a))
+            val d = a.multiply(a).add(b.multiply(b))
+            multiplications += 3
+            if ((target shr bit) and 1 == 0) {
+                a = c
+                b = d
+            } else {
+                a = d
+                b = c.add(d)
+            }
+        }
+        return Pair(if (target == 0) BigInteger.ZERO else a, multiplications)
+    }
+
+    /** Format string for the string formatting benchmark: mixes float, integer, hex, scientific and string conversions */
+    const val RECORD_FORMAT = "%08.3f %d %016x %e %s"
+
+    /** Times each record set is formatted in the string formatting benchmarks */
+    const val STRING_FORMAT_PASSES = 10
+
+    /** One row of the string formatting benchmark: a field for each conversion in [RECORD_FORMAT] */
+    class FormatRecord(val ratio: Double, val count: Int, val id: Long, val magnitude: Double, val label: String)
+
+    /** Deterministic records with values spread over several orders of magnitude */
+    fun generateFormatRecords(count: Int, seed: Long): Array<FormatRecord> {
+        val rng = XorShift128Plus(seed)
+        return Array(count) { i ->
+            FormatRecord(
+                ratio = rng.nextDouble() * 10_000.0,
+                count = rng.nextInt(),
+                id = rng.nextLong(),
+                magnitude = (rng.nextDouble() - 0.5) * Math.pow(10.0, (rng.nextInt(40) - 20).toDouble()),
+                label = "item-$i"
+            )
+        }
+    }
+
+    /**
+     * Format records [from, to) with [RECORD_FORMAT], [passes] times
+     *
+     * @return Total bytes (UTF-8) produced, and the number of empty strings (expected 0)
+     */
+    fun formatRecords(records: Array<FormatRecord>, from: Int, to: Int, passes: Int): Pair<Long, Int> {
+        var totalBytes = 0L
+        var emptyCount = 0
+        BenchmarkControl.repeatUntilCancelled(passes) {
+            for (i in from until to) {
+                val r = records[i]
+                val formatted = String.format(java.util.Locale.ROOT, RECORD_FORMAT, r.ratio, r.count, r.id, r.magnitude, r.label)
+                if (formatted.isEmpty()) emptyCount++
+                // Output is ASCII only, so the UTF-8 byte count is the length
+                totalBytes += formatted.length
+            }
+        }
+        return Pair(totalBytes, emptyCount)
+    }
+
+    /**
+     * [iterations] rounds of fetch-and-add, compare-and-set and load on [counter]
+     *
+     * Each round adds 1, then tries to bump the value it just produced by another 1, so
+     * uncontended the counter ends at 2 × iterations. Under contention the CAS fails whenever
+     * another thread got in between, and the final value is iterations plus the successful CAS
+     * count.
+     *
+     * @return Number of successful compare-and-set operations, and an XOR checksum of the loads
+     */
+    fun atomicOps(counter: AtomicLong, iterations: Long): Pair<Long, Long> {
+        var casSuccesses = 0L
+        var observed = 0L
+        for (i in 0 until iterations) {
+            val next = counter.getAndAdd(1L) + 1L
+            if (counter.compareAndSet(next, next + 1L)) casSuccesses++
+            observed = observed xor counter.get()
+        }
+        return Pair(casSuccesses, observed)
+    }
+
+    /** Channel benchmarks send integerOpsCount / this many messages (a queue hand-off costs ~100x a bitwise op) */
+    const val CHANNEL_MESSAGE_DIVISOR = 100L
+
+    /** One channel message: a 64-bit payload tagged with its sender, sequence number and send time */
+    class ChannelMessage(val producer: Int, val sequence: Long, val sentAtNs: Long)
+
+    /** Send [count] messages from [producer] into [queue], stamped with their send time */
+    fun produceMessages(queue: BlockingQueue<ChannelMessage>, producer: Int, count: Long) {
+        for (sequence in 0 until count) {
+            queue.put(ChannelMessage(producer, sequence, System.nanoTime()))
+        }
+    }
+
+    /**
+     * Receive [count] messages from [queue]
+     *
+     * @return Per-message latency in nanoseconds (in arrival order), and whether every
+     * producer's messages arrived in sequence
+     */
+    fun consumeMessages(
+        queue: BlockingQueue<ChannelMessage>,
+        count: Int,
+        producers: Int
+    ): Pair<LongArray, Boolean> {
+        val latencies = LongArray(count)
+        val nextSequence = LongArray(producers)
+        var inOrder = true
+        for (i in 0 until count) {
+            val message = queue.take()
+            latencies[i] = System.nanoTime() - message.sentAtNs
+            if (message.sequence != nextSequence[message.producer]) inOrder = false
+            nextSequence[message.producer] = message.sequence + 1
+        }
+        return Pair(latencies, inOrder)
+    }
+
+    /** Nearest-rank percentile ([p] in 0-100) of an ascending-sorted array */
+    fun percentileOfSorted(sorted: LongArray, p: Double): Long {
+        if (sorted.isEmpty()) return 0L
+        val rank = Math.ceil(p / 100.0 * sorted.size).toInt().coerceIn(1, sorted.size)
+        return sorted[rank - 1]
+    }
+
+    /** Times each hash map benchmark rebuilds its map from scratch */
+    const val HASHMAP_PASSES = 20
+
+    /** Distinct string keys (the index makes them unique, the random suffix spreads the hashes) */
+    fun generateHashMapKeys(count: Int, seed: Long): Array<String> {
+        val rng = XorShift128Plus(seed)
+        return Array(count) { i -> "key-$i-${java.lang.Long.toHexString(rng.nextLong())}" }
+    }
+
+    /**
+     * Insert keys [from, to) into [map] (value = index), then look each one up
+     *
+     * @return Number of lookups that found the value inserted for that key
+     */
+    fun insertAndLookup(map: MutableMap<String, Int>, keys: Array<String>, from: Int, to: Int): Int {
+        for (i in from until to) {
+            map[keys[i]] = i
+        }
+        var hits = 0
+        for (i in from until to) {
+            if (map[keys[i]] == i) hits++
+        }
+        return hits
+    }
+
+    /**
+     * Fraction of [keys] that land in an already-occupied bucket of a java.util.HashMap sized for
+     * them (same hash spreading and default 0.75 load factor)
+     */
+    fun hashCollisionRate(keys: Array<String>): Double {
+        if (keys.isEmpty()) return 0.0
+        var capacity = 16
+        while (capacity * 0.75 < keys.size) capacity = capacity shl 1
+        val occupied = BooleanArray(capacity)
+        var collisions = 0
+        for (key in keys) {
+            val h = key.hashCode()
+            val bucket = (h xor (h ushr 16)) and (capacity - 1)
+            if (occupied[bucket]) collisions++ else occupied[bucket] = true
+        }
+        return collisions.toDouble() / keys.size
+    }
+
+    /** Allocation benchmarks do integerOpsCount / this many allocations in the mixed-size run */
+    const val ALLOCATION_DIVISOR = 100L
+
+    /** Each size-class run does 1/this of the mixed-size allocations */
+    const val ALLOCATION_CLASS_DIVISOR = 10L
+
+    /** Allocations stay reachable for this many iterations, so they can't be optimized away */
+    private const val ALLOCATION_RING_SIZE = 64
+
+    /**
+     * Allocate [iterations] byte arrays of random size in [minSize, maxSize], touching each and
+     * dropping it a few iterations later
+     *
+     * @return Total bytes allocated
+     */
+    fun allocationLoop(iterations: Long, minSize: Int, maxSize: Int, seed: Long): Long {
+        val rng = XorShift128Plus(seed)
+        val ring = arrayOfNulls<ByteArray>(ALLOCATION_RING_SIZE)
+        val span = maxSize - minSize + 1
+        var totalBytes = 0L
+        for (i in 0 until iterations) {
+            val size = minSize + rng.nextInt(span)
+            val block = ByteArray(size)
+            block[size - 1] = i.toByte()
+            ring[(i % ALLOCATION_RING_SIZE).toInt()] = block
+            totalBytes += size
+        }
+        return totalBytes
+    }
+
+    /** Largest board for the 64-bit bitmask N-Queens solver (larger boards take hours) */
+    const val MAX_NQUEENS_BITMASK_SIZE = 16
+
+    /** Board size both N-Queens solvers run on for the array-vs-bitmask comparison */
+    const val NQUEENS_COMPARISON_SIZE = 12
+
+    /** Largest board for the dancing-links N-Queens solver (a 14 board already takes seconds) */
+    const val MAX_NQUEENS_DLX_SIZE = 13
+
+    /** Known N-Queens solution counts, indexed by board size (0 unused) */
+    val NQUEENS_KNOWN_SOLUTIONS = longArrayOf(
+        1L, 1L, 0L, 0L, 2L, 10L, 4L, 40L, 92L, 352L, 724L, 2_680L, 14_200L, 73_712L, 365_596L,
+        2_279_184L, 14_772_512L
+    )
+
+    /**
+     * Count N-Queens solutions with 64-bit occupancy masks and an explicit stack (Knuth-style,
+     * no recursion)
+     *
+     * @param size Board size, clamped to [1, MAX_NQUEENS_BITMASK_SIZE]
+     * @param firstColumn If >= 0, only count solutions with the first-row queen in this column
+     */
+    fun solveNQueensBitmask(size: Int, firstColumn: Int = -1): Long {
+        val n = size.coerceIn(1, MAX_NQUEENS_BITMASK_SIZE)
+        val all = (1L shl n) - 1
+        val cols = LongArray(n + 1)
+        val diag1 = LongArray(n + 1)
+        val diag2 = LongArray(n + 1)
+        val available = LongArray(n + 1)
+
+        var row = 0
+        if (firstColumn >= 0) {
+            if (firstColumn >= n) return 0L
+            if (n == 1) return 1L
+            val bit = 1L shl firstColumn
+            cols[1] = bit
+            diag1[1] = bit shl 1
+            diag2[1] = bit ushr 1
+            row = 1
+        }
+
+        val startRow = row
+        var solutions = 0L
+        available[row] = all and (cols[row] or diag1[row] or diag2[row]).inv()
+        while (row >= startRow) {
+            val free = available[row]
+            if (free == 0L) {
+                row--
+                continue
+            }
+            val bit = free and -free
+            available[row] = free xor bit
+            if (row == n - 1) {
+                solutions++
+                continue
+            }
+            val next = row + 1
+            cols[next] = cols[row] or bit
+            diag1[next] = (diag1[row] or bit) shl 1
+            diag2[next] = (diag2[row] or bit) ushr 1
+            available[next] = all and (cols[next] or diag1[next] or diag2[next]).inv()
+            row = next
+        }
+        return solutions
+    }
+
+    /** Count N-Queens solutions with boolean column / diagonal arrays (baseline for the bitmask solver) */
+    fun solveNQueensArrays(size: Int): Long {
+        val cols = BooleanArray(size)
+        val diag1 = BooleanArray(2 * size - 1)
+        val diag2 = BooleanArray(2 * size - 1)
+
+        fun place(row: Int): Long {
+            if (row == size) return 1L
+            var solutions = 0L
+            for (col in 0 until size) {
+                val d1 = row + col
+                val d2 = row - col + size - 1
+                if (cols[col] || diag1[d1] || diag2[d2]) continue
+                cols[col] = true
+                diag1[d1] = true
+                diag2[d2] = true
+                solutions += place(row + 1)
+                cols[col] = false
+                diag1[d1] = false
+                diag2[d2] = false
+            }
+            return solutions
+        }
+
+        return place(0)
+    }
+
+    /** Side of the small image used to check anti-aliasing variance (kept small so it's cheap) */
+    const val RAY_TRACING_VARIANCE_SIZE = 64
+
+    /**
+     * Render [scene] from a fixed pinhole camera, averaging [spp] randomly jittered rays per pixel
+     *
+     * @param image Receives the averaged r, g, b per pixel (size width × height × 3)
+     * @return Sum of all channel values (checksum)
+     */
+    fun renderScene(
+        scene: RayTracingScene,
+        width: Int,
+        height: Int,
+        spp: Int,
+        maxDepth: Int,
+        seed: Long,
+        image: DoubleArray
+    ): Double {
+        val rng = XorShift128Plus(seed)
+        val aspect = width.toDouble() / height
+        val tanHalfFov = Math.tan(Math.toRadians(30.0))
+        val color = DoubleArray(3)
+        var checksum = 0.0
+
+        for (y in 0 until height) {
+            for (x in 0 until width) {
+                color[0] = 0.0
+                color[1] = 0.0
+                color[2] = 0.0
+                repeat(spp) {
+                    val u = ((x + rng.nextDouble()) / width * 2.0 - 1.0) * aspect * tanHalfFov
+                    val v = (1.0 - (y + rng.nextDouble()) / height * 2.0) * tanHalfFov
+                    val invLength = 1.0 / Math.sqrt(u * u + v * v + 1.0)
+                    scene.trace(0.0, 0.3, -1.0, u * invLength, v * invLength, invLength, maxDepth, color)
+                }
+                val pixel = (y * width + x) * 3
+                for (channel in 0 until 3) {
+                    image[pixel + channel] = color[channel] / spp
+                    checksum += image[pixel + channel]
+                }
+            }
+        }
+        return checksum
+    }
+
+    /**
+     * Per-pixel variance of a renderer, estimated from two independent renders of the same view:
+     * mean over pixels of Σ(a - b)² / 2
+     */
+    fun renderVariance(a: DoubleArray, b: DoubleArray): Double {
+        var sum = 0.0
+        for (i in a.indices) {
+            val d = a[i] - b[i]
+            sum += d * d
+        }
+        return sum / 2.0 / (a.size / 3)
+    }
+
+    /** Spheres in the BVH ray tracing scene (enough for the hierarchy to pay off) */
+    const val RAY_TRACING_BVH_SPHERES = 50
+
+    /**
+     * Pairwise (tree) reduction of data[from, to) computing sum, max and min together
+     *
+     * Each level combines adjacent pairs in place in the scratch arrays, which must hold at
+     * least (to - from + 1) / 2 elements each.
+     *
+     * @return (sum, max, min); (0, -Inf, +Inf) for an empty range
+     */
+    fun treeReduce(
+        data: DoubleArray,
+        from: Int,
+        to: Int,
+        sums: DoubleArray,
+        maxes: DoubleArray,
+        mins: DoubleArray
+    ): Triple<Double, Double, Double> {
+        val n = to - from
+        if (n <= 0) return Triple(0.0, Double.NEGATIVE_INFINITY, Double.POSITIVE_INFINITY)
+
+        var count = (n + 1) / 2
+        for (i in 0 until count) {
+            val a = data[from + 2 * i]
+            if (from + 2 * i + 1 < to) {
+                val b = data[from + 2 * i + 1]
+                sums[i] = a + b
+                maxes[i] = Math.max(a, b)
+                mins[i] = Math.min(a, b)
+            } else {
+                sums[i] = a
+                maxes[i] = a
+                mins[i] = a
+            }
+        }
+
+        while (count > 1) {
+            val pairs = count / 2
+            for (i in 0 until pairs) {
+                sums[i] = sums[2 * i] + sums[2 * i + 1]
+                maxes[i] = Math.max(maxes[2 * i], maxes[2 * i + 1])
+                mins[i] = Math.min(mins[2 * i], mins[2 * i + 1])
+            }
+            if (count % 2 == 1) {
+                sums[pairs] = sums[count - 1]
+                maxes[pairs] = maxes[count - 1]
+                mins[pairs] = mins[count - 1]
+            }
+            count = (count + 1) / 2
+        }
+        return Triple(sums[0], maxes[0], mins[0])
+    }
+
+    /** Sequential left-to-right sum, max and min of data[from, to) */
+    fun sequentialReduce(data: DoubleArray, from: Int, to: Int): Triple<Double, Double, Double> {
+        var sum = 0.0
+        var max = Double.NEGATIVE_INFINITY
+        var min = Double.POSITIVE_INFINITY
+        for (i in from until to) {
+            val value = data[i]
+            sum += value
+            if (value > max) max = value
+            if (value < min) min = value
+        }
+        return Triple(sum, max, min)
+    }
+
+    /** Rounding tolerance for comparing two summation orders of [count] values totalling [sum] */
+    fun reductionTolerance(count: Int, sum: Double): Double = count * Math.ulp(Math.abs(sum))
+
+    /** LZ77 is far slower per byte than RLE: compressionIterations / this many passes */
+    const val LZ77_ITERATION_DIVISOR = 100
+
+    /** Huffman passes per run: compressionIterations / this many */
+    const val HUFFMAN_ITERATION_DIVISOR = 40
+
+    /** Functions timed by the transcendental benchmarks, in run order */
+    val TRANSCENDENTAL_FUNCTIONS = arrayOf("sin", "cos", "exp", "ln", "sqrt")
+
+    /** Inputs per function, cycled through (power of two so the index is a mask) */
+    const val TRANSCENDENTAL_INPUT_COUNT = 1_024
+
+    /**
+     * Evenly spaced inputs across the range each function is usually called on: several periods
+     * for sin / cos (so argument reduction is exercised), [-50, 50] for exp (the sum stays
+     * finite), (0, 1e6] for ln and [0, 1e6] for sqrt
+     */
+    fun transcendentalInputs(function: Int): DoubleArray {
+        val (lo, hi) =
+            when (function) {
+                0, 1 -> Pair(-16 * Math.PI, 16 * Math.PI)
+                2 -> Pair(-50.0, 50.0)
+                3 -> Pair(1e-6, 1e6)
+                else -> Pair(0.0, 1e6)
+            }
+        return DoubleArray(TRANSCENDENTAL_INPUT_COUNT) { lo + (hi - lo) * it / (TRANSCENDENTAL_INPUT_COUNT - 1) }
+    }
+
+    /**
+     * Evaluate [TRANSCENDENTAL_FUNCTIONS]\[function] [count] times, cycling through [inputs]
+     * from [offset]
+     *
+     * @return Sum of the results, so the calls can't be eliminated
+     */
+    fun evaluateTranscendental(function: Int, inputs: DoubleArray, count: Long, offset: Int = 0): Double {
+        val mask = inputs.size - 1
+        var index = offset and mask
+        var sum = 0.0
+        // One loop per function so the call isn't behind a branch
+        when (function) {
+            0 ->
+                for (i in 0 until count) {
+                    sum += Math.sin(inputs[index])
+                    index = (index + 1) and mask
+                }
+            1 ->
+                for (i in 0 until count) {
+                    sum += Math.cos(inputs[index])
+                    index = (index + 1) and mask
+                }
+            2 ->
+                for (i in 0 until count) {
+                    sum += Math.exp(inputs[index])
+                    index = (index + 1) and mask
+                }
+            3 ->
+                for (i in 0 until count) {
+                    sum += Math.log(inputs[index])
+                    index = (index + 1) and mask
+                }
+            else ->
+                for (i in 0 until count) {
+                    sum += Math.sqrt(inputs[index])
+                    index = (index + 1) and mask
+                }
+        }
+        return sum
+    }
+
+    /** Times each comparator sorts the Unicode corpus per run */
+    const val UNICODE_SORT_PASSES = 4
+
+    /**
+     * [count] strings of 8-24 characters: about 40% plain ASCII letters, the rest mixing ASCII
+     * with Cyrillic (both cases), CJK and Arabic characters; seeded so every device sorts the
+     * same corpus
+     */
+    fun generateUnicodeStrings(count: Int, seed: Long = 0x5027L): Array<String> {
+        val rng = XorShift128Plus(seed)
+        val letters = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ"
+        return Array(count) {
+            val asciiOnly = rng.nextInt(10) < 4
+            val chars = CharArray(8 + rng.nextInt(17))
+            for (i in chars.indices) {
+                val kind = if (asciiOnly) 0 else rng.nextInt(6)
+                chars[i] =
+                    when (kind) {
+                        3 -> (0x0410 + rng.nextInt(0x40)).toChar()
+                        4 -> (0x4E00 + rng.nextInt(0x5000)).toChar()
+                        5 -> (0x0621 + rng.nextInt(0x2A)).toChar()
+                        else -> letters[rng.nextInt(letters.length)]
+                    }
+            }
+            String(chars)
+        }
+    }
+
+    /**
+     * Top-down merge sort of data[from until to] by [comparator] (stable)
+     *
+     * @param scratch Buffer of at least data.size entries
+     */
+    fun <T> mergeSort(
+        data: Array<T>,
+        scratch: Array<T>,
+        comparator: Comparator<in T>,
+        from: Int = 0,
+        to: Int = data.size
+    ) {
+        if (to - from < 2) return
+        val mid = (from + to) ushr 1
+        mergeSort(data, scratch, comparator, from, mid)
+        mergeSort(data, scratch, comparator, mid, to)
+        if (comparator.compare(data[mid - 1], data[mid]) <= 0) return
+        var i = from
+        var j = mid
+        var k = from
+        while (i < mid && j < to) {
+            scratch[k++] = if (comparator.compare(data[i], data[j]) <= 0) data[i++] else data[j++]
+        }
+        while (i < mid) scratch[k++] = data[i++]
+        while (j < to) scratch[k++] = data[j++]
+        System.arraycopy(scratch, from, data, from, to - from)
+    }
+
+    /** Check [data] is in non-decreasing order by [comparator] */
+    fun <T> isSorted(data: Array<T>, comparator: Comparator<in T>): Boolean {
+        for (i in 1 until data.size) {
+            if (comparator.compare(data[i - 1], data[i]) > 0) return false
+        }
+        return true
+    }
+
+    /** Haar round trips per wavelet run: scanIterations / this many */
+    const val WAVELET_ITERATION_DIVISOR = 5
+
+    private val INV_SQRT2 = 1.0 / Math.sqrt(2.0)
+
+    /** Smallest power of two >= [count] (at least 2), for transforms that need power-of-two lengths */
+    fun nextPowerOfTwo(count: Int): Int = Integer.highestOneBit((count - 1).coerceAtLeast(1)) shl 1
+
+    /** Seeded signal in [-1, 1) for the wavelet benchmarks */
+    fun generateWaveletSignal(n: Int, seed: Long = 0x4AA2L): DoubleArray {
+        val rng = XorShift128Plus(seed)
+        return DoubleArray(n) { rng.nextDouble() * 2.0 - 1.0 }
+    }
+
+    /**
+     * Full multi-level orthonormal Haar transform of data[from until from + length] in place
+     *
+     * Each level replaces the first len samples with len / 2 averages followed by len / 2
+     * details, (a ± b) / √2, down to a single average.
+     *
+     * @param length Power of two
+     * @param scratch Buffer of at least [length] entries
+     */
+    fun haarForward(data: DoubleArray, scratch: DoubleArray, from: Int = 0, length: Int = data.size) {
+        var len = length
+        while (len >= 2) {
+            val half = len / 2
+            for (i in 0 until half) {
+                val a = data[from + 2 * i]
+                val b = data[from + 2 * i + 1]
+                scratch[i] = (a + b) * INV_SQRT2
+                scratch[half + i] = (a - b) * INV_SQRT2
+            }
+            System.arraycopy(scratch, 0, data, from, len)
+            len = half
+        }
+    }
+
+    /** Invert [haarForward] in place */
+    fun haarInverse(data: DoubleArray, scratch: DoubleArray, from: Int = 0, length: Int = data.size) {
+        var len = 2
+        while (len <= length) {
+            val half = len / 2
+            for (i in 0 until half) {
+                val avg = data[from + i]
+                val detail = data[from + half + i]
+                scratch[2 * i] = (avg + detail) * INV_SQRT2
+                scratch[2 * i + 1] = (avg - detail) * INV_SQRT2
+            }
+            System.arraycopy(scratch, 0, data, from, len)
+            len *= 2
+        }
+    }
+
+    /** Largest absolute difference between data[from until from + length] and the same range of [reference] */
+    fun maxAbsDifference(data: DoubleArray, reference: DoubleArray, from: Int = 0, length: Int = data.size): Double {
+        var maxError = 0.0
+        for (i in from until from + length) {
+            maxError = kotlin.math.max(maxError, kotlin.math.abs(data[i] - reference[i]))
+        }
+        return maxError
+    }
+
+    /** Times each dedup benchmark rebuilds its set from scratch */
+    const val DEDUP_PASSES = 20
+
+    /** Shards per thread in the multi-core dedup, so two threads rarely want the same lock */
+    const val DEDUP_SHARDS_PER_THREAD = 4
+
+    /**
+     * [count] strings of which about [duplicateFraction] repeat one of the others, in random order
+     *
+     * Duplicates are fresh copies of a unique string, so the set has to compare contents rather
+     * than stop at a reference match.
+     *
+     * @return The strings and how many of them are distinct
+     */
+    fun generateDedupStrings(count: Int, duplicateFraction: Double, seed: Long = 0xDED0L): Pair<Array<String>, Int> {
+        val rng = XorShift128Plus(seed)
+        val uniqueCount = Math.round(count * (1.0 - duplicateFraction)).toInt().coerceIn(1, count)
+        val uniques = Array(uniqueCount) { i -> "entry-$i-${java.lang.Long.toHexString(rng.nextLong())}" }
+        val strings = Array(count) { i -> if (i < uniqueCount) uniques[i] else String(uniques[rng.nextInt(uniqueCount)].toCharArray()) }
+        // Fisher-Yates, so duplicates are spread through the input rather than trailing it
+        for (i in count - 1 downTo 1) {
+            val j = rng.nextInt(i + 1)
+            val tmp = strings[i]
+            strings[i] = strings[j]
+            strings[j] = tmp
+        }
+        return Pair(strings, uniqueCount)
+    }
+
+    /** Add strings[from, to) to [set], returning how many weren't already in it */
+    fun insertDistinct(set: MutableSet<String>, strings: Array<String>, from: Int, to: Int): Int {
+        var added = 0
+        for (i in from until to) {
+            if (set.add(strings[i])) added++
+        }
+        return added
+    }
+
+    /**
+     * Add strings[from, to) to the shard picked by each string's hash, holding only that shard's
+     * lock for the insert
+     *
+     * @return How many strings weren't already in their shard
+     */
+    fun shardedInsert(shards: Array<HashSet<String>>, strings: Array<String>, from: Int, to: Int): Int {
+        var added = 0
+        for (i in from until to) {
+            val s = strings[i]
+            val shard = shards[(s.hashCode() and Int.MAX_VALUE) % shards.size]
+            if (synchronized(shard) { shard.add(s) }) added++
+        }
+        return added
+    }
+
+    /** Z-order pairs per run: monteCarloSamples / this many */
+    const val ZORDER_DIVISOR = 10
+
+    /** Coordinate pairs kept in memory; longer runs cycle through them with a per-lap salt */
+    const val ZORDER_TABLE_SIZE = 65_536
+
+    /** Spread the 32 bits of [v] to the even bit positions of a Long */
+    fun mortonSpread(v: Int): Long {
+        var x = v.toLong() and 0xFFFFFFFFL
+        x = (x or (x shl 16)) and 0x0000FFFF0000FFFFL
+        x = (x or (x shl 8)) and 0x00FF00FF00FF00FFL
+        x = (x or (x shl 4)) and 0x0F0F0F0F0F0F0F0FL
+        x = (x or (x shl 2)) and 0x3333333333333333L
+        x = (x or (x shl 1)) and 0x5555555555555555L
+        return x
+    }
+
+    /** Gather the even bits of [v] back into an Int (inverse of [mortonSpread]) */
+    fun mortonCompact(v: Long): Int {
+        var x = v and 0x5555555555555555L
+        x = (x or (x ushr 1)) and 0x3333333333333333L
+        x = (x or (x ushr 2)) and 0x0F0F0F0F0F0F0F0FL
+        x = (x or (x ushr 4)) and 0x00FF00FF00FF00FFL
+        x = (x or (x ushr 8)) and 0x0000FFFF0000FFFFL
+        x = (x or (x ushr 16)) and 0x00000000FFFFFFFFL
+        return x.toInt()
+    }
+
+    /** 64-bit Morton code: x in the even bits, y in the odd bits */
+    fun mortonEncode(x: Int, y: Int): Long = mortonSpread(x) or (mortonSpread(y) shl 1)
+
+    /** Seeded unsigned 32-bit (x, y) coordinates for the Z-order benchmarks */
+    fun generateZOrderCoordinates(count: Int, seed: Long = 0x2C0DL): Pair<IntArray, IntArray> {
+        val rng = XorShift128Plus(seed)
+        return Pair(IntArray(count) { rng.nextLong().toInt() }, IntArray(count) { rng.nextLong().toInt() })
+    }
+
+    /**
+     * Encode pairs [firstPair, firstPair + pairs) into [codes], then decode them and compare
+     *
+     * Pair p is (xs[i] xor salt, ys[i] xor salt) with i = p % xs.size and the salt taken from
+     * p / xs.size, so every lap over the table produces different codes.
+     *
+     * @param codes Buffer of at least xs.size entries
+     * @return Pairs whose decoded coordinates didn't match
+     */
+    fun zorderRoundTrips(xs: IntArray, ys: IntArray, codes: LongArray, firstPair: Long, pairs: Long): Long {
+        var mismatches = 0L
+        var p = firstPair
+        val end = firstPair + pairs
+        while (p < end) {
+            val offset = (p % xs.size).toInt()
+            val n = minOf(end - p, (xs.size - offset).toLong()).toInt()
+            val salt = (p / xs.size).toInt() * -0x61C88647 // 0x9E3779B9
+            for (i in 0 until n) {
+                codes[i] = mortonEncode(xs[offset + i] xor salt, ys[offset + i] xor salt)
+            }
+            for (i in 0 until n) {
+                val code = codes[i]
+                if (mortonCompact(code) != xs[offset + i] xor salt || mortonCompact(code ushr 1) != ys[offset + i] xor salt) {
+                    mismatches++
+                }
+            }
+            p += n
+        }
+        return mismatches
+    }
+
+    /**
+     * [blockCount] level-shifted 8×8 sample blocks (-128..127): a random gradient per block plus
+     * ±8 of noise, smooth enough to compress the way photo content does
+     */
+    fun generateJpegBlocks(blockCount: Int, seed: Long = 0x09E6L): DoubleArray {
+        val rng = XorShift128Plus(seed)
+        val samples = DoubleArray(blockCount * Dct8x8.BLOCK_SIZE)
+        for (block in 0 until blockCount) {
+            val base = rng.nextDouble() * 160.0 - 80.0
+            val gradientX = rng.nextDouble() * 8.0 - 4.0
+            val gradientY = rng.nextDouble() * 8.0 - 4.0
+            for (i in 0 until Dct8x8.BLOCK_SIZE) {
+                val value = base + gradientX * (i % 8) + gradientY * (i / 8) + rng.nextDouble() * 16.0 - 8.0
+                samples[block * Dct8x8.BLOCK_SIZE + i] = Math.rint(value).coerceIn(-128.0, 127.0)
+            }
+        }
+        return samples
+    }
+
+    /**
+     * Blocks [fromBlock, toBlock) of [samples] through DCT, quantize / dequantize and IDCT into
+     * the same blocks of [out]
+     *
+     * @param coefficients Scratch block of at least 64 entries
+     */
+    fun jpegDecodeBlocks(
+        dct: Dct8x8,
+        samples: DoubleArray,
+        out: DoubleArray,
+        coefficients: DoubleArray,
+        fromBlock: Int,
+        toBlock: Int
+    ) {
+        for (block in fromBlock until toBlock) {
+            val offset = block * Dct8x8.BLOCK_SIZE
+            dct.forward(samples, offset, coefficients, 0)
+            Dct8x8.quantizeRoundTrip(coefficients, 0)
+            dct.inverse(coefficients, 0, out, offset)
+        }
+    }
+
+    /** Largest error of an unquantized IDCT(DCT(x)) over every block of [samples] */
+    fun dctRoundTripError(samples: DoubleArray): Double {
+        val dct = Dct8x8()
+        val block = DoubleArray(Dct8x8.BLOCK_SIZE)
+        var maxError = 0.0
+        for (offset in samples.indices step Dct8x8.BLOCK_SIZE) {
+            dct.forward(samples, offset, block, 0)
+            dct.inverse(block, 0, block, 0)
+            for (i in 0 until Dct8x8.BLOCK_SIZE) {
+                maxError = kotlin.math.max(maxError, kotlin.math.abs(block[i] - samples[offset + i]))
+            }
+        }
+        return maxError
+    }
+
+    /** Peak signal-to-noise ratio of [decoded] against [reference] for 8-bit samples, in dB */
+    fun psnr(decoded: DoubleArray, reference: DoubleArray): Double {
+        var squaredError = 0.0
+        for (i in reference.indices) {
+            val e = decoded[i] - reference[i]
+            squaredError += e * e
+        }
+        val mse = squaredError / reference.size
+        return if (mse > 0.0) 10.0 * Math.log10(255.0 * 255.0 / mse) else Double.POSITIVE_INFINITY
+    }
+
+    /** Entries the LRU cache benchmarks keep */
+    const val LRU_CAPACITY = 1_000
+
+    /** Distinct keys the LRU benchmarks draw from (10× the capacity) */
+    const val LRU_KEY_SPACE = 10_000
+
+    /** Cache accesses per LRU run: stringSortCount × this many */
+    const val LRU_ACCESS_MULTIPLIER = 100
+
+    /** Accesses kept in memory; longer runs cycle through them */
+    const val LRU_ACCESS_TABLE_SIZE = 1 shl 16
+
+    /** Shards per thread in the multi-core LRU cache */
+    const val LRU_SHARDS_PER_THREAD = 4
+
+    /**
+     * Keys in [0, keySpace) drawn from a Zipf distribution with exponent 1, key 0 the most
+     * popular; for 10,000 keys the top 20% take about 84% of the accesses
+     */
+    fun generateZipfAccesses(count: Int, keySpace: Int, seed: Long = 0x2193L): IntArray {
+        val cumulative = DoubleArray(keySpace)
+        var total = 0.0
+        for (k in 0 until keySpace) {
+            total += 1.0 / (k + 1)
+            cumulative[k] = total
+        }
+        val rng = XorShift128Plus(seed)
+        return IntArray(count) {
+            val i = Arrays.binarySearch(cumulative, rng.nextDouble() * total)
+            if (i >= 0) i else (-i - 1).coerceAtMost(keySpace - 1)
+        }
+    }
+
+    /** Value the LRU benchmarks cache for [key], so every hit can be checked */
+    fun lruValue(key: Int): Int = key * 31 + 7
+
+    /**
+     * Replay [count] accesses against [cache], starting at accesses[start] and wrapping around:
+     * each is a get, followed on a miss by a put of [lruValue]
+     *
+     * @return Hits, and hits that returned the wrong value
+     */
+    fun replayCacheAccesses(cache: IntCache, accesses: IntArray, start: Int, count: Long): Pair<Long, Long> {
+        var hits = 0L
+        var wrongValues = 0L
+        var index = start % accesses.size
+        for (i in 0L until count) {
+            val key = accesses[index]
+            val value = cache.get(key)
+            if (value == IntCache.MISS) {
+                cache.put(key, lruValue(key))
+            } else {
+                hits++
+                if (value != lruValue(key)) wrongValues++
+            }
+            if (++index == accesses.size) index = 0
+        }
+        return Pair(hits, wrongValues)
+    }
+
+    /** Priority queue operations per run: stringSortCount × this many */
+    const val PRIORITY_QUEUE_MULTIPLIER = 20
+
+    /** Share of priority queue operations that insert, in percent (the rest extract the minimum) */
+    const val PRIORITY_QUEUE_INSERT_PERCENT = 70
+
+    /** Shards per thread in the multi-core priority queue */
+    const val PRIORITY_QUEUE_SHARDS_PER_THREAD = 2
+
+    /** Longest delay between the current time and a newly inserted event */
+    const val MAX_EVENT_DELAY = 1_000_000
+
+    /**
+     * Min-heap of event times, used the way a timer queue or Dijkstra's frontier is: every insert
+     * is the time of the last extracted event plus a delay, so extractions must come out
+     * non-decreasing. Not thread-safe.
+     */
+    class EventQueue {
+        private val heap = java.util.PriorityQueue<Long>()
+        private var now = 0L
+
+        /** Extractions that came out earlier than the one before */
+        var outOfOrder = 0L
+            private set
+
+        val size: Int
+            get() = heap.size
+
+        fun schedule(delay: Long) {
+            heap.add(now + delay)
+        }
+
+        /** Remove the earliest event; false if the queue is empty */
+        fun extractMin(): Boolean {
+            val time = heap.poll() ?: return false
+            if (time < now) outOfOrder++
+            now = time
+            return true
+        }
+    }
+
+    /**
+     * [count] operations on [queue]: PRIORITY_QUEUE_INSERT_PERCENT% inserts with a random delay,
+     * the rest extract-min (an insert instead when the queue is empty)
+     *
+     * @return Extractions performed
+     */
+    fun runEventQueueOps(queue: EventQueue, count: Long, seed: Long): Long {
+        val rng = XorShift128Plus(seed)
+        var extracted = 0L
+        for (i in 0L until count) {
+            if (rng.nextInt(100) < PRIORITY_QUEUE_INSERT_PERCENT || !queue.extractMin()) {
+                queue.schedule(rng.nextInt(MAX_EVENT_DELAY).toLong())
+            } else {
+                extracted++
+            }
+        }
+        return extracted
+    }
+
+    /** [runEventQueueOps] with each operation on a random one of [shards], under that shard's lock */
+    fun runShardedEventQueueOps(shards: Array<EventQueue>, count: Long, seed: Long): Long {
+        val rng = XorShift128Plus(seed)
+        var extracted = 0L
+        for (i in 0L until count) {
+            val shard = shards[rng.nextInt(shards.size)]
+            val insert = rng.nextInt(100) < PRIORITY_QUEUE_INSERT_PERCENT
+            val delay = rng.nextInt(MAX_EVENT_DELAY).toLong()
+            synchronized(shard) {
+                if (insert || !shard.extractMin()) shard.schedule(delay) else extracted++
+            }
+        }
+        return extracted
+    }
+
+    /**
+     * One data shape in the compression sweep: each byte repeats the previous one with
+     * [repeatProbability], otherwise it's drawn uniformly from the first [alphabetSize] byte values
+     */
+    class EntropyLevel(val name: String, val alphabetSize: Int, val repeatProbability: Double)
+
+    /** Compression sweep data shapes, lowest entropy first */
+    val COMPRESSION_SWEEP_LEVELS =
+        arrayOf(
+            EntropyLevel("constant", 1, 0.0),
+            EntropyLevel("low", 4, 0.5),
+            EntropyLevel("medium", 16, 0.25),
+            EntropyLevel("high", 256, 0.1),
+            EntropyLevel("random", 256, 0.0)
+        )
+
+    /** [size] bytes shaped by [level] */
+    fun generateEntropyData(size: Int, level: EntropyLevel, seed: Long = 0xE27BL): ByteArray {
+        val rng = XorShift128Plus(seed)
+        val data = ByteArray(size)
+        var previous: Byte = 0
+        for (i in 0 until size) {
+            if (i == 0 || rng.nextDouble() >= level.repeatProbability) {
+                previous = rng.nextInt(level.alphabetSize).toByte()
+            }
+            data[i] = previous
+        }
+        return data
+    }
+
+    /** Order-0 Shannon entropy of [data] in bits per byte (0 for constant data, 8 at most) */
+    fun shannonEntropy(data: ByteArray): Double {
+        if (data.isEmpty()) return 0.0
+        val histogram = LongArray(256)
+        for (b in data) histogram[b.toInt() and 0xFF]++
+        var entropy = 0.0
+        for (count in histogram) {
+            if (count == 0L) continue
+            val p = count.toDouble() / data.size
+            entropy -= p * Math.log(p) / Math.log(2.0)
+        }
+        return entropy
+    }
+
+    /** Bit-reversal passes per run: scanIterations / this many */
+    const val BIT_REVERSAL_ITERATION_DIVISOR = 5
+
+    /** Index [i] with its low [bits] bits reversed */
+    fun reverseBits(i: Int, bits: Int): Int = Integer.reverse(i) ushr (32 - bits)
+
+    /**
+     * Swap every element with the one at its bit-reversed index, in place (the FFT input
+     * reordering). Applying it twice restores the original order.
+     *
+     * @param data Size must be a power of two, at least 2
+     */
+    fun bitReversalPermute(data: DoubleArray) {
+        val bits = Integer.numberOfTrailingZeros(data.size)
+        for (i in data.indices) {
+            val j = reverseBits(i, bits)
+            if (i < j) {
+                val tmp = data[i]
+                data[i] = data[j]
+                data[j] = tmp
+            }
+        }
+    }
+
+    /** Tile edge for the blocked transpose */
+    const val TRANSPOSE_TILE_SIZE = 64
+
+    /** The recursive transpose stops splitting once both sides are at most this long */
+    const val TRANSPOSE_RECURSION_CUTOFF = 16
+
+    /** dst = srcᵀ for n × n row-major matrices, reading src row by row */
+    fun transposeNaive(src: DoubleArray, dst: DoubleArray, n: Int) {
+        for (i in 0 until n) {
+            for (j in 0 until n) {
+                dst[j * n + i] = src[i * n + j]
+            }
+        }
+    }
+
+    /**
+     * dst = srcᵀ one [tile] × [tile] tile at a time, so both the rows read and the columns
+     * written stay in cache; only source rows [fromRow, toRow) are transposed
+     */
+    fun transposeBlocked(src: DoubleArray, dst: DoubleArray, n: Int, tile: Int, fromRow: Int = 0, toRow: Int = n) {
+        var ii = fromRow
+        while (ii < toRow) {
+            val iEnd = minOf(ii + tile, toRow)
+            var jj = 0
+            while (jj < n) {
+                val jEnd = minOf(jj + tile, n)
+                for (i in ii until iEnd) {
+                    for (j in jj until jEnd) {
+                        dst[j * n + i] = src[i * n + j]
+                    }
+                }
+                jj += tile
+            }
+            ii += tile
+        }
+    }
+
+    /**
+     * Cache-oblivious dst = srcᵀ: halve the longer side of the [rowFrom, rowTo) × [colFrom, colTo)
+     * block until it fits under TRANSPOSE_RECURSION_CUTOFF, which keeps the working set small at
+     * every cache level without knowing their sizes
+     */
+    fun transposeRecursive(
+        src: DoubleArray,
+        dst: DoubleArray,
+        n: Int,
+        rowFrom: Int = 0,
+        rowTo: Int = n,
+        colFrom: Int = 0,
+        colTo: Int = n
+    ) {
+        val rows = rowTo - rowFrom
+        val cols = colTo - colFrom
+        if (rows <= TRANSPOSE_RECURSION_CUTOFF && cols <= TRANSPOSE_RECURSION_CUTOFF) {
+            for (i in rowFrom until rowTo) {
+                for (j in colFrom until colTo) {
+                    dst[j * n + i] = src[i * n + j]
+                }
+            }
+        } else if (rows >= cols) {
+            val mid = rowFrom + rows / 2
+            transposeRecursive(src, dst, n, rowFrom, mid, colFrom, colTo)
+            transposeRecursive(src, dst, n, mid, rowTo, colFrom, colTo)
+        } else {
+            val mid = colFrom + cols / 2
+            transposeRecursive(src, dst, n, rowFrom, rowTo, colFrom, mid)
+            transposeRecursive(src, dst, n, rowFrom, rowTo, mid, colTo)
+        }
+    }
+
+    /** Buffer fills per CSPRNG run */
+    const val CRYPTO_RNG_PASSES = 4
+
+    /**
+     * Chi-square of a byte histogram (255 degrees of freedom) above which the output isn't
+     * plausibly uniform: about 10 standard deviations over the expected 255
+     */
+    const val BYTE_CHI_SQUARE_LIMIT = 480.0
+
+    /** Fill [buffer] from [rng], 8 bytes per draw (little endian) */
+    fun fillXorShiftBytes(rng: XorShift128Plus, buffer: ByteArray) {
+        var i = 0
+        while (i < buffer.size) {
+            var bits = rng.nextLong()
+            val end = minOf(i + 8, buffer.size)
+            while (i < end) {
+                buffer[i++] = bits.toByte()
+                bits = bits ushr 8
+            }
+        }
+    }
+
+    /** Pearson chi-square of [buffer]'s byte values against a uniform distribution */
+    fun byteChiSquare(buffer: ByteArray): Double {
+        if (buffer.isEmpty()) return 0.0
+        val histogram = LongArray(256)
+        for (b in buffer) histogram[b.toInt() and 0xFF]++
+        val expected = buffer.size / 256.0
+        var chiSquare = 0.0
+        for (count in histogram) {
+            val d = count - expected
+            chiSquare += d * d / expected
+        }
+        return chiSquare
+    }
+
+    /** Full traversals of the list per linked-list run */
+    const val LINKED_LIST_TRAVERSALS = 100
+
+    /** Singly linked list node; one heap object per element so traversal really chases pointers */
+    class ListNode(val value: Long) {
+        var next: ListNode? = null
+    }
+
+    /**
+     * Allocate [count] nodes (value i × 0x9E3779B97F4A7C15) in one pass and link them, returning
+     * the head
+     *
+     * With [shuffled] the link order is a random permutation of the allocation order, so
+     * consecutive nodes sit at unrelated addresses and the hardware prefetcher can't run ahead;
+     * otherwise each node links to the one allocated after it.
+     */
+    fun buildLinkedList(count: Int, shuffled: Boolean, seed: Long = 0x11A7L): ListNode? {
+        if (count == 0) return null
+        val nodes = Array(count) { i -> ListNode(i * -0x61C8864680B583EBL) }
+        val order = IntArray(count) { it }
+        if (shuffled) {
+            val rng = XorShift128Plus(seed)
+            for (i in count - 1 downTo 1) {
+                val j = rng.nextInt(i + 1)
+                val t = order[i]
+                order[i] = order[j]
+                order[j] = t
+            }
+        }
+        for (i in 0 until count - 1) nodes[order[i]].next = nodes[order[i + 1]]
+        return nodes[order[0]]
+    }
+
+    /** Wrapping sum of the values of a [buildLinkedList] list of [count] nodes */
+    fun expectedLinkedListSum(count: Int): Long {
+        var sum = 0L
+        for (i in 0 until count) sum += i * -0x61C8864680B583EBL
+        return sum
+    }
+
+    /** Walk the list from [head] summing values (wrapping) */
+    fun sumLinkedList(head: ListNode?): Long {
+        var sum = 0L
+        var node = head
+        while (node != null) {
+            sum += node.value
+            node = node.next
+        }
+        return sum
+    }
+
+    /** Entries visited by each B-tree range scan */
+    const val BTREE_RANGE_WIDTH = 100
+
+    /** Phase timings and failed checks of one [runBTreeOps] run */
+    class BTreeOpsResult(val insertNs: Long, val lookupNs: Long, val scanNs: Long, val scannedEntries: Long, val errors: Int)
+
+    /**
+     * [opsPerKind] inserts into an empty sorted map, then [opsPerKind] point lookups of random
+     * inserted keys, then [opsPerKind] range scans of up to BTREE_RANGE_WIDTH entries starting at
+     * random inserted keys
+     *
+     * The map is java.util.TreeMap, the JDK's ordered map (a red-black tree rather than a B-tree,
+     * but the same insert / lookup / ordered-iteration workload). Keys are (seed + i) × an odd
+     * constant, so they're distinct and arrive in scrambled order; each value is its key inverted.
+     * A lookup returning the wrong value, or a scan out of key order, counts as an error.
+     */
+    fun runBTreeOps(opsPerKind: Int, seed: Long): BTreeOpsResult {
+        val keys = LongArray(opsPerKind) { i -> (seed + i) * -0x61C8864680B583EBL }
+        val map = java.util.TreeMap<Long, Long>()
+        val rng = XorShift128Plus(seed)
+        var errors = 0
+
+        var start = System.nanoTime()
+        for (key in keys) map[key] = key.inv()
+        val insertNs = System.nanoTime() - start
+
+        start = System.nanoTime()
+        repeat(opsPerKind) {
+            val key = keys[rng.nextInt(opsPerKind)]
+            if (map[key] != key.inv()) errors++
+        }
+        val lookupNs = System.nanoTime() - start
+
+        var scanned = 0L
+        start = System.nanoTime()
+        repeat(opsPerKind) {
+            val iterator = map.tailMap(keys[rng.nextInt(opsPerKind)], true).entries.iterator()
+            var previous = Long.MIN_VALUE
+            var first = true
+            var remaining = BTREE_RANGE_WIDTH
+            while (remaining > 0 && iterator.hasNext()) {
+                val entry = iterator.next()
+                val key = entry.key
+                if ((!first && key <= previous) || entry.value != key.inv()) errors++
+                previous = key
+                first = false
+                remaining--
+                scanned++
+            }
+        }
+        val scanNs = System.nanoTime() - start
+
+        return BTreeOpsResult(insertNs, lookupNs, scanNs, scanned, errors)
+    }
+
+    /** Length of every string the string-builder benchmark builds */
+    const val STRING_BUILDER_LENGTH = 200
+
+    /** Fragments appended to build them: 16 distinct 10-character pieces */
+    private val STRING_BUILDER_PIECES = Array(16) { p -> String(CharArray(10) { c -> 'a' + (p + c * 7) % 26 }) }
+
+    private fun stringBuilderPiece(string: Int, piece: Int): String =
+        STRING_BUILDER_PIECES[(string + piece) and (STRING_BUILDER_PIECES.size - 1)]
+
+    /**
+     * Build [count] STRING_BUILDER_LENGTH-character strings by appending fragments to a
+     * StringBuilder, sized up front when [presized] and otherwise left to grow from its default
+     * capacity
+     *
+     * @return Sum of the strings' hash codes, equal for every strategy
+     */
+    fun buildStringsAppending(count: Int, presized: Boolean): Long {
+        val pieces = STRING_BUILDER_LENGTH / STRING_BUILDER_PIECES[0].length
+        var checksum = 0L
+        for (i in 0 until count) {
+            val sb = if (presized) StringBuilder(STRING_BUILDER_LENGTH) else StringBuilder()
+            for (j in 0 until pieces) sb.append(stringBuilderPiece(i, j))
+            checksum += sb.toString().hashCode()
+        }
+        return checksum
+    }
+
+    /**
+     * [buildStringsAppending]'s strings built by repeated concatenation instead, each step
+     * allocating a new String holding everything so far
+     */
+    fun buildStringsConcatenating(count: Int): Long {
+        val pieces = STRING_BUILDER_LENGTH / STRING_BUILDER_PIECES[0].length
+        var checksum = 0L
+        for (i in 0 until count) {
+            var s = ""
+            for (j in 0 until pieces) s = "$s${stringBuilderPiece(i, j)}"
+            checksum += s.hashCode()
+        }
+        return checksum
+    }
+
+    /** Float lanes per block in [multiplyAddLanes]: 8 × 32 bits, the shape of a 256-bit f32x8 vector */
+    const val VECTOR_LANES = 8
+
+    /** Operands for the vector benchmark: small integers, so every multiply-add is exact in float */
+    fun generateVectorOperands(size: Int): Triple<FloatArray, FloatArray, FloatArray> =
+        Triple(
+            FloatArray(size) { i -> ((i * 7) and 15).toFloat() },
+            FloatArray(size) { i -> ((i * 13 + 5) and 15).toFloat() },
+            FloatArray(size) { i -> (i and 255).toFloat() }
+        )
+
+    /** c[i] = a[i] × b[i] + c[i], one element at a time */
+    fun multiplyAddScalar(a: FloatArray, b: FloatArray, c: FloatArray) {
+        for (i in c.indices) c[i] = a[i] * b[i] + c[i]
+    }
+
+    /**
+     * [multiplyAddScalar] written as VECTOR_LANES-wide blocks of independent lanes plus a scalar
+     * tail, the portable stand-in for an explicit f32x8 loop. Whether either loop is compiled to
+     * NEON is up to ART; this form gives it straight-line independent lanes to work with.
+     */
+    fun multiplyAddLanes(a: FloatArray, b: FloatArray, c: FloatArray) {
+        val n = c.size
+        val vectorEnd = n - n % VECTOR_LANES
+        var i = 0
+        while (i < vectorEnd) {
+            c[i] = a[i] * b[i] + c[i]
+            c[i + 1] = a[i + 1] * b[i + 1] + c[i + 1]
+            c[i + 2] = a[i + 2] * b[i + 2] + c[i + 2]
+            c[i + 3] = a[i + 3] * b[i + 3] + c[i + 3]
+            c[i + 4] = a[i + 4] * b[i + 4] + c[i + 4]
+            c[i + 5] = a[i + 5] * b[i + 5] + c[i + 5]
+            c[i + 6] = a[i + 6] * b[i + 6] + c[i + 6]
+            c[i + 7] = a[i + 7] * b[i + 7] + c[i + 7]
+            i += VECTOR_LANES
+        }
+        while (i < n) {
+            c[i] = a[i] * b[i] + c[i]
+            i++
+        }
+    }
+
+    /** Elements of [c] that differ from [generateVectorOperands]' c after [passes] multiply-adds */
+    fun countVectorMismatches(a: FloatArray, b: FloatArray, c: FloatArray, passes: Int): Int {
+        var mismatches = 0
+        for (i in c.indices) {
+            if (c[i] != (i and 255) + passes * a[i] * b[i]) mismatches++
+        }
+        return mismatches
+    }
+
+    /** Keys for the counting sort benchmark are drawn from [0, COUNTING_SORT_MAX_VALUE] */
+    const val COUNTING_SORT_MAX_VALUE = 65_535
+
+    /**
+     * Counting sort of keys in [0, counts.size) in place: tally each value, then rewrite [data]
+     * as runs of each value in order. O(n + k) with k = counts.size.
+     *
+     * @param counts Buffer of one entry per possible value; cleared before use
+     */
+    fun countingSort(data: IntArray, counts: IntArray) {
+        counts.fill(0)
+        for (value in data) counts[value]++
+        var out = 0
+        for (value in counts.indices) {
+            val end = out + counts[value]
+            if (end > out) data.fill(value, out, end)
+            out = end
+        }
+    }
+
+    /** The intern pool holds stringSortCount / STRING_INTERN_UNIQUE_DIVISOR symbols */
+    const val STRING_INTERN_UNIQUE_DIVISOR = 10
+
+    /** Share of lookups that are for interned symbols */
+    const val STRING_INTERN_HIT_PERCENT = 90
+
+    /** Times the lookup list is replayed per run */
+    const val STRING_INTERN_PASSES = 20
+
+    /** Symbols to intern plus the lookups to replay against them */
+    class InternWorkload(val symbols: Array<String>, val lookups: Array<String>, val expectedHits: Int)
+
+    /**
+     * [count] / STRING_INTERN_UNIQUE_DIVISOR distinct identifier-like symbols (random lowercase
+     * stem, '_', index in base 36) and [count] lookups, STRING_INTERN_HIT_PERCENT% of them fresh
+     * copies of symbols (so a hit compares characters rather than references) and the rest
+     * names joined with '#', which no symbol contains
+     */
+    fun generateInternWorkload(count: Int, seed: Long = 0x1A7EL): InternWorkload {
+        val rng = XorShift128Plus(seed)
+        fun stem() = String(CharArray(6 + rng.nextInt(7)) { 'a' + rng.nextInt(26) })
+        val symbols = Array((count / STRING_INTERN_UNIQUE_DIVISOR).coerceAtLeast(1)) { i -> "${stem()}_${i.toString(36)}" }
+        var expectedHits = 0
+        val lookups =
+            Array(count) { i ->
+                if (rng.nextInt(100) < STRING_INTERN_HIT_PERCENT) {
+                    expectedHits++
+                    String(symbols[rng.nextInt(symbols.size)].toCharArray())
+                } else {
+                    "${stem()}#${i.toString(36)}"
+                }
+            }
+        return InternWorkload(symbols, lookups, expectedHits)
+    }
+
+    /**
+     * Look up lookups[from until to] in [pool], which maps each symbol to its index in [symbols]
+     *
+     * @return Hits whose ID resolves back to the same string
+     */
+    fun lookupInterned(pool: Map<String, Int>, symbols: Array<String>, lookups: Array<String>, from: Int, to: Int): Long {
+        var hits = 0L
+        for (i in from until to) {
+            val id = pool[lookups[i]] ?: continue
+            if (symbols[id] == lookups[i]) hits++
+        }
+        return hits
+    }
 }
@@ -0,0 +1,74 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark.algorithms
+
+/**
+ * Bitset-backed Bloom filter with 3 hash functions
+ *
+ * The 3 bit positions come from double hashing (h1 + i × h2) over two 64-bit hashes of the key,
+ * so each key is hashed only once. Not thread-safe; concurrent writers should fill separate
+ * filters of the same size and combine them with [mergeFrom].
+ *
+ * @param bitCount Number of bits (rounded up to a multiple of 64)
+ */
+class BloomFilter(bitCount: Int) {
+    private val words = LongArray((bitCount + 63) / 64)
+    private val bits = words.size.toLong() * 64
+
+    fun add(key: ByteArray) {
+        val h1 = hash1(key)
+        val h2 = hash2(key)
+        for (i in 0 until HASH_COUNT) {
+            val bit = index(h1, h2, i)
+            words[(bit ushr 6).toInt()] = words[(bit ushr 6).toInt()] or (1L shl (bit and 63).toInt())
+        }
+    }
+
+    fun mightContain(key: ByteArray): Boolean {
+        val h1 = hash1(key)
+        val h2 = hash2(key)
+        for (i in 0 until HASH_COUNT) {
+            val bit = index(h1, h2, i)
+            if (words[(bit ushr 6).toInt()] and (1L shl (bit and 63).toInt()) == 0L) return false
+        }
+        return true
+    }
+
+    /** OR the bits of [other] (same size) into words [fromWord, toWord) of this filter */
+    fun mergeFrom(other: BloomFilter, fromWord: Int = 0, toWord: Int = words.size) {
+        require(other.words.size == words.size) { "Bloom filters must be the same size" }
+        for (i in fromWord until toWord) {
+            words[i] = words[i] or other.words[i]
+        }
+    }
+
+    /** Number of 64-bit words, for splitting [mergeFrom] across threads */
+    val wordCount: Int
+        get() = words.size
+
+    /** Fraction of bits set */
+    fun fillRatio(): Double = words.sumOf { java.lang.Long.bitCount(it).toLong() }.toDouble() / bits
+
+    private fun index(h1: Long, h2: Long, i: Int): Long = java.lang.Long.remainderUnsigned(h1 + i * h2, bits)
+
+    companion object {
+        const val HASH_COUNT = 3
+
+        /** FNV-1a 64-bit */
+        private fun hash1(key: ByteArray): Long {
+            var h = -0x340d631b7bdddcdbL
+            for (b in key) {
+                h = (h xor (b.toLong() and 0xFF)) * 0x100000001b3L
+            }
+            return h
+        }
+
+        /** Multiply-xorshift hash, forced odd so double hashing visits distinct bits */
+        private fun hash2(key: ByteArray): Long {
+            var h = 0x9E3779B97F4A7C15uL.toLong()
+            for (b in key) {
+                h = (h + (b.toLong() and 0xFF)) * -0x4b47d5b1b0d1a4f3L
+                h = h xor (h ushr 29)
+            }
+            return h or 1L
+        }
+    }
+}
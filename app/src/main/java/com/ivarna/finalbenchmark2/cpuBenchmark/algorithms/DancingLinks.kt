@@ -0,0 +1,174 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark.algorithms
+
+/**
+ * Exact cover solver: Knuth's Algorithm X with dancing links
+ *
+ * Columns 0 until [primaryColumns] must be covered exactly once; the [secondaryColumns] after
+ * them at most once. Nodes live in parallel Int arrays (left / right / up / down / column), so
+ * each link is an index rather than an object reference; covering a column unlinks every row
+ * that uses it and uncovering relinks them in reverse order.
+ *
+ * Not thread-safe: searching mutates the links (they're restored when it returns).
+ */
+class DancingLinks(private val primaryColumns: Int, secondaryColumns: Int) {
+    private val columnCount = primaryColumns + secondaryColumns
+
+    // Node 0 is the root, nodes 1..columnCount the column headers, then the row nodes
+    private var left = IntArray(INITIAL_CAPACITY)
+    private var right = IntArray(INITIAL_CAPACITY)
+    private var up = IntArray(INITIAL_CAPACITY)
+    private var down = IntArray(INITIAL_CAPACITY)
+    private var column = IntArray(INITIAL_CAPACITY)
+    private val size = IntArray(columnCount + 1)
+    private var nodeCount = columnCount + 1
+
+    /** Rows tried during the last [countSolutions] (search tree size) */
+    var searchNodes = 0L
+        private set
+
+    init {
+        ensureCapacity(nodeCount)
+        for (c in 0..columnCount) {
+            up[c] = c
+            down[c] = c
+            column[c] = c
+            // Primary headers form the root's ring; secondary headers link only to themselves
+            if (c <= primaryColumns) {
+                left[c] = if (c == 0) primaryColumns else c - 1
+                right[c] = if (c == primaryColumns) 0 else c + 1
+            } else {
+                left[c] = c
+                right[c] = c
+            }
+        }
+    }
+
+    /** Add a row that covers [columns] (0-based column indices) */
+    fun addRow(columns: IntArray) {
+        ensureCapacity(nodeCount + columns.size)
+        val first = nodeCount
+        for ((i, col) in columns.withIndex()) {
+            val header = col + 1
+            val node = nodeCount++
+            column[node] = header
+            // Insert at the bottom of the column
+            up[node] = up[header]
+            down[node] = header
+            down[up[header]] = node
+            up[header] = node
+            size[header]++
+            left[node] = if (i == 0) node else node - 1
+            right[node] = first
+            right[left[node]] = node
+            left[first] = node
+        }
+    }
+
+    /** Count every exact cover */
+    fun countSolutions(): Long {
+        searchNodes = 0L
+        return search()
+    }
+
+    private fun search(): Long {
+        if (right[0] == 0) return 1L
+
+        // Branch on the primary column with the fewest rows
+        var best = right[0]
+        var c = right[best]
+        while (c != 0) {
+            if (size[c] < size[best]) best = c
+            c = right[c]
+        }
+        if (size[best] == 0) return 0L
+
+        var solutions = 0L
+        cover(best)
+        var row = down[best]
+        while (row != best) {
+            searchNodes++
+            var j = right[row]
+            while (j != row) {
+                cover(column[j])
+                j = right[j]
+            }
+            solutions += search()
+            j = left[row]
+            while (j != row) {
+                uncover(column[j])
+                j = left[j]
+            }
+            row = down[row]
+        }
+        uncover(best)
+        return solutions
+    }
+
+    private fun cover(c: Int) {
+        right[left[c]] = right[c]
+        left[right[c]] = left[c]
+        var i = down[c]
+        while (i != c) {
+            var j = right[i]
+            while (j != i) {
+                down[up[j]] = down[j]
+                up[down[j]] = up[j]
+                size[column[j]]--
+                j = right[j]
+            }
+            i = down[i]
+        }
+    }
+
+    private fun uncover(c: Int) {
+        var i = up[c]
+        while (i != c) {
+            var j = left[i]
+            while (j != i) {
+                size[column[j]]++
+                down[up[j]] = j
+                up[down[j]] = j
+                j = left[j]
+            }
+            i = up[i]
+        }
+        right[left[c]] = c
+        left[right[c]] = c
+    }
+
+    private fun ensureCapacity(capacity: Int) {
+        if (capacity <= left.size) return
+        val newSize = maxOf(capacity, left.size * 2)
+        left = left.copyOf(newSize)
+        right = right.copyOf(newSize)
+        up = up.copyOf(newSize)
+        down = down.copyOf(newSize)
+        column = column.copyOf(newSize)
+    }
+
+    companion object {
+        private const val INITIAL_CAPACITY = 256
+
+        /**
+         * N-Queens as exact cover: each of the n² squares is a row covering its rank and file
+         * (primary) and its two diagonals (secondary, since most diagonals stay empty)
+         */
+        fun nQueens(n: Int): DancingLinks {
+            val diagonals = 2 * n - 1
+            val links = DancingLinks(2 * n, 2 * diagonals)
+            for (rank in 0 until n) {
+                for (file in 0 until n) {
+                    links.addRow(
+                        intArrayOf(
+                            rank,
+                            n + file,
+                            2 * n + rank + file,
+                            2 * n + diagonals + rank - file + n - 1
+                        )
+                    )
+                }
+            }
+            return links
+        }
+    }
+}
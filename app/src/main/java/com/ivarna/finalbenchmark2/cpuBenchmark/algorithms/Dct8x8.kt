@@ -0,0 +1,95 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark.algorithms
+
+import kotlin.math.PI
+import kotlin.math.cos
+import kotlin.math.sqrt
+
+/**
+ * Reference 8×8 2D DCT-II / inverse pair as used by baseline JPEG
+ *
+ * Separable: an 8-point transform over the rows and then the columns, each a plain matrix
+ * product with a precomputed orthonormal cosine table (no fast AAN factorisation), so each
+ * direction costs 1024 multiply-adds per block. Blocks are 64 row-major samples at any offset in
+ * a DoubleArray; source and destination may be the same block.
+ *
+ * Not thread-safe: both passes go through a per-instance scratch block.
+ */
+class Dct8x8 {
+    private val tmp = DoubleArray(BLOCK_SIZE)
+
+    /** Forward DCT of src[srcOffset until srcOffset + 64] into dst[dstOffset until dstOffset + 64] */
+    fun forward(src: DoubleArray, srcOffset: Int, dst: DoubleArray, dstOffset: Int) {
+        // Rows: tmp[y][u] = Σx C[u][x] · src[y][x]
+        for (y in 0 until 8) {
+            for (u in 0 until 8) {
+                var sum = 0.0
+                for (x in 0 until 8) sum += COS[u * 8 + x] * src[srcOffset + y * 8 + x]
+                tmp[y * 8 + u] = sum
+            }
+        }
+        // Columns: dst[v][u] = Σy C[v][y] · tmp[y][u]
+        for (v in 0 until 8) {
+            for (u in 0 until 8) {
+                var sum = 0.0
+                for (y in 0 until 8) sum += COS[v * 8 + y] * tmp[y * 8 + u]
+                dst[dstOffset + v * 8 + u] = sum
+            }
+        }
+    }
+
+    /** Inverse DCT (the table is orthonormal, so this is [forward] with it transposed) */
+    fun inverse(src: DoubleArray, srcOffset: Int, dst: DoubleArray, dstOffset: Int) {
+        // Columns: tmp[y][u] = Σv C[v][y] · src[v][u]
+        for (y in 0 until 8) {
+            for (u in 0 until 8) {
+                var sum = 0.0
+                for (v in 0 until 8) sum += COS[v * 8 + y] * src[srcOffset + v * 8 + u]
+                tmp[y * 8 + u] = sum
+            }
+        }
+        // Rows: dst[y][x] = Σu C[u][x] · tmp[y][u]
+        for (y in 0 until 8) {
+            for (x in 0 until 8) {
+                var sum = 0.0
+                for (u in 0 until 8) sum += COS[u * 8 + x] * tmp[y * 8 + u]
+                dst[dstOffset + y * 8 + x] = sum
+            }
+        }
+    }
+
+    companion object {
+        const val BLOCK_SIZE = 64
+
+        /** COS[u * 8 + x] = c(u) / 2 · cos((2x + 1)uπ / 16), with c(0) = 1/√2 and c(u) = 1 otherwise */
+        private val COS =
+            DoubleArray(BLOCK_SIZE) { i ->
+                val u = i / 8
+                val x = i % 8
+                (if (u == 0) sqrt(0.5) else 1.0) / 2.0 * cos((2 * x + 1) * u * PI / 16.0)
+            }
+
+        /** Luminance quantization table from Annex K of the JPEG standard (quality 50), row-major */
+        val LUMINANCE_QUANT =
+            intArrayOf(
+                16, 11, 10, 16, 24, 40, 51, 61,
+                12, 12, 14, 19, 26, 58, 60, 55,
+                14, 13, 16, 24, 40, 57, 69, 56,
+                14, 17, 22, 29, 51, 87, 80, 62,
+                18, 22, 37, 56, 68, 109, 103, 77,
+                24, 35, 55, 64, 81, 104, 113, 92,
+                49, 64, 78, 87, 103, 121, 120, 101,
+                72, 92, 95, 98, 112, 100, 103, 99
+            )
+
+        /**
+         * Quantize the coefficients at [offset] with [table] and dequantize them again in place,
+         * leaving what a decoder would reconstruct from the stored integers
+         */
+        fun quantizeRoundTrip(coefficients: DoubleArray, offset: Int, table: IntArray = LUMINANCE_QUANT) {
+            for (i in 0 until BLOCK_SIZE) {
+                val q = table[i]
+                coefficients[offset + i] = Math.rint(coefficients[offset + i] / q) * q
+            }
+        }
+    }
+}
@@ -0,0 +1,165 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark.algorithms
+
+import java.util.PriorityQueue
+
+/**
+ * Canonical Huffman coding of bytes
+ *
+ * An encoded block is a header (original length as 4 bytes big endian, then the code length of
+ * each of the 256 byte values, 0 = unused) followed by the codes packed MSB first. Code lengths
+ * come from a Huffman tree of node objects built with a priority queue; codes are then assigned
+ * canonically (shorter codes first, ties by byte value), so the lengths alone describe the code.
+ *
+ * Stateless and thread-safe: every call builds its own tables.
+ */
+object HuffmanCoder {
+    const val HEADER_SIZE = 4 + 256
+
+    // A code of length L needs at least Fibonacci(L + 2) input bytes, so blocks under 2^31 bytes
+    // never exceed 44 bits
+    private const val MAX_CODE_LENGTH = 44
+
+    private class Node(val weight: Long, val symbol: Int, val order: Int, val left: Node?, val right: Node?)
+
+    /** Upper bound on the encoded size of a block of [size] bytes */
+    fun maxEncodedSize(size: Int): Int = HEADER_SIZE + size * 6 + 8
+
+    /** Huffman code length per byte value (0 for values absent from [histogram]) */
+    fun codeLengths(histogram: LongArray): IntArray {
+        val queue =
+            PriorityQueue<Node>(256) { a, b ->
+                if (a.weight != b.weight) a.weight.compareTo(b.weight) else a.order.compareTo(b.order)
+            }
+        var order = 0
+        for (symbol in 0 until 256) {
+            if (histogram[symbol] > 0) queue.add(Node(histogram[symbol], symbol, order++, null, null))
+        }
+
+        val lengths = IntArray(256)
+        if (queue.isEmpty()) return lengths
+        while (queue.size > 1) {
+            val a = queue.poll()!!
+            val b = queue.poll()!!
+            queue.add(Node(a.weight + b.weight, -1, order++, a, b))
+        }
+        assignDepths(queue.poll()!!, 0, lengths)
+        return lengths
+    }
+
+    private fun assignDepths(node: Node, depth: Int, lengths: IntArray) {
+        if (node.left == null || node.right == null) {
+            // A lone symbol still needs a 1-bit code
+            lengths[node.symbol] = maxOf(depth, 1)
+            return
+        }
+        assignDepths(node.left, depth + 1, lengths)
+        assignDepths(node.right, depth + 1, lengths)
+    }
+
+    /** First canonical code of each length (index = length) */
+    private fun firstCodes(lengthCounts: IntArray): LongArray {
+        val first = LongArray(MAX_CODE_LENGTH + 1)
+        var code = 0L
+        for (length in 1..MAX_CODE_LENGTH) {
+            code = (code + lengthCounts[length - 1]) shl 1
+            first[length] = code
+        }
+        return first
+    }
+
+    private fun lengthCounts(lengths: IntArray): IntArray {
+        val counts = IntArray(MAX_CODE_LENGTH + 1)
+        for (length in lengths) if (length > 0) counts[length]++
+        return counts
+    }
+
+    /**
+     * Encode data[from, to) into [out] at [outOffset]
+     *
+     * @return Encoded size in bytes (at most [maxEncodedSize])
+     */
+    fun encode(data: ByteArray, from: Int, to: Int, out: ByteArray, outOffset: Int): Int {
+        val histogram = LongArray(256)
+        for (i in from until to) histogram[data[i].toInt() and 0xFF]++
+
+        val lengths = codeLengths(histogram)
+        val nextCode = firstCodes(lengthCounts(lengths))
+        val codes = LongArray(256)
+        for (symbol in 0 until 256) {
+            if (lengths[symbol] > 0) codes[symbol] = nextCode[lengths[symbol]]++
+        }
+
+        val size = to - from
+        var pos = outOffset
+        out[pos++] = (size ushr 24).toByte()
+        out[pos++] = (size ushr 16).toByte()
+        out[pos++] = (size ushr 8).toByte()
+        out[pos++] = size.toByte()
+        for (symbol in 0 until 256) out[pos++] = lengths[symbol].toByte()
+
+        // Bits accumulate at the bottom of a 64-bit buffer and leave from the top, a byte at a time
+        var buffer = 0L
+        var bufferedBits = 0
+        for (i in from until to) {
+            val symbol = data[i].toInt() and 0xFF
+            buffer = (buffer shl lengths[symbol]) or codes[symbol]
+            bufferedBits += lengths[symbol]
+            while (bufferedBits >= 8) {
+                bufferedBits -= 8
+                out[pos++] = (buffer ushr bufferedBits).toByte()
+            }
+        }
+        if (bufferedBits > 0) out[pos++] = (buffer shl (8 - bufferedBits)).toByte()
+        return pos - outOffset
+    }
+
+    /**
+     * Decode the block at encoded[offset] into [out] at [outOffset]
+     *
+     * @return Decoded size in bytes
+     */
+    fun decode(encoded: ByteArray, offset: Int, out: ByteArray, outOffset: Int): Int {
+        var pos = offset
+        val size =
+            ((encoded[pos].toInt() and 0xFF) shl 24) or
+                ((encoded[pos + 1].toInt() and 0xFF) shl 16) or
+                ((encoded[pos + 2].toInt() and 0xFF) shl 8) or
+                (encoded[pos + 3].toInt() and 0xFF)
+        pos += 4
+        val lengths = IntArray(256) { encoded[pos + it].toInt() and 0xFF }
+        pos += 256
+
+        // Symbols sorted by (length, value): codes of one length are consecutive from first[length]
+        val counts = lengthCounts(lengths)
+        val first = firstCodes(counts)
+        val symbolStart = IntArray(MAX_CODE_LENGTH + 1)
+        for (length in 1..MAX_CODE_LENGTH) symbolStart[length] = symbolStart[length - 1] + counts[length - 1]
+        val fill = symbolStart.copyOf()
+        val symbols = IntArray(256)
+        for (symbol in 0 until 256) {
+            if (lengths[symbol] > 0) symbols[fill[lengths[symbol]]++] = symbol
+        }
+
+        var current = 0
+        var bit = 8
+        for (k in 0 until size) {
+            var code = 0L
+            var length = 0
+            while (true) {
+                if (bit == 8) {
+                    current = encoded[pos++].toInt() and 0xFF
+                    bit = 0
+                }
+                code = (code shl 1) or ((current ushr (7 - bit)) and 1).toLong()
+                bit++
+                length++
+                val index = code - first[length]
+                if (index >= 0 && index < counts[length]) {
+                    out[outOffset + k] = symbols[symbolStart[length] + index.toInt()].toByte()
+                    break
+                }
+            }
+        }
+        return size
+    }
+}
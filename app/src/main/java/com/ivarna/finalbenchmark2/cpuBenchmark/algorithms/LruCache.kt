@@ -0,0 +1,125 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark.algorithms
+
+/**
+ * Int-keyed cache of non-negative Int values
+ *
+ * [get] returns [MISS] for absent keys rather than null, so hits don't box the value.
+ */
+interface IntCache {
+    fun get(key: Int): Int
+
+    fun put(key: Int, value: Int)
+
+    /** Entries dropped to make room so far */
+    val evictions: Long
+
+    companion object {
+        const val MISS = -1
+    }
+}
+
+/**
+ * Least-recently-used cache: a HashMap from key to node plus a doubly linked list of the nodes,
+ * most recently used at the front
+ *
+ * [get] and [put] move the entry to the front; a [put] of a new key into a full cache first drops
+ * the entry at the back. Not thread-safe; see [ShardedLruCache].
+ */
+class LruCache(val capacity: Int) : IntCache {
+    private class Node(val key: Int, var value: Int) {
+        var prev: Node? = null
+        var next: Node? = null
+    }
+
+    private val map = HashMap<Int, Node>(capacity * 4 / 3 + 1)
+
+    // Sentinels: head.next is the most recently used entry, tail.prev the least
+    private val head = Node(0, 0)
+    private val tail = Node(0, 0)
+
+    override var evictions = 0L
+        private set
+
+    init {
+        require(capacity >= 1) { "LRU capacity must be at least 1, got $capacity" }
+        head.next = tail
+        tail.prev = head
+    }
+
+    val size: Int
+        get() = map.size
+
+    override fun get(key: Int): Int {
+        val node = map[key] ?: return IntCache.MISS
+        unlink(node)
+        pushFront(node)
+        return node.value
+    }
+
+    override fun put(key: Int, value: Int) {
+        val existing = map[key]
+        if (existing != null) {
+            existing.value = value
+            unlink(existing)
+            pushFront(existing)
+            return
+        }
+        if (map.size == capacity) {
+            val oldest = tail.prev!!
+            unlink(oldest)
+            map.remove(oldest.key)
+            evictions++
+        }
+        val node = Node(key, value)
+        map[key] = node
+        pushFront(node)
+    }
+
+    /** Keys from most to least recently used */
+    fun keys(): List<Int> {
+        val keys = ArrayList<Int>(map.size)
+        var node = head.next
+        while (node !== tail) {
+            keys.add(node!!.key)
+            node = node.next
+        }
+        return keys
+    }
+
+    private fun unlink(node: Node) {
+        node.prev!!.next = node.next
+        node.next!!.prev = node.prev
+    }
+
+    private fun pushFront(node: Node) {
+        node.prev = head
+        node.next = head.next
+        head.next!!.prev = node
+        head.next = node
+    }
+}
+
+/**
+ * [LruCache] split into [shardCount] independently locked shards picked by key hash
+ *
+ * Each shard holds capacity / shardCount entries (rounded up), so recency is only tracked within
+ * a shard and the total can slightly exceed [capacity]. Thread-safe.
+ */
+class ShardedLruCache(capacity: Int, shardCount: Int) : IntCache {
+    private val shards = Array(shardCount) { LruCache((capacity + shardCount - 1) / shardCount) }
+
+    override val evictions: Long
+        get() = shards.sumOf { shard -> synchronized(shard) { shard.evictions } }
+
+    override fun get(key: Int): Int {
+        val shard = shardFor(key)
+        return synchronized(shard) { shard.get(key) }
+    }
+
+    override fun put(key: Int, value: Int) {
+        val shard = shardFor(key)
+        synchronized(shard) { shard.put(key, value) }
+    }
+
+    private fun shardFor(key: Int): LruCache = shards[(key and Int.MAX_VALUE) % shards.size]
+}
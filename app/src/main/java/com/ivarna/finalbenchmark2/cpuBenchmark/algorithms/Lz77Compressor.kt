@@ -0,0 +1,215 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark.algorithms
+
+/**
+ * Sliding-window LZ77 compressor with a 32 KB window, hash chains and lazy matching
+ *
+ * Output is a sequence of tokens in groups of 8, each group preceded by a flag byte (bit k set =
+ * token k is a match). A literal is one byte; a match is three: distance - 1 as 15 bits (big
+ * endian) and length - 3 as one byte, so matches are 3..258 bytes up to 32 KB back.
+ *
+ * Match candidates come from chains of earlier positions with the same 3-byte hash, walking at
+ * most [MAX_CHAIN] of them. Lazy matching: before emitting a match, the match starting one byte
+ * later is checked, and if it is longer a literal is emitted instead and the later match is used.
+ *
+ * Not thread-safe: the hash chains are reused between calls, so use one compressor per thread.
+ */
+class Lz77Compressor {
+    private val head = IntArray(1 shl HASH_BITS)
+    private val prev = IntArray(WINDOW_SIZE)
+
+    // Per-call state
+    private var input = ByteArray(0)
+    private var inputLength = 0
+    private var output = ByteArray(0)
+    private var outPos = 0
+    private var flagPos = 0
+    private var flagBit = 8
+    private var matchDistance = 0
+
+    /**
+     * Compress [data] into [out], which must hold at least [maxCompressedSize] bytes
+     *
+     * @return Compressed size in bytes
+     */
+    fun compress(data: ByteArray, out: ByteArray): Int {
+        require(out.size >= maxCompressedSize(data.size)) { "Output buffer too small" }
+        input = data
+        inputLength = data.size
+        output = out
+        outPos = 0
+        flagBit = 8
+        head.fill(-1)
+
+        var i = 0
+        var haveCached = false
+        var cachedLength = 0
+        var cachedDistance = 0
+        while (i < inputLength) {
+            val length: Int
+            val distance: Int
+            if (haveCached) {
+                length = cachedLength
+                distance = cachedDistance
+                haveCached = false
+            } else {
+                length = findMatch(i)
+                distance = matchDistance
+                insert(i)
+            }
+
+            if (length < MIN_MATCH) {
+                emitLiteral(input[i])
+                i++
+                continue
+            }
+
+            // Lazy matching: prefer a longer match starting at the next byte
+            val nextLength = findMatch(i + 1)
+            val nextDistance = matchDistance
+            insert(i + 1)
+            if (nextLength > length) {
+                emitLiteral(input[i])
+                cachedLength = nextLength
+                cachedDistance = nextDistance
+                haveCached = true
+                i++
+                continue
+            }
+
+            emitMatch(distance, length)
+            for (p in i + 2 until i + length) insert(p)
+            i += length
+        }
+        return outPos
+    }
+
+    private fun hash(pos: Int): Int {
+        val value =
+            ((input[pos].toInt() and 0xFF) shl 16) or
+                ((input[pos + 1].toInt() and 0xFF) shl 8) or
+                (input[pos + 2].toInt() and 0xFF)
+        return (value * HASH_MULTIPLIER) ushr (32 - HASH_BITS)
+    }
+
+    private fun insert(pos: Int) {
+        if (pos + 2 >= inputLength) return
+        val h = hash(pos)
+        prev[pos and WINDOW_MASK] = head[h]
+        head[h] = pos
+    }
+
+    /** Longest match for [pos] among earlier positions (0 if under [MIN_MATCH]); sets matchDistance */
+    private fun findMatch(pos: Int): Int {
+        val maxLength = minOf(MAX_MATCH, inputLength - pos)
+        if (maxLength < MIN_MATCH) return 0
+
+        var best = 0
+        var candidate = head[hash(pos)]
+        // Chain links must go strictly backwards; a slot overwritten by a newer position ends the chain
+        var limit = pos
+        var chain = MAX_CHAIN
+        while (candidate in 0 until limit && pos - candidate <= WINDOW_SIZE && chain-- > 0) {
+            var length = 0
+            while (length < maxLength && input[candidate + length] == input[pos + length]) length++
+            if (length > best) {
+                best = length
+                matchDistance = pos - candidate
+                if (length == maxLength) break
+            }
+            limit = candidate
+            candidate = prev[candidate and WINDOW_MASK]
+        }
+        return if (best >= MIN_MATCH) best else 0
+    }
+
+    private fun startToken(isMatch: Boolean) {
+        if (flagBit == 8) {
+            flagPos = outPos++
+            output[flagPos] = 0
+            flagBit = 0
+        }
+        if (isMatch) output[flagPos] = (output[flagPos].toInt() or (1 shl flagBit)).toByte()
+        flagBit++
+    }
+
+    private fun emitLiteral(value: Byte) {
+        startToken(false)
+        output[outPos++] = value
+    }
+
+    private fun emitMatch(distance: Int, length: Int) {
+        startToken(true)
+        output[outPos++] = ((distance - 1) ushr 8).toByte()
+        output[outPos++] = (distance - 1).toByte()
+        output[outPos++] = (length - MIN_MATCH).toByte()
+    }
+
+    companion object {
+        const val WINDOW_SIZE = 32 * 1024
+        const val MIN_MATCH = 3
+        const val MAX_MATCH = 258
+        const val MAX_CHAIN = 64
+
+        private const val WINDOW_MASK = WINDOW_SIZE - 1
+        private const val HASH_BITS = 15
+        private const val HASH_MULTIPLIER = -0x61c8864f // 0x9E3779B1
+
+        /** Worst case output size: every byte a literal, plus one flag byte per 8 tokens */
+        fun maxCompressedSize(inputSize: Int): Int = inputSize + (inputSize + 7) / 8
+
+        /**
+         * Decompress [length] bytes of [compressed] into [out]
+         *
+         * @return Decompressed size in bytes
+         */
+        fun decompress(compressed: ByteArray, length: Int, out: ByteArray): Int {
+            var inPos = 0
+            var outPos = 0
+            var flags = 0
+            var bit = 8
+            while (inPos < length) {
+                if (bit == 8) {
+                    flags = compressed[inPos++].toInt() and 0xFF
+                    bit = 0
+                    if (inPos >= length) break
+                }
+                if ((flags ushr bit) and 1 != 0) {
+                    val distance =
+                        (((compressed[inPos].toInt() and 0xFF) shl 8) or (compressed[inPos + 1].toInt() and 0xFF)) + 1
+                    val matchLength = (compressed[inPos + 2].toInt() and 0xFF) + MIN_MATCH
+                    inPos += 3
+                    // Byte by byte: matches may overlap the bytes they produce
+                    val from = outPos - distance
+                    for (k in 0 until matchLength) out[outPos++] = out[from + k]
+                } else {
+                    out[outPos++] = compressed[inPos++]
+                }
+                bit++
+            }
+            return outPos
+        }
+
+        /**
+         * [size] bytes of space-separated words drawn from a 64-word random vocabulary, with
+         * occasional newlines: repetitive like text, so matches are plentiful
+         */
+        fun sampleText(size: Int, seed: Long): ByteArray {
+            val rng = XorShift128Plus(seed)
+            val vocabulary =
+                Array(64) {
+                    ByteArray(2 + rng.nextInt(8)) { ('a'.code + rng.nextInt(26)).toByte() }
+                }
+            val data = ByteArray(size)
+            var pos = 0
+            while (pos < size) {
+                val word = vocabulary[rng.nextInt(vocabulary.size)]
+                for (b in word) {
+                    if (pos == size) break
+                    data[pos++] = b
+                }
+                if (pos < size) data[pos++] = if (rng.nextInt(12) == 0) '\n'.code.toByte() else ' '.code.toByte()
+            }
+            return data
+        }
+    }
+}
@@ -0,0 +1,294 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark.algorithms
+
+/**
+ * Sphere-only scene for the ray tracing variants (anti-aliased, BVH)
+ *
+ * Sphere data lives in flat arrays so the intersection loops stay allocation-free. Shading is
+ * a single directional light (Lambert) plus mirror reflection up to maxDepth bounces, with a
+ * vertical gradient for rays that escape.
+ *
+ * A bounding volume hierarchy over the spheres is built up front; set [useBvh] to find hits by
+ * walking it (O(log n) tests per ray) instead of testing every sphere.
+ *
+ * Not thread-safe: [trace] keeps the current hit distance and counters in fields, so use one
+ * scene per thread.
+ */
+class RayTracingScene(
+        private val centers: DoubleArray, // x, y, z per sphere
+        private val radii: DoubleArray,
+        private val colors: DoubleArray, // r, g, b per sphere
+        private val reflectivity: DoubleArray
+) {
+    val sphereCount: Int
+        get() = radii.size
+
+    /** Find hits through the BVH instead of testing every sphere */
+    var useBvh = false
+
+    /** Ray segments cast (primary rays plus reflections) since the last [resetCounters] */
+    var raysCast = 0L
+        private set
+
+    /** BVH nodes visited since the last [resetCounters] (only counted when [useBvh] is set) */
+    var nodesVisited = 0L
+        private set
+
+    // Distance to the hit found by the last nearestHit call
+    private var hitT = 0.0
+
+    // BVH as flat arrays: per node an AABB (min / max xyz), and either two children or a
+    // leaf range [leafStart, leafStart + leafCount) into sphereOrder
+    private val sphereOrder = IntArray(radii.size) { it }
+    private val nodeMin = DoubleArray(maxOf(1, 2 * radii.size - 1) * 3)
+    private val nodeMax = DoubleArray(maxOf(1, 2 * radii.size - 1) * 3)
+    private val nodeLeft = IntArray(maxOf(1, 2 * radii.size - 1))
+    private val nodeRight = IntArray(maxOf(1, 2 * radii.size - 1))
+    private val leafStart = IntArray(maxOf(1, 2 * radii.size - 1))
+    private val leafCount = IntArray(maxOf(1, 2 * radii.size - 1))
+    private var nodeCount = 0
+    private val traversalStack = IntArray(64)
+
+    /** Number of nodes in the BVH */
+    val bvhNodeCount: Int
+        get() = nodeCount
+
+    init {
+        if (radii.isNotEmpty()) buildNode(0, radii.size)
+    }
+
+    fun resetCounters() {
+        raysCast = 0L
+        nodesVisited = 0L
+    }
+
+    /** Build the subtree over sphereOrder[from, to) by median split along the box's longest axis */
+    private fun buildNode(from: Int, to: Int): Int {
+        val node = nodeCount++
+        for (axis in 0 until 3) {
+            nodeMin[node * 3 + axis] = Double.MAX_VALUE
+            nodeMax[node * 3 + axis] = -Double.MAX_VALUE
+        }
+        for (k in from until to) {
+            val i = sphereOrder[k]
+            for (axis in 0 until 3) {
+                nodeMin[node * 3 + axis] = minOf(nodeMin[node * 3 + axis], centers[i * 3 + axis] - radii[i])
+                nodeMax[node * 3 + axis] = maxOf(nodeMax[node * 3 + axis], centers[i * 3 + axis] + radii[i])
+            }
+        }
+
+        if (to - from <= BVH_LEAF_SIZE) {
+            nodeLeft[node] = -1
+            nodeRight[node] = -1
+            leafStart[node] = from
+            leafCount[node] = to - from
+            return node
+        }
+
+        val extents = DoubleArray(3) { axis -> nodeMax[node * 3 + axis] - nodeMin[node * 3 + axis] }
+        val axis = extents.indices.maxByOrNull { extents[it] } ?: 0
+        val sorted = (from until to).map { sphereOrder[it] }.sortedBy { centers[it * 3 + axis] }
+        sorted.forEachIndexed { k, sphere -> sphereOrder[from + k] = sphere }
+
+        val middle = (from + to) / 2
+        leafCount[node] = 0
+        nodeLeft[node] = buildNode(from, middle)
+        nodeRight[node] = buildNode(middle, to)
+        return node
+    }
+
+    /**
+     * Find the closest sphere hit by the ray (direction must be normalized)
+     *
+     * @return Sphere index, or -1 if the ray hits nothing
+     */
+    private fun nearestHit(ox: Double, oy: Double, oz: Double, dx: Double, dy: Double, dz: Double): Int {
+        raysCast++
+        if (useBvh) return nearestHitBvh(ox, oy, oz, dx, dy, dz)
+
+        var nearest = -1
+        var nearestT = Double.MAX_VALUE
+        for (i in radii.indices) {
+            val t = intersectSphere(i, ox, oy, oz, dx, dy, dz)
+            if (t < nearestT) {
+                nearestT = t
+                nearest = i
+            }
+        }
+        hitT = nearestT
+        return nearest
+    }
+
+    private fun nearestHitBvh(ox: Double, oy: Double, oz: Double, dx: Double, dy: Double, dz: Double): Int {
+        val invX = 1.0 / dx
+        val invY = 1.0 / dy
+        val invZ = 1.0 / dz
+        var nearest = -1
+        var nearestT = Double.MAX_VALUE
+        var top = 0
+        if (nodeCount > 0) traversalStack[top++] = 0
+
+        while (top > 0) {
+            val node = traversalStack[--top]
+            nodesVisited++
+            if (!hitsBox(node, ox, oy, oz, invX, invY, invZ, nearestT)) continue
+
+            if (nodeLeft[node] < 0) {
+                for (k in leafStart[node] until leafStart[node] + leafCount[node]) {
+                    val i = sphereOrder[k]
+                    val t = intersectSphere(i, ox, oy, oz, dx, dy, dz)
+                    if (t < nearestT) {
+                        nearestT = t
+                        nearest = i
+                    }
+                }
+            } else {
+                traversalStack[top++] = nodeRight[node]
+                traversalStack[top++] = nodeLeft[node]
+            }
+        }
+        hitT = nearestT
+        return nearest
+    }
+
+    /** Slab test: does the ray enter the node's box before [maxT]? */
+    private fun hitsBox(
+            node: Int,
+            ox: Double,
+            oy: Double,
+            oz: Double,
+            invX: Double,
+            invY: Double,
+            invZ: Double,
+            maxT: Double
+    ): Boolean {
+        val base = node * 3
+        var t1 = (nodeMin[base] - ox) * invX
+        var t2 = (nodeMax[base] - ox) * invX
+        var tMin = minOf(t1, t2)
+        var tMax = maxOf(t1, t2)
+        t1 = (nodeMin[base + 1] - oy) * invY
+        t2 = (nodeMax[base + 1] - oy) * invY
+        tMin = maxOf(tMin, minOf(t1, t2))
+        tMax = minOf(tMax, maxOf(t1, t2))
+        t1 = (nodeMin[base + 2] - oz) * invZ
+        t2 = (nodeMax[base + 2] - oz) * invZ
+        tMin = maxOf(tMin, minOf(t1, t2))
+        tMax = minOf(tMax, maxOf(t1, t2))
+        return tMax >= maxOf(tMin, 0.0) && tMin < maxT
+    }
+
+    /** Distance along the ray to sphere [i], or Double.MAX_VALUE on a miss */
+    private fun intersectSphere(i: Int, ox: Double, oy: Double, oz: Double, dx: Double, dy: Double, dz: Double): Double {
+        val ocx = ox - centers[i * 3]
+        val ocy = oy - centers[i * 3 + 1]
+        val ocz = oz - centers[i * 3 + 2]
+        val b = ocx * dx + ocy * dy + ocz * dz
+        val c = ocx * ocx + ocy * ocy + ocz * ocz - radii[i] * radii[i]
+        val discriminant = b * b - c
+        if (discriminant < 0.0) return Double.MAX_VALUE
+        val root = Math.sqrt(discriminant)
+        var t = -b - root
+        if (t < EPSILON) t = -b + root
+        return if (t < EPSILON) Double.MAX_VALUE else t
+    }
+
+    /**
+     * Trace one ray (direction must be normalized) and add its color to [color] (r, g, b)
+     */
+    fun trace(
+            originX: Double,
+            originY: Double,
+            originZ: Double,
+            dirX: Double,
+            dirY: Double,
+            dirZ: Double,
+            maxDepth: Int,
+            color: DoubleArray
+    ) {
+        var ox = originX
+        var oy = originY
+        var oz = originZ
+        var dx = dirX
+        var dy = dirY
+        var dz = dirZ
+        var attenuation = 1.0
+
+        for (bounce in 0 until maxDepth.coerceAtLeast(1)) {
+            val hit = nearestHit(ox, oy, oz, dx, dy, dz)
+            if (hit < 0) {
+                val sky = 0.5 * (dy + 1.0)
+                color[0] += attenuation * (1.0 - 0.5 * sky)
+                color[1] += attenuation * (1.0 - 0.3 * sky)
+                color[2] += attenuation
+                return
+            }
+
+            val px = ox + hitT * dx
+            val py = oy + hitT * dy
+            val pz = oz + hitT * dz
+            val invRadius = 1.0 / radii[hit]
+            val nx = (px - centers[hit * 3]) * invRadius
+            val ny = (py - centers[hit * 3 + 1]) * invRadius
+            val nz = (pz - centers[hit * 3 + 2]) * invRadius
+
+            val diffuse = Math.max(0.0, nx * LIGHT_X + ny * LIGHT_Y + nz * LIGHT_Z)
+            val shade = attenuation * (1.0 - reflectivity[hit]) * (AMBIENT + (1.0 - AMBIENT) * diffuse)
+            color[0] += shade * colors[hit * 3]
+            color[1] += shade * colors[hit * 3 + 1]
+            color[2] += shade * colors[hit * 3 + 2]
+
+            attenuation *= reflectivity[hit]
+            if (attenuation < MIN_ATTENUATION) return
+
+            // Mirror reflection, nudged off the surface to avoid self-intersection
+            val dot = dx * nx + dy * ny + dz * nz
+            dx -= 2.0 * dot * nx
+            dy -= 2.0 * dot * ny
+            dz -= 2.0 * dot * nz
+            ox = px + nx * EPSILON
+            oy = py + ny * EPSILON
+            oz = pz + nz * EPSILON
+        }
+    }
+
+    companion object {
+        private const val EPSILON = 1e-6
+        private const val BVH_LEAF_SIZE = 2
+        private const val AMBIENT = 0.1
+        private const val MIN_ATTENUATION = 1e-3
+
+        // Normalized (-1, 1, -1)
+        private const val LIGHT_X = -0.5773502691896258
+        private const val LIGHT_Y = 0.5773502691896258
+        private const val LIGHT_Z = -0.5773502691896258
+
+        /** Two reflective spheres resting on a large ground sphere */
+        fun threeSpheres(): RayTracingScene =
+                RayTracingScene(
+                        centers = doubleArrayOf(-0.6, 0.0, 3.0, 1.1, -0.3, 3.8, 0.0, -1001.0, 3.0),
+                        radii = doubleArrayOf(1.0, 0.7, 1000.0),
+                        colors = doubleArrayOf(0.9, 0.2, 0.2, 0.2, 0.8, 0.3, 0.6, 0.6, 0.6),
+                        reflectivity = doubleArrayOf(0.4, 0.6, 0.1)
+                )
+
+        /** [count] random spheres spread over a slab in front of the camera */
+        fun randomSpheres(count: Int, seed: Long): RayTracingScene {
+            val rng = XorShift128Plus(seed)
+            val centers = DoubleArray(count * 3)
+            val radii = DoubleArray(count)
+            val colors = DoubleArray(count * 3)
+            val reflectivity = DoubleArray(count)
+            for (i in 0 until count) {
+                centers[i * 3] = (rng.nextDouble() - 0.5) * 8.0
+                centers[i * 3 + 1] = (rng.nextDouble() - 0.5) * 5.0
+                centers[i * 3 + 2] = 4.0 + rng.nextDouble() * 10.0
+                radii[i] = 0.2 + rng.nextDouble() * 0.4
+                for (channel in 0 until 3) {
+                    colors[i * 3 + channel] = 0.2 + rng.nextDouble() * 0.8
+                }
+                reflectivity[i] = rng.nextDouble() * 0.6
+            }
+            return RayTracingScene(centers, radii, colors, reflectivity)
+        }
+    }
+}
@@ -1,12 +1,20 @@
 package com.ivarna.finalbenchmark2.cpuBenchmark.algorithms
 
 import android.util.Log
+import com.ivarna.finalbenchmark2.cpuBenchmark.BenchmarkControl
 import com.ivarna.finalbenchmark2.cpuBenchmark.BenchmarkResult
 import com.ivarna.finalbenchmark2.cpuBenchmark.CpuAffinityManager
+import com.ivarna.finalbenchmark2.cpuBenchmark.PerfReadings
+import com.ivarna.finalbenchmark2.cpuBenchmark.SortingComparisonResult
 import com.ivarna.finalbenchmark2.cpuBenchmark.WorkloadParams
+import com.ivarna.finalbenchmark2.cpuBenchmark.withCounters
+import java.math.BigInteger
+import java.util.concurrent.LinkedBlockingQueue
+import java.util.concurrent.atomic.AtomicLong
 import kotlinx.coroutines.Dispatchers
 import kotlinx.coroutines.withContext
 import kotlinx.coroutines.yield
+import org.json.JSONArray
 import org.json.JSONObject
 
 object SingleCoreBenchmarks {
@@ -24,20 +32,21 @@ object SingleCoreBenchmarks {
                                 TAG,
                                 "Starting Prime Generation (range: ${params.primeRange}) - Sieve of Eratosthenes (Single-threaded)"
                         )
-                        CpuAffinityManager.setLastCoreAffinity()
                         CpuAffinityManager.setMaxPerformance()
 
+                        val perf = PerfReadings()
                         val (primeCount, timeMs) =
-                                BenchmarkHelpers.measureBenchmark {
-                                        // Use Pollard's Rho for factorization
-                                        BenchmarkHelpers.countFactorsPollardRho(params.primeRange)
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                // Use Pollard's Rho for factorization
+                                                BenchmarkHelpers.countFactorsPollardRho(params.primeRange)
+                                        }
                                 }
 
                         val ops = params.primeRange.toDouble() // Operations = numbers processed
                         val opsPerSecond = ops / (timeMs / 1000.0)
 
                         CpuAffinityManager.resetPerformance()
-                        CpuAffinityManager.resetCpuAffinity()
 
                         // Thermal stabilization delay (skip for test runs)
                         if (!isTestRun) {
@@ -62,7 +71,7 @@ object SingleCoreBenchmarks {
                                                         )
                                                 }
                                                 .toString()
-                        )
+                        ).withCounters(perf)
                 }
 
         /**
@@ -82,21 +91,23 @@ object SingleCoreBenchmarks {
                                 TAG,
                                 "Starting Single-Core Fibonacci - Core-independent fixed workload (10M iterations)"
                         )
-                        CpuAffinityManager.setLastCoreAffinity()
                         CpuAffinityManager.setMaxPerformance()
 
                         // Use UNIFIED polynomial evaluation from BenchmarkHelpers
 
+                        val perf = PerfReadings()
                         val (results, timeMs) =
-                                BenchmarkHelpers.measureBenchmark {
-                                        val iterations = params.fibonacciIterations
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                val iterations = params.fibonacciIterations
 
-                                        var totalResult = 0L
-                                        repeat(iterations) {
-                                                // Call unified polynomial evaluation
-                                                totalResult += BenchmarkHelpers.fibonacciIterative(35)
+                                                var totalResult = 0L
+                                                BenchmarkControl.repeatUntilCancelled(iterations, checkEvery = BenchmarkControl.FINE_GRAINED_CHECK_STRIDE) {
+                                                        // Call unified polynomial evaluation
+                                                        totalResult += BenchmarkHelpers.fibonacciIterative(35)
+                                                }
+                                                totalResult
                                         }
-                                        totalResult
                                 }
 
                         val actualOps =
@@ -104,7 +115,6 @@ object SingleCoreBenchmarks {
                         val opsPerSecond = actualOps / (timeMs / 1000.0)
 
                         CpuAffinityManager.resetPerformance()
-                        CpuAffinityManager.resetCpuAffinity()
 
                         // Thermal stabilization delay (skip for test runs)
                         if (!isTestRun) {
@@ -134,7 +144,7 @@ object SingleCoreBenchmarks {
                                                         )
                                                 }
                                                 .toString()
-                        )
+                        ).withCounters(perf)
                 }
 
         /**
@@ -151,20 +161,22 @@ object SingleCoreBenchmarks {
                                 TAG,
                                 "Starting Single-Core Matrix Multiplication (size: ${params.matrixSize}, iterations: ${params.matrixIterations}) - Cache-Resident Strategy"
                         )
-                        CpuAffinityManager.setLastCoreAffinity()
                         CpuAffinityManager.setMaxPerformance()
 
                         val size = params.matrixSize
                         val iterations = params.matrixIterations
 
+                        val perf = PerfReadings()
                         val (checksum, timeMs) =
-                                BenchmarkHelpers.measureBenchmark {
-                                        // CACHE-RESIDENT: Call matrix multiplication with
-                                        // repetitions
-                                        BenchmarkHelpers.performMatrixMultiplication(
-                                                size,
-                                                iterations
-                                        )
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                // CACHE-RESIDENT: Call matrix multiplication with
+                                                // repetitions
+                                                BenchmarkHelpers.performMatrixMultiplication(
+                                                        size,
+                                                        iterations
+                                                )
+                                        }
                                 }
 
                         // CACHE-RESIDENT: Total operations = size³ × 2 (multiply + add) ×
@@ -173,7 +185,6 @@ object SingleCoreBenchmarks {
                         val opsPerSecond = totalOps / (timeMs / 1000.0)
 
                         CpuAffinityManager.resetPerformance()
-                        CpuAffinityManager.resetCpuAffinity()
 
                         // Thermal stabilization delay (skip for test runs)
                         if (!isTestRun) {
@@ -209,7 +220,7 @@ object SingleCoreBenchmarks {
                                                         )
                                                 }
                                                 .toString()
-                        )
+                        ).withCounters(perf)
                 }
 
         /**
@@ -226,21 +237,22 @@ object SingleCoreBenchmarks {
         suspend fun hashComputing(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
                 withContext(Dispatchers.Default) {
                         Log.d(TAG, "Starting Single-Core Hash Computing - CPU-BOUND SHA-256-like")
-                        CpuAffinityManager.setLastCoreAffinity()
                         CpuAffinityManager.setMaxPerformance()
 
                         val iterations = params.hashIterations
 
+                        val perf = PerfReadings()
                         val (finalHash, timeMs) =
-                                BenchmarkHelpers.measureBenchmarkSuspend {
-                                        // Call SHA-256-like hash computing (no buffer needed)
-                                        BenchmarkHelpers.performHashComputing(iterations)
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                // Call SHA-256-like hash computing (no buffer needed)
+                                                BenchmarkHelpers.performHashComputing(iterations)
+                                        }
                                 }
 
                         val opsPerSecond = iterations.toDouble() / (timeMs / 1000.0)
 
                         CpuAffinityManager.resetPerformance()
-                        CpuAffinityManager.resetCpuAffinity()
 
                         // Thermal stabilization delay (skip for test runs)
                         if (!isTestRun) {
@@ -272,7 +284,7 @@ object SingleCoreBenchmarks {
                                                         )
                                                 }
                                                 .toString()
-                        )
+                        ).withCounters(perf)
                 }
 
         /**
@@ -292,7 +304,6 @@ object SingleCoreBenchmarks {
                                 TAG,
                                 "Starting Single-Core String Sorting - CACHE-RESIDENT: ${params.stringSortCount} total strings"
                         )
-                        CpuAffinityManager.setLastCoreAffinity()
                         CpuAffinityManager.setMaxPerformance()
 
                         // CACHE-RESIDENT: Generate small source list (4,096 strings) that fits in
@@ -309,13 +320,16 @@ object SingleCoreBenchmarks {
                         )
                         Log.d(TAG, "Memory cleaned. Starting cache-resident sorting...")
 
+                        val perf = PerfReadings()
                         val (checksum, timeMs) =
-                                BenchmarkHelpers.measureBenchmark {
-                                        // CACHE-RESIDENT: Use centralized helper function
-                                        BenchmarkHelpers.runStringSortWorkload(
-                                                sourceList,
-                                                iterations
-                                        )
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                // CACHE-RESIDENT: Use centralized helper function
+                                                BenchmarkHelpers.runStringSortWorkload(
+                                                        sourceList,
+                                                        iterations
+                                                )
+                                        }
                                 }
 
                         // Calculate operations per second
@@ -328,7 +342,6 @@ object SingleCoreBenchmarks {
                         val opsPerSecond = totalComparisons / (timeMs / 1000.0)
 
                         CpuAffinityManager.resetPerformance()
-                        CpuAffinityManager.resetCpuAffinity()
 
                         // Thermal stabilization delay (skip for test runs)
                         if (!isTestRun) {
@@ -377,7 +390,7 @@ object SingleCoreBenchmarks {
                                                         )
                                                 }
                                                 .toString()
-                        )
+                        ).withCounters(perf)
                 }
 
         /** Helper extension to check if list is sorted */
@@ -469,28 +482,29 @@ object SingleCoreBenchmarks {
                                 TAG,
                                 "Starting Single-Core Ray Tracing - CACHE-RESIDENT: ${params.rayTracingIterations} iterations"
                         )
-                        CpuAffinityManager.setLastCoreAffinity()
                         CpuAffinityManager.setMaxPerformance()
 
                         val (width, height) = params.rayTracingResolution
                         val maxDepth = params.rayTracingDepth
                         val iterations = params.rayTracingIterations
 
+                        val perf = PerfReadings()
                         val (totalNoise, timeMs) =
-                                BenchmarkHelpers.measureBenchmark {
-                                        BenchmarkHelpers.performPerlinNoise(
-                                                width,
-                                                height,
-                                                params.rayTracingDepth,  // Use depth as 3rd dimension
-                                                iterations
-                                        )
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkHelpers.performPerlinNoise(
+                                                        width,
+                                                        height,
+                                                        params.rayTracingDepth,  // Use depth as 3rd dimension
+                                                        iterations
+                                                )
+                                        }
                                 }
 
                         val totalRays = (width * height * iterations).toLong()
                         val raysPerSecond = totalRays / (timeMs / 1000.0)
 
                         CpuAffinityManager.resetPerformance()
-                        CpuAffinityManager.resetCpuAffinity()
 
                         // Thermal stabilization delay (skip for test runs)
                         if (!isTestRun) {
@@ -518,7 +532,7 @@ object SingleCoreBenchmarks {
                                                         )
                                                 }
                                                 .toString()
-                        )
+                        ).withCounters(perf)
                 }
 
         /**
@@ -536,7 +550,6 @@ object SingleCoreBenchmarks {
         suspend fun compression(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
                 withContext(Dispatchers.Default) {
                         Log.d(TAG, "Starting Single-Core Compression - FIXED WORK PER CORE")
-                        CpuAffinityManager.setLastCoreAffinity()
                         CpuAffinityManager.setMaxPerformance()
 
                         // FIXED WORK PER CORE: Use params.compressionIterations with 2MB buffer
@@ -547,17 +560,21 @@ object SingleCoreBenchmarks {
                         val iterations =
                                 params.compressionIterations // Use configurable workload per core
 
-                        val (totalBytes, timeMs) =
-                                BenchmarkHelpers.measureBenchmarkSuspend {
-                                        // Call centralized compression function
-                                        BenchmarkHelpers.performCompression(bufferSize, iterations)
+                        val perf = PerfReadings()
+                        val (compressionResult, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                // Call centralized compression function, timing each
+                                                // iteration to expose bimodal runs
+                                                BenchmarkHelpers.performCompressionTimed(bufferSize, iterations)
+                                        }
                                 }
+                        val (totalBytes, iterationTimesMs) = compressionResult
 
                         // Calculate throughput in bytes per second
                         val throughput = totalBytes.toDouble() / (timeMs / 1000.0)
 
                         CpuAffinityManager.resetPerformance()
-                        CpuAffinityManager.resetCpuAffinity()
 
                         // Thermal stabilization delay (skip for test runs)
                         if (!isTestRun) {
@@ -594,20 +611,24 @@ object SingleCoreBenchmarks {
                                                                 "expected_performance",
                                                                 "~0.15 Gops/s baseline for single-core devices"
                                                         )
+                                                        put(
+                                                                "raw_iteration_times_ms",
+                                                                JSONArray().apply { iterationTimesMs.forEach { put(it) } }
+                                                        )
                                                 }
                                                 .toString()
-                        )
+                        ).withCounters(perf)
                 }
 
         /**
-         * Test 8: Single-Core Leibniz π Calculation
+         * Test 8: Single-Core "Monte Carlo π"
          *
-         * ALGORITHM: Uses Leibniz formula: π/4 = 1 - 1/3 + 1/5 - 1/7 + 1/9 - ...
+         * ALGORITHM: Despite the name (kept for score compatibility), runs the Mandelbrot
+         * escape-time loop over monteCarloSamples points of a fixed grid (see
+         * [BenchmarkHelpers.performMandelbrotSet])
          *
-         * WHY LEIBNIZ INSTEAD OF MONTE CARLO:
          * - Deterministic: No random numbers, no caching issues
-         * - Predictable: Same iterations = same result
-         * - Pure arithmetic: Tests raw CPU throughput
+         * - Predictable: Same samples = same iteration count
          * - Fair comparison: Same algorithm as Multi-Core
          *
          * PERFORMANCE: Baseline for single-core comparison
@@ -615,21 +636,23 @@ object SingleCoreBenchmarks {
         suspend fun monteCarloPi(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
                 withContext(Dispatchers.Default) {
                         Log.d(TAG, "Starting Single-Core Leibniz π (iterations: ${params.monteCarloSamples})")
-                        CpuAffinityManager.setLastCoreAffinity()
                         CpuAffinityManager.setMaxPerformance()
 
                         val iterations = params.monteCarloSamples.toLong()
 
-                        val (totalIterations, timeMs) = BenchmarkHelpers.measureBenchmark {
-                            BenchmarkHelpers.performMandelbrotSet(iterations, maxIterations = 256)
-                        }
+                        val perf = PerfReadings()
+                        val (totalIterations, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                            BenchmarkHelpers.performMandelbrotSet(iterations, maxIterations = 256)
+                                        }
+                                }
 
                         val opsPerSecond = totalIterations.toDouble() / (timeMs / 1000.0)
 
                         val isValid = timeMs > 0 && opsPerSecond > 0 && totalIterations > 0
 
                         CpuAffinityManager.resetPerformance()
-                        CpuAffinityManager.resetCpuAffinity()
 
                         // Thermal stabilization delay (skip for test runs)
                         if (!isTestRun) {
@@ -650,7 +673,7 @@ object SingleCoreBenchmarks {
                                                         put("max_iterations", 256)
                                                 }
                                                 .toString()
-                        )
+                        ).withCounters(perf)
                 }
 
         /**
@@ -671,7 +694,6 @@ object SingleCoreBenchmarks {
                                 TAG,
                                 "Starting Single-Core JSON Parsing - CACHE-RESIDENT: ${params.jsonDataSizeMb}MB, ${params.jsonParsingIterations} iterations"
                         )
-                        CpuAffinityManager.setLastCoreAffinity()
                         CpuAffinityManager.setMaxPerformance()
 
                         val dataSize = params.jsonDataSizeMb * 1024 * 1024
@@ -682,13 +704,16 @@ object SingleCoreBenchmarks {
                         val jsonData = BenchmarkHelpers.generateComplexJson(dataSize)
                         Log.d(TAG, "JSON generated. Starting cache-resident parsing...")
 
+                        val perf = PerfReadings()
                         val (totalElementCount, timeMs) =
-                                BenchmarkHelpers.measureBenchmark {
-                                        // CACHE-RESIDENT: Parse the same JSON multiple times
-                                        BenchmarkHelpers.performJsonParsingWorkload(
-                                                jsonData,
-                                                iterations
-                                        )
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                // CACHE-RESIDENT: Parse the same JSON multiple times
+                                                BenchmarkHelpers.performJsonParsingWorkload(
+                                                        jsonData,
+                                                        iterations
+                                                )
+                                        }
                                 }
 
                         // Calculate operations per second based on total elements parsed
@@ -696,7 +721,6 @@ object SingleCoreBenchmarks {
                         val opsPerSecond = totalElementCount.toDouble() / (timeMs / 1000.0)
 
                         CpuAffinityManager.resetPerformance()
-                        CpuAffinityManager.resetCpuAffinity()
 
                         // Thermal stabilization delay (skip for test runs)
                         if (!isTestRun) {
@@ -722,7 +746,7 @@ object SingleCoreBenchmarks {
                                                         )
                                                 }
                                                 .toString()
-                        )
+                        ).withCounters(perf)
                 }
 
         /**
@@ -742,15 +766,17 @@ object SingleCoreBenchmarks {
                                 TAG,
                                 "Starting Single-Core N-Queens (size: ${params.nqueensSize}) - FIXED: Iteration tracking"
                         )
-                        CpuAffinityManager.setLastCoreAffinity()
                         CpuAffinityManager.setMaxPerformance()
 
                         val boardSize = params.nqueensSize
 
+                        val perf = PerfReadings()
                         val (result, timeMs) =
-                                BenchmarkHelpers.measureBenchmark {
-                                        // Use centralized solver from BenchmarkHelpers
-                                        BenchmarkHelpers.solveNQueens(boardSize)
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                // Use centralized solver from BenchmarkHelpers
+                                                BenchmarkHelpers.solveNQueens(boardSize)
+                                        }
                                 }
 
                         val (solutionCount, iterationCount) = result
@@ -762,7 +788,6 @@ object SingleCoreBenchmarks {
                         val opsPerSecond = iterationCount.toDouble() / (timeMs / 1000.0)
 
                         CpuAffinityManager.resetPerformance()
-                        CpuAffinityManager.resetCpuAffinity()
 
                         // Thermal stabilization delay (skip for test runs)
                         if (!isTestRun) {
@@ -794,6 +819,3768 @@ object SingleCoreBenchmarks {
                                                         )
                                                 }
                                                 .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 11: String Search (Boyer-Moore-Horspool)
+         *
+         * - Corpus of newline-separated strings over a 4-letter alphabet (generated outside timing)
+         * - Pattern is taken from the first corpus string so at least one match always exists
+         * - Horspool's bad-character skips produce irregular, data-dependent memory access
+         *
+         * METRIC: Matches found per second
+         */
+        suspend fun stringSearch(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(
+                                TAG,
+                                "Starting Single-Core String Search (strings: ${params.stringSearchCount}, pattern length: ${params.stringSearchPatternLen})"
+                        )
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val stringLength = 16
+                        val patternLength = params.stringSearchPatternLen.coerceIn(1, stringLength)
+                        val iterations = params.stringSearchIterations
+
+                        // Build corpus and shift table OUTSIDE timing block
+                        val corpus = BenchmarkHelpers.generateSearchCorpus(params.stringSearchCount, stringLength)
+                        val pattern = corpus.copyOfRange(0, patternLength)
+                        val shift = BenchmarkHelpers.buildHorspoolShiftTable(pattern)
+
+                        val perf = PerfReadings()
+                        val (totalMatches, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                var matches = 0L
+                                                BenchmarkControl.repeatUntilCancelled(iterations) {
+                                                        matches +=
+                                                                BenchmarkHelpers.countOccurrencesHorspool(
+                                                                        corpus,
+                                                                        0,
+                                                                        corpus.size,
+                                                                        pattern,
+                                                                        shift
+                                                                )
+                                                }
+                                                matches
+                                        }
+                                }
+
+                        val opsPerSecond = if (timeMs > 0) totalMatches.toDouble() / (timeMs / 1000.0) else 0.0
+                        val bytesScanned = corpus.size.toLong() * iterations
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core String Search",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = totalMatches >= iterations && timeMs > 0,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("string_count", params.stringSearchCount)
+                                                        put("pattern", String(pattern, Charsets.US_ASCII))
+                                                        put("pattern_length", patternLength)
+                                                        put("iterations", iterations)
+                                                        put("total_matches", totalMatches)
+                                                        put("bytes_scanned", bytesScanned)
+                                                        put("matches_per_sec", opsPerSecond)
+                                                        put("algorithm", "Boyer-Moore-Horspool")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 12: Graph Breadth-First Search
+         *
+         * - Random sparse directed graph in CSR form, average out-degree 8 (built outside timing)
+         * - Each iteration runs a full BFS from a seeded random root
+         * - Pointer-chasing through the edge array stresses cache and memory latency
+         *
+         * METRIC: Nodes visited per second
+         */
+        suspend fun graphBfs(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(
+                                TAG,
+                                "Starting Single-Core Graph BFS (nodes: ${params.graphNodeCount}, iterations: ${params.graphBfsIterations})"
+                        )
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val nodeCount = params.graphNodeCount
+                        val iterations = params.graphBfsIterations
+
+                        // Build graph and pick roots OUTSIDE timing block
+                        val (offsets, targets) = BenchmarkHelpers.generateRandomGraph(nodeCount)
+                        val rootRng = XorShift128Plus(0xB0F5L)
+                        val roots = IntArray(iterations) { rootRng.nextInt(nodeCount) }
+
+                        val perf = PerfReadings()
+                        val (totalVisited, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                var visited = 0L
+                                                for (root in roots) {
+                                                        visited += BenchmarkHelpers.breadthFirstSearch(offsets, targets, root)
+                                                }
+                                                visited
+                                        }
+                                }
+
+                        val opsPerSecond = if (timeMs > 0) totalVisited.toDouble() / (timeMs / 1000.0) else 0.0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Graph BFS",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = totalVisited >= iterations && timeMs > 0,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("node_count", nodeCount)
+                                                        put("edge_count", targets.size)
+                                                        put("iterations", iterations)
+                                                        put("total_nodes_visited", totalVisited)
+                                                        put("nodes_visited_per_sec", opsPerSecond)
+                                                        put("algorithm", "Queue-based BFS over CSR graph")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 13: FFT (Cooley-Tukey radix-2)
+         *
+         * - In-place iterative FFT on a seeded complex signal
+         * - Each iteration is a forward + inverse round trip (2 transforms)
+         * - Bit-reversal and strided butterflies stress FP throughput and cache behaviour
+         *
+         * VALIDATION: IFFT(FFT(x)) must reproduce x within 1e-6
+         * METRIC: Flops per second (5 * N * log2(N) per transform)
+         */
+        suspend fun fft(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        // Round down to a power of two so the radix-2 kernel always applies
+                        val fftSize = Integer.highestOneBit(params.fftSize.coerceAtLeast(2))
+                        if (fftSize != params.fftSize) {
+                                Log.w(TAG, "FFT size ${params.fftSize} is not a power of two, using $fftSize")
+                        }
+                        Log.d(
+                                TAG,
+                                "Starting Single-Core FFT (size: $fftSize, iterations: ${params.fftIterations})"
                         )
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val iterations = params.fftIterations
+
+                        // Generate signal OUTSIDE timing block
+                        val (signalRe, signalIm) = BenchmarkHelpers.generateFftSignal(fftSize)
+
+                        val perf = PerfReadings()
+                        val (maxError, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkHelpers.performFftRoundTrips(signalRe, signalIm, iterations)
+                                        }
+                                }
+
+                        val totalFlops = BenchmarkHelpers.fftFlops(fftSize) * 2.0 * iterations
+                        val opsPerSecond = if (timeMs > 0) totalFlops / (timeMs / 1000.0) else 0.0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core FFT",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = maxError < 1e-6 && timeMs > 0,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("fft_size", fftSize)
+                                                        put("iterations", iterations)
+                                                        put("transforms", iterations * 2)
+                                                        put("total_flops", totalFlops)
+                                                        put("max_round_trip_error", maxError)
+                                                        put("flops_per_sec", opsPerSecond)
+                                                        put("algorithm", "Iterative radix-2 Cooley-Tukey")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 14: AES-128-CBC
+         *
+         * - Encrypts then decrypts [WorkloadParams.aesDataSizeMb] MB through javax.crypto
+         * - The JCE provider uses ARMv8 crypto extensions when present, so results reflect
+         *   hardware vs software AES; detection is reported in the metrics
+         *
+         * METRIC: Bytes processed (encrypted + decrypted) per second
+         */
+        suspend fun aes128(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(TAG, "Starting Single-Core AES-128-CBC (size: ${params.aesDataSizeMb} MB)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val sizeMb = params.aesDataSizeMb
+                        val hardwareAes = CpuAffinityManager.hasHardwareAes()
+
+                        val perf = PerfReadings()
+                        val (roundTripValid, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkHelpers.performAesCbcRoundTrips(sizeMb, 0xAE5L)
+                                        }
+                                }
+
+                        val totalBytes = sizeMb.toLong() * 1024 * 1024 * 2
+                        val opsPerSecond = if (timeMs > 0) totalBytes.toDouble() / (timeMs / 1000.0) else 0.0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core AES-128",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = roundTripValid && timeMs > 0,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("data_size_mb", sizeMb)
+                                                        put("total_bytes", totalBytes)
+                                                        put("bytes_per_sec", opsPerSecond)
+                                                        put("hardware_aes", hardwareAes)
+                                                        put("round_trip_valid", roundTripValid)
+                                                        put("algorithm", "AES-128-CBC (javax.crypto)")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 15: Fibonacci Sequence - Loop
+         *
+         * - Computes the real F(n) with a constant-space loop, cycling n over fibonacciNRange
+         * - No recursion, so it is safe on devices with small thread stacks
+         * - fibonacciNRange is clamped to [0, 92] (largest F(n) that fits in a Long)
+         *
+         * METRIC: Fibonacci numbers computed per second
+         */
+        suspend fun fibonacciLoop(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val startN = params.fibonacciNRange.first.coerceIn(0, BenchmarkHelpers.MAX_FIBONACCI_N)
+                        val endN = params.fibonacciNRange.second.coerceIn(startN, BenchmarkHelpers.MAX_FIBONACCI_N)
+                        Log.d(TAG, "Starting Single-Core Fibonacci Loop (n: $startN..$endN, iterations: ${params.fibonacciIterations})")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val iterations = params.fibonacciIterations
+                        val span = endN - startN + 1
+
+                        val perf = PerfReadings()
+                        val (checksum, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                var sum = 0L
+                                                BenchmarkControl.repeatUntilCancelled(iterations, checkEvery = BenchmarkControl.FINE_GRAINED_CHECK_STRIDE) { i ->
+                                                        sum += BenchmarkHelpers.fibonacciLoop(startN + i % span)
+                                                }
+                                                sum
+                                        }
+                                }
+
+                        val opsPerSecond = if (timeMs > 0) iterations.toDouble() / (timeMs / 1000.0) else 0.0
+                        // Cross-check the loop against the DP table for the largest n
+                        val isValid =
+                                BenchmarkHelpers.fibonacciLoop(endN) ==
+                                        BenchmarkHelpers.fibonacciDp(endN, LongArray(BenchmarkHelpers.MAX_FIBONACCI_N + 1)) &&
+                                        timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Fibonacci Loop",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("start_n", startN)
+                                                        put("end_n", endN)
+                                                        put("iterations", iterations)
+                                                        put("checksum", checksum)
+                                                        put("implementation", "Iterative loop")
+                                                        put("time_complexity", "O(n)")
+                                                        put("space_complexity", "O(1)")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 16: Fibonacci Sequence - Dynamic Programming
+         *
+         * - Fills a reused LongArray table bottom-up for each F(n), cycling n over fibonacciNRange
+         * - Adds a dependent load/store per step compared with the loop variant
+         *
+         * METRIC: Fibonacci numbers computed per second
+         */
+        suspend fun fibonacciDp(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val startN = params.fibonacciNRange.first.coerceIn(0, BenchmarkHelpers.MAX_FIBONACCI_N)
+                        val endN = params.fibonacciNRange.second.coerceIn(startN, BenchmarkHelpers.MAX_FIBONACCI_N)
+                        Log.d(TAG, "Starting Single-Core Fibonacci DP (n: $startN..$endN, iterations: ${params.fibonacciIterations})")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val iterations = params.fibonacciIterations
+                        val span = endN - startN + 1
+                        val table = LongArray(BenchmarkHelpers.MAX_FIBONACCI_N + 1)
+
+                        val perf = PerfReadings()
+                        val (checksum, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                var sum = 0L
+                                                BenchmarkControl.repeatUntilCancelled(iterations, checkEvery = BenchmarkControl.FINE_GRAINED_CHECK_STRIDE) { i ->
+                                                        sum += BenchmarkHelpers.fibonacciDp(startN + i % span, table)
+                                                }
+                                                sum
+                                        }
+                                }
+
+                        val opsPerSecond = if (timeMs > 0) iterations.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid =
+                                BenchmarkHelpers.fibonacciDp(endN, table) == BenchmarkHelpers.fibonacciLoop(endN) &&
+                                        timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Fibonacci DP",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("start_n", startN)
+                                                        put("end_n", endN)
+                                                        put("iterations", iterations)
+                                                        put("checksum", checksum)
+                                                        put("implementation", "Bottom-up DP table")
+                                                        put("time_complexity", "O(n)")
+                                                        put("space_complexity", "O(n)")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 17: Radix Sort
+         *
+         * - LSD radix sort of stringSortCount random 32-bit keys (8 bits per pass, 4 passes)
+         * - Sorts a fresh copy of the same input stringSortIterations times
+         * - Counterpart to the comparison-based String Sorting benchmark
+         *
+         * METRIC: Elements sorted per second
+         */
+        suspend fun radixSort(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(TAG, "Starting Single-Core Radix Sort (elements: ${params.stringSortCount}, iterations: ${params.stringSortIterations})")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val input = BenchmarkHelpers.generateRadixSortInput(params.stringSortCount)
+                        val work = IntArray(input.size)
+                        val scratch = IntArray(input.size)
+                        val iterations = params.stringSortIterations
+
+                        val perf = PerfReadings()
+                        val (_, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkControl.repeatUntilCancelled(iterations) {
+                                                        System.arraycopy(input, 0, work, 0, input.size)
+                                                        BenchmarkHelpers.radixSortLsd(work, scratch)
+                                                }
+                                        }
+                                }
+
+                        val totalElements = input.size.toLong() * iterations
+                        val opsPerSecond = if (timeMs > 0) totalElements.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid =
+                                BenchmarkHelpers.isSortedUnsigned(work) &&
+                                        work.sumOf { it.toLong() } == input.sumOf { it.toLong() } &&
+                                        timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Radix Sort",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("elements", input.size)
+                                                        put("iterations", iterations)
+                                                        put("total_elements", totalElements)
+                                                        put("elements_per_sec", opsPerSecond)
+                                                        put("implementation", "LSD radix sort, 8 bits x 4 passes")
+                                                        put("time_complexity", "O(n)")
+                                                        put("space_complexity", "O(n)")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 18: Regex Matching
+         *
+         * - Scans regexCorpusSize random sentences for an email-like pattern (java.util.regex)
+         * - Word boundaries, character classes and backtracking on every '@'-free word
+         *
+         * METRIC: Matches found per second
+         */
+        suspend fun regex(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(TAG, "Starting Single-Core Regex (sentences: ${params.regexCorpusSize})")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val (sentences, expectedMatches) = BenchmarkHelpers.generateRegexCorpus(params.regexCorpusSize)
+                        val corpusBytes = sentences.sumOf { it.length.toLong() }
+                        val pattern = java.util.regex.Pattern.compile(BenchmarkHelpers.EMAIL_PATTERN)
+
+                        val perf = PerfReadings()
+                        val (matches, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkHelpers.countRegexMatches(sentences, 0, sentences.size, pattern)
+                                        }
+                                }
+
+                        val opsPerSecond = if (timeMs > 0) matches.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid = matches == expectedMatches && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Regex",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("sentences", sentences.size)
+                                                        put("corpus_bytes", corpusBytes)
+                                                        put("matches", matches)
+                                                        put("expected_matches", expectedMatches)
+                                                        put("matches_per_sec", opsPerSecond)
+                                                        put(
+                                                                "bytes_per_sec",
+                                                                if (timeMs > 0) corpusBytes / (timeMs / 1000.0) else 0.0
+                                                        )
+                                                        put("pattern", BenchmarkHelpers.EMAIL_PATTERN)
+                                                        put("implementation", "java.util.regex")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 19: SHA-256 Hash Chain
+         *
+         * - hashChainDepth rounds of SHA-256, each hashing the previous digest (64-byte seed)
+         * - Inherently sequential: measures per-hash latency on one big core rather than bulk
+         *   throughput, so there is no multi-core variant
+         *
+         * METRIC: Hashes per second
+         */
+        suspend fun sha256Chain(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(TAG, "Starting Single-Core SHA-256 Chain (depth: ${params.hashChainDepth})")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val depth = params.hashChainDepth
+                        val seed = ByteArray(64) { it.toByte() }
+
+                        val perf = PerfReadings()
+                        val (finalHash, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) { BenchmarkHelpers.sha256Chain(seed, depth) }
+                                }
+
+                        val opsPerSecond = if (timeMs > 0) depth.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid = finalHash.size == 32 && finalHash.any { it != 0.toByte() } && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core SHA-256 Chain",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("depth", depth)
+                                                        put("hashes_per_sec", opsPerSecond)
+                                                        put("final_hash", finalHash.joinToString("") { "%02x".format(it) })
+                                                        put("algorithm", "SHA-256 (java.security.MessageDigest)")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 20: CRC-32 Checksum
+         *
+         * - CRC-32 (java.util.zip) of a hashDataSizeMb buffer, CRC32_PASSES times
+         * - One-shot: the whole buffer in a single update call
+         * - Incremental: the same buffer in 64 KB updates
+         * - A SHA-256 pass over the same buffer is timed for comparison
+         *
+         * METRIC: Bytes checksummed per second (one-shot)
+         */
+        suspend fun crc32(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(TAG, "Starting Single-Core CRC-32 (buffer: ${params.hashDataSizeMb} MB)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val bufferBytes = params.hashDataSizeMb * 1024 * 1024
+                        val buffer = ByteArray(bufferBytes).also { java.util.Random(0xC3C3L).nextBytes(it) }
+                        val passes = BenchmarkHelpers.CRC32_PASSES
+                        val totalBytes = bufferBytes.toLong() * passes
+
+                        val perf = PerfReadings()
+                        val (oneShotCrc, oneShotMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                var crc = 0L
+                                                BenchmarkControl.repeatUntilCancelled(passes) { crc = BenchmarkHelpers.crc32(buffer) }
+                                                crc
+                                        }
+                                }
+                        val (incrementalCrc, incrementalMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                var crc = 0L
+                                                BenchmarkControl.repeatUntilCancelled(passes) { crc = BenchmarkHelpers.crc32Incremental(buffer) }
+                                                crc
+                                        }
+                                }
+                        val (_, sha256Ms) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                java.security.MessageDigest.getInstance("SHA-256").digest(buffer)
+                                        }
+                                }
+
+                        fun bytesPerSec(bytes: Long, ms: Long) = if (ms > 0) bytes / (ms / 1000.0) else 0.0
+                        val opsPerSecond = bytesPerSec(totalBytes, oneShotMs)
+                        val sha256BytesPerSec = bytesPerSec(bufferBytes.toLong(), sha256Ms)
+                        val isValid = oneShotCrc == incrementalCrc && oneShotMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core CRC-32",
+                                executionTimeMs = (oneShotMs + incrementalMs).toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("buffer_bytes", bufferBytes)
+                                                        put("passes", passes)
+                                                        put("crc32", oneShotCrc)
+                                                        put("one_shot_bytes_per_sec", opsPerSecond)
+                                                        put("incremental_bytes_per_sec", bytesPerSec(totalBytes, incrementalMs))
+                                                        put("incremental_chunk_bytes", BenchmarkHelpers.CRC32_CHUNK_BYTES)
+                                                        put("sha256_bytes_per_sec", sha256BytesPerSec)
+                                                        put(
+                                                                "crc32_to_sha256_ratio",
+                                                                if (sha256BytesPerSec > 0) opsPerSecond / sha256BytesPerSec else 0.0
+                                                        )
+                                                        put("implementation", "java.util.zip.CRC32")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 21: Base64 Encode/Decode
+         *
+         * - Base64-encodes a hashDataSizeMb buffer and decodes the result, BASE64_PASSES times
+         * - Valid only if the decoded bytes match the original exactly
+         *
+         * METRIC: Bytes of original data round-tripped per second
+         */
+        suspend fun base64(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(TAG, "Starting Single-Core Base64 (buffer: ${params.hashDataSizeMb} MB)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val bufferBytes = params.hashDataSizeMb * 1024 * 1024
+                        val buffer = ByteArray(bufferBytes).also { java.util.Random(0xB64L).nextBytes(it) }
+                        val passes = BenchmarkHelpers.BASE64_PASSES
+
+                        val perf = PerfReadings()
+                        val (decoded, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                var last = ByteArray(0)
+                                                BenchmarkControl.repeatUntilCancelled(passes) { last = BenchmarkHelpers.base64RoundTrip(buffer) }
+                                                last
+                                        }
+                                }
+
+                        val totalBytes = bufferBytes.toLong() * passes
+                        val opsPerSecond = if (timeMs > 0) totalBytes.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid = decoded.contentEquals(buffer) && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Base64",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("buffer_bytes", bufferBytes)
+                                                        put("passes", passes)
+                                                        put("total_bytes", totalBytes)
+                                                        put("bytes_per_sec", opsPerSecond)
+                                                        put("implementation", "android.util.Base64 (NO_WRAP)")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 22: Merge Sort
+         *
+         * - Classic top-down merge sort of sortElementCount random 32-bit keys
+         * - Sequential baseline for Multi-Core Parallel Merge Sort (same input and algorithm)
+         *
+         * METRIC: Elements sorted per second
+         */
+        suspend fun mergeSort(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(TAG, "Starting Single-Core Merge Sort (elements: ${params.sortElementCount})")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val input = BenchmarkHelpers.generateRadixSortInput(params.sortElementCount)
+                        val work = input.copyOf()
+                        val scratch = IntArray(input.size)
+
+                        val perf = PerfReadings()
+                        val (_, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) { BenchmarkHelpers.mergeSort(work, scratch) }
+                                }
+
+                        val opsPerSecond = if (timeMs > 0) input.size.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid =
+                                BenchmarkHelpers.isSorted(work) &&
+                                        work.sumOf { it.toLong() } == input.sumOf { it.toLong() } &&
+                                        timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Merge Sort",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("elements", input.size)
+                                                        put("elements_per_sec", opsPerSecond)
+                                                        put("implementation", "Top-down merge sort")
+                                                        put("time_complexity", "O(n log n)")
+                                                        put("space_complexity", "O(n)")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 23: Levenshtein Distance
+         *
+         * - Edit distance for levenshteinPairCount pairs of random 30-50 character strings
+         * - Wagner-Fischer DP with a single reused row
+         *
+         * METRIC: Pairs compared per second
+         */
+        suspend fun levenshtein(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(TAG, "Starting Single-Core Levenshtein (pairs: ${params.levenshteinPairCount})")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val pairCount = params.levenshteinPairCount
+                        val (pool, indices) = BenchmarkHelpers.generateLevenshteinPairs(pairCount)
+
+                        val perf = PerfReadings()
+                        val (distanceSum, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkHelpers.sumLevenshteinDistances(pool, indices, 0, pairCount)
+                                        }
+                                }
+
+                        val opsPerSecond = if (timeMs > 0) pairCount.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid = BenchmarkHelpers.levenshteinSelfCheck(pool) && distanceSum > 0 && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Levenshtein",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("pairs", pairCount)
+                                                        put("distance_sum", distanceSum)
+                                                        put("pairs_per_sec", opsPerSecond)
+                                                        put("implementation", "Wagner-Fischer, single-row DP")
+                                                        put("time_complexity", "O(m × n) per pair")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 24: Sparse Matrix-Vector Multiplication
+         *
+         * - CSR matrix of matrixSize × matrixSize with 10 random non-zeros per row
+         * - y = A · x repeated matrixIterations × matrixSize times (2 × nnz flops each)
+         *
+         * METRIC: Floating-point operations per second
+         */
+        suspend fun sparseMatVec(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(TAG, "Starting Single-Core Sparse MatVec (size: ${params.matrixSize})")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val size = params.matrixSize
+                        val (rowOffsets, columns, values) = BenchmarkHelpers.generateSparseMatrix(size)
+                        val x = DoubleArray(size) { 1.0 + it % 7 }
+                        val y = DoubleArray(size)
+                        val repetitions = params.matrixIterations.toLong() * size
+
+                        val perf = PerfReadings()
+                        val (checksum, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                var sum = 0.0
+                                                for (rep in 0 until repetitions) {
+                                                        BenchmarkHelpers.sparseMatVec(rowOffsets, columns, values, x, y)
+                                                        sum += y[(rep % size).toInt()]
+                                                }
+                                                sum
+                                        }
+                                }
+
+                        val totalFlops = 2.0 * columns.size * repetitions
+                        val opsPerSecond = if (timeMs > 0) totalFlops / (timeMs / 1000.0) else 0.0
+                        val isValid =
+                                BenchmarkHelpers.verifySparseMatVec(rowOffsets, columns, values, x, y) && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Sparse MatVec",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("size", size)
+                                                        put("nnz", columns.size)
+                                                        put("repetitions", repetitions)
+                                                        put("total_flops", totalFlops)
+                                                        put("gflops", opsPerSecond / 1e9)
+                                                        put("checksum", checksum)
+                                                        put("format", "CSR")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 25: 0/1 Knapsack
+         *
+         * - knapsackItems random items, capacity knapsackItems × 10
+         * - Iterative DP over one capacity row (items × capacity cell updates)
+         *
+         * METRIC: Items processed per second
+         */
+        suspend fun knapsack(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(TAG, "Starting Single-Core Knapsack (items: ${params.knapsackItems})")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val items = params.knapsackItems
+                        val capacity = items * 10
+                        val (weights, values) = BenchmarkHelpers.generateKnapsackInstance(items)
+
+                        val perf = PerfReadings()
+                        val (optimum, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) { BenchmarkHelpers.knapsack01(weights, values, capacity) }
+                                }
+
+                        val opsPerSecond = if (timeMs > 0) items.toDouble() / (timeMs / 1000.0) else 0.0
+                        val cellUpdates = items.toLong() * capacity
+                        val isValid = optimum > 0 && optimum <= values.sumOf { it.toLong() } && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Knapsack",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("items", items)
+                                                        put("capacity", capacity)
+                                                        put("optimal_value", optimum)
+                                                        put("items_per_sec", opsPerSecond)
+                                                        put(
+                                                                "cell_updates_per_sec",
+                                                                if (timeMs > 0) cellUpdates / (timeMs / 1000.0) else 0.0
+                                                        )
+                                                        put("implementation", "1D DP row, descending capacity")
+                                                        put("time_complexity", "O(items × capacity)")
+                                                        put("space_complexity", "O(capacity)")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 26: Dijkstra Shortest Paths
+         *
+         * - Random weighted directed graph (graphNodeCount nodes, average out-degree 8,
+         *   weights 1-100), built outside timing
+         * - Single-source shortest paths from a seeded random source with a binary heap
+         *
+         * METRIC: Nodes settled per second
+         */
+        suspend fun dijkstra(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(TAG, "Starting Single-Core Dijkstra (nodes: ${params.graphNodeCount})")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val nodeCount = params.graphNodeCount
+                        val (offsets, targets) = BenchmarkHelpers.generateRandomGraph(nodeCount)
+                        val weights = BenchmarkHelpers.generateEdgeWeights(targets.size)
+                        val source = XorShift128Plus(0xD1E5L).nextInt(nodeCount)
+                        val dist = LongArray(nodeCount)
+
+                        val perf = PerfReadings()
+                        val (settled, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkHelpers.dijkstra(offsets, targets, weights, source, dist)
+                                        }
+                                }
+
+                        val opsPerSecond = if (timeMs > 0) settled.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid =
+                                BenchmarkHelpers.verifyShortestPaths(offsets, targets, weights, source, dist) &&
+                                        timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Dijkstra",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("nodes", nodeCount)
+                                                        put("edges", targets.size)
+                                                        put("source", source)
+                                                        put("nodes_settled", settled)
+                                                        put("nodes_settled_per_sec", opsPerSecond)
+                                                        put("implementation", "Binary heap with lazy deletion")
+                                                        put("time_complexity", "O((V + E) log V)")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 27: Convex Hull
+         *
+         * - monteCarloSamples / 10 random 2D points (integer coordinates, generated outside timing)
+         * - Andrew's monotone chain: sort by x then y, build lower and upper hulls
+         *
+         * METRIC: Points processed per second
+         */
+        suspend fun convexHull(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val pointCount = (params.monteCarloSamples / 10).toInt().coerceAtLeast(3)
+                        Log.d(TAG, "Starting Single-Core Convex Hull (points: $pointCount)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val points = BenchmarkHelpers.generateHullPoints(pointCount)
+
+                        val perf = PerfReadings()
+                        val (hull, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) { BenchmarkHelpers.convexHull(points) }
+                                }
+
+                        val opsPerSecond = if (timeMs > 0) pointCount.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid =
+                                hull.size in 3..pointCount &&
+                                        BenchmarkHelpers.isConvexCounterClockwise(hull) &&
+                                        timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Convex Hull",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("points", pointCount)
+                                                        put("hull_vertices", hull.size)
+                                                        put("points_per_sec", opsPerSecond)
+                                                        put("implementation", "Andrew's monotone chain")
+                                                        put("time_complexity", "O(n log n)")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 28: Bloom Filter
+         *
+         * - Inserts stringSortCount random 16-letter keys into a 3-hash bitset Bloom filter
+         *   (10 bits per key)
+         * - Queries stringSortCount keys, half inserted and half new, BLOOM_QUERY_PASSES times
+         * - Valid only if every inserted key is found and false positives stay below 5%
+         *
+         * METRIC: Queries per second
+         */
+        suspend fun bloomFilter(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val keyCount = params.stringSortCount
+                        Log.d(TAG, "Starting Single-Core Bloom Filter (keys: $keyCount)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val (inserted, queries) = BenchmarkHelpers.generateBloomKeys(keyCount)
+                        val memberCount = keyCount / 2
+                        val passes = BenchmarkHelpers.BLOOM_QUERY_PASSES
+                        val filter = BloomFilter(keyCount * BenchmarkHelpers.BLOOM_BITS_PER_KEY)
+
+                        val perf = PerfReadings()
+                        val (_, insertMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) { inserted.forEach { filter.add(it) } }
+                                }
+                        val (hits, queryMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkHelpers.queryBloomFilter(filter, queries, 0, keyCount, memberCount, passes)
+                                        }
+                                }
+
+                        val totalQueries = keyCount.toLong() * passes
+                        val opsPerSecond = if (queryMs > 0) totalQueries.toDouble() / (queryMs / 1000.0) else 0.0
+                        val (memberHits, falsePositives) = hits
+                        val nonMembers = keyCount - memberCount
+                        val falsePositiveRate = if (nonMembers > 0) falsePositives.toDouble() / nonMembers else 0.0
+                        val isValid = memberHits == memberCount && falsePositiveRate < 0.05 && queryMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Bloom Filter",
+                                executionTimeMs = (insertMs + queryMs).toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("keys", keyCount)
+                                                        put("query_passes", passes)
+                                                        put("total_queries", totalQueries)
+                                                        put("queries_per_sec", opsPerSecond)
+                                                        put("insert_time_ms", insertMs)
+                                                        put("false_positive_rate", falsePositiveRate)
+                                                        put("fill_ratio", filter.fillRatio())
+                                                        put("hash_functions", BloomFilter.HASH_COUNT)
+                                                        put("bits_per_key", BenchmarkHelpers.BLOOM_BITS_PER_KEY)
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 29: UTF-8 Validation
+         *
+         * - hashDataSizeMb of valid mixed ASCII / multi-byte UTF-8, validated UTF8_PASSES times
+         *   with a strict CharsetDecoder
+         * - A copy with one invalid byte injected mid-buffer must be rejected
+         *
+         * METRIC: Bytes validated per second
+         */
+        suspend fun utf8Validation(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(TAG, "Starting Single-Core UTF-8 Validation (buffer: ${params.hashDataSizeMb} MB)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val bufferBytes = params.hashDataSizeMb * 1024 * 1024
+                        val buffer = BenchmarkHelpers.generateUtf8Buffer(bufferBytes)
+                        val corrupted = buffer.copyOf().also { it[bufferBytes / 2] = 0xFF.toByte() }
+                        val decoder = BenchmarkHelpers.strictUtf8Decoder()
+                        val out = java.nio.CharBuffer.allocate(bufferBytes)
+                        val passes = BenchmarkHelpers.UTF8_PASSES
+
+                        val perf = PerfReadings()
+                        val (allValid, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                var valid = true
+                                                BenchmarkControl.repeatUntilCancelled(passes) {
+                                                        valid = valid && BenchmarkHelpers.isValidUtf8(buffer, 0, bufferBytes, decoder, out)
+                                                }
+                                                valid
+                                        }
+                                }
+                        val (corruptedAccepted, rejectMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkHelpers.isValidUtf8(corrupted, 0, bufferBytes, decoder, out)
+                                        }
+                                }
+
+                        val totalBytes = bufferBytes.toLong() * passes
+                        val opsPerSecond = if (timeMs > 0) totalBytes.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid = allValid && !corruptedAccepted && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core UTF-8 Validation",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("buffer_bytes", bufferBytes)
+                                                        put("passes", passes)
+                                                        put("bytes_per_sec", opsPerSecond)
+                                                        put("invalid_input_rejected", !corruptedAccepted)
+                                                        put("invalid_reject_time_ms", rejectMs)
+                                                        put("implementation", "java.nio CharsetDecoder (REPORT)")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 30: JSON Serialization
+         *
+         * - Builds the same objects the JSON parsing benchmark generates as an in-memory
+         *   JSONObject tree (outside the timing block)
+         * - Serializes the whole document JSON_SERIALIZATION_PASSES times
+         * - Validated by length against the generated text and by parsing the output back
+         *
+         * METRIC: Bytes of serialized output per second
+         */
+        suspend fun jsonSerialization(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(TAG, "Starting Single-Core JSON Serialization (${params.jsonDataSizeMb}MB document)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val dataSize = params.jsonDataSizeMb * 1024 * 1024
+                        val passes = BenchmarkHelpers.JSON_SERIALIZATION_PASSES
+                        val document = BenchmarkHelpers.buildComplexJsonDocument(dataSize)
+                        val objectCount = document.getJSONArray("data").length()
+
+                        val perf = PerfReadings()
+                        val (result, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkHelpers.serializeJsonDocument(document, passes)
+                                        }
+                                }
+                        val (totalBytes, serialized) = result
+
+                        val expectedLength = BenchmarkHelpers.generateComplexJson(dataSize).length
+                        val roundTripCount = JSONObject(serialized).getJSONArray("data").length()
+                        val opsPerSecond = if (timeMs > 0) totalBytes.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid = serialized.length == expectedLength && roundTripCount == objectCount && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core JSON Serialization",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("object_count", objectCount)
+                                                        put("document_bytes", serialized.length)
+                                                        put("passes", passes)
+                                                        put("total_bytes", totalBytes)
+                                                        put("bytes_per_sec", opsPerSecond)
+                                                        put("round_trip_ok", roundTripCount == objectCount)
+                                                        put("implementation", "org.json JSONObject.toString")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 31: Polynomial Evaluation
+         *
+         * - POLYNOMIAL_COUNT random polynomials of polynomialDegree, each evaluated at
+         *   polynomialEvalPoints points in [-1, 1) with Horner's method
+         * - Validated against the naive power sum on a sample of points
+         *
+         * METRIC: Polynomial evaluations per second
+         */
+        suspend fun polynomialEval(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(
+                                TAG,
+                                "Starting Single-Core Polynomial Evaluation (degree: ${params.polynomialDegree}, points: ${params.polynomialEvalPoints})"
+                        )
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val polys =
+                                BenchmarkHelpers.generatePolynomials(
+                                        BenchmarkHelpers.POLYNOMIAL_COUNT,
+                                        params.polynomialDegree
+                                )
+                        val points = BenchmarkHelpers.generateEvalPoints(params.polynomialEvalPoints)
+
+                        val perf = PerfReadings()
+                        val (checksum, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkHelpers.evaluatePolynomials(polys, points)
+                                        }
+                                }
+
+                        val evaluations = polys.size.toLong() * points.size
+                        val opsPerSecond = if (timeMs > 0) evaluations.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid = checksum.isFinite() && BenchmarkHelpers.verifyHorner(polys, points) && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Polynomial Evaluation",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("polynomials", polys.size)
+                                                        put("degree", params.polynomialDegree)
+                                                        put("eval_points", points.size)
+                                                        put("evaluations", evaluations)
+                                                        put("multiply_adds", evaluations * params.polynomialDegree)
+                                                        put("checksum", checksum)
+                                                        put("evaluations_per_sec", opsPerSecond)
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 32: Prime Factorization
+         *
+         * - Factorizes monteCarloSamples / 1000 random values in [10^9, 10^15]
+         * - Trial division up to 10^4, then Pollard's rho (Brent) with Miller-Rabin primality
+         * - Every factorization is checked: the product of its factors must equal the input
+         *
+         * METRIC: Factorizations per second
+         */
+        suspend fun primeFactorization(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val count = (params.monteCarloSamples / 1000).toInt().coerceAtLeast(1)
+                        Log.d(TAG, "Starting Single-Core Prime Factorization ($count values)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val values = BenchmarkHelpers.generateFactorizationInputs(count)
+
+                        val perf = PerfReadings()
+                        val (totalFactors, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkHelpers.factorizeAll(values)
+                                        }
+                                }
+
+                        val opsPerSecond = if (timeMs > 0) count.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid = totalFactors >= count && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Prime Factorization",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("values", count)
+                                                        put("total_factors", totalFactors)
+                                                        put("products_verified", totalFactors >= 0)
+                                                        put("trial_division_limit", BenchmarkHelpers.TRIAL_DIVISION_LIMIT)
+                                                        put("factorizations_per_sec", opsPerSecond)
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 33: Bitwise Operations
+         *
+         * - integerOpsCount iterations of popcount, parity and bit reversal on a 64-bit value
+         * - The value is rotated by its popcount each iteration (serial dependency chain)
+         * - Min / max popcount are reported to show the values stay non-trivial
+         *
+         * METRIC: Iterations per second
+         */
+        suspend fun bitwiseOperations(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(TAG, "Starting Single-Core Bitwise Operations (${params.integerOpsCount} iterations)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val iterations = params.integerOpsCount
+
+                        val perf = PerfReadings()
+                        val (result, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkHelpers.bitwiseOperations(iterations)
+                                        }
+                                }
+                        val (checksum, minPopcount, maxPopcount) = result
+
+                        val opsPerSecond = if (timeMs > 0) iterations.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid = minPopcount < maxPopcount && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Bitwise Operations",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("iterations", iterations)
+                                                        put("checksum", checksum)
+                                                        put("min_popcount", minPopcount)
+                                                        put("max_popcount", maxPopcount)
+                                                        put("ops_per_sec", opsPerSecond)
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 34: Monte Carlo Integration
+         *
+         * - monteCarloSamples hit-or-miss samples each for ∫₀¹ exp(-x²) dx and the oscillating
+         *   ∫₀¹ sin(1/x) dx
+         * - Both estimates must land within MONTE_CARLO_INTEGRATION_TOLERANCE of the exact value
+         *
+         * METRIC: Samples per second
+         */
+        suspend fun monteCarloIntegration(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(TAG, "Starting Single-Core Monte Carlo Integration (${params.monteCarloSamples} samples per integral)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val samples = params.monteCarloSamples
+
+                        val perf = PerfReadings()
+                        val (hits, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                Pair(
+                                                        BenchmarkHelpers.sampleGaussianIntegral(samples),
+                                                        BenchmarkHelpers.sampleSinReciprocalIntegral(samples)
+                                                )
+                                        }
+                                }
+
+                        val gaussianEstimate = hits.first.toDouble() / samples
+                        val sinEstimate = 2.0 * hits.second / samples
+                        val gaussianError = Math.abs(gaussianEstimate - BenchmarkHelpers.GAUSSIAN_INTEGRAL_EXACT)
+                        val sinError = Math.abs(sinEstimate - BenchmarkHelpers.SIN_RECIPROCAL_INTEGRAL_EXACT)
+
+                        val totalSamples = samples * 2
+                        val opsPerSecond = if (timeMs > 0) totalSamples.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid =
+                                gaussianError < BenchmarkHelpers.MONTE_CARLO_INTEGRATION_TOLERANCE &&
+                                        sinError < BenchmarkHelpers.MONTE_CARLO_INTEGRATION_TOLERANCE &&
+                                        timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Monte Carlo Integration",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("samples", totalSamples)
+                                                        put("gaussian_estimate", gaussianEstimate)
+                                                        put("gaussian_error", gaussianError)
+                                                        put("sin_reciprocal_estimate", sinEstimate)
+                                                        put("sin_reciprocal_error", sinError)
+                                                        put("samples_per_sec", opsPerSecond)
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Sorting comparison micro-suite (not a scored benchmark)
+         *
+         * - One set of stringSortCount 31-bit keys, copied three times outside the timed sections
+         * - Timed separately: library stable sort (TimSort, boxed), library unstable sort
+         *   (dual-pivot quicksort, primitive) and LSD radix sort
+         *
+         * Shows how much a device gains from each strategy on identical input.
+         */
+        suspend fun sortingComparison(params: WorkloadParams): SortingComparisonResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(TAG, "Starting Single-Core Sorting Comparison (${params.stringSortCount} keys)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        // Non-negative keys so signed and unsigned orders agree
+                        val keys = BenchmarkHelpers.generateRadixSortInput(params.stringSortCount).map { it ushr 1 }.toIntArray()
+                        val boxed = keys.toTypedArray()
+                        val unstable = keys.copyOf()
+                        val radix = keys.copyOf()
+                        val scratch = IntArray(keys.size)
+
+                        val (stableSortNs, unstableSortNs, radixSortNs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        var startTime = System.nanoTime()
+                                        java.util.Arrays.sort(boxed)
+                                        val stableNs = System.nanoTime() - startTime
+
+                                        startTime = System.nanoTime()
+                                        unstable.sort()
+                                        val unstableNs = System.nanoTime() - startTime
+
+                                        startTime = System.nanoTime()
+                                        BenchmarkHelpers.radixSortLsd(radix, scratch)
+                                        Triple(stableNs, unstableNs, System.nanoTime() - startTime)
+                                }
+
+                        val outputsMatch = unstable.contentEquals(radix) && boxed.indices.all { boxed[it] == unstable[it] }
+
+                        CpuAffinityManager.resetPerformance()
+
+                        return@withContext SortingComparisonResult(
+                                stableSortNs = stableSortNs,
+                                unstableSortNs = unstableSortNs,
+                                radixSortNs = radixSortNs,
+                                count = keys.size,
+                                outputsMatch = outputsMatch
+                        )
+                }
+
+        /**
+         * Test 35: Fibonacci Sequence - 128-bit Integers
+         *
+         * - Iterative F(n) in an unsigned 128-bit value (two Longs with carry), cycling n over
+         *   fibonacciNRange clamped to [0, MAX_FIBONACCI_N_U128]
+         * - fibonacciIterations / WIDE_FIBONACCI_ITERATION_DIVISOR computations
+         * - The largest value is cross-checked against the BigInteger implementation
+         *
+         * METRIC: Fibonacci numbers computed per second
+         */
+        suspend fun fibonacciU128(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val startN = params.fibonacciNRange.first.coerceIn(0, BenchmarkHelpers.MAX_FIBONACCI_N_U128)
+                        val endN = params.fibonacciNRange.second.coerceIn(startN, BenchmarkHelpers.MAX_FIBONACCI_N_U128)
+                        val iterations =
+                                (params.fibonacciIterations / BenchmarkHelpers.WIDE_FIBONACCI_ITERATION_DIVISOR).coerceAtLeast(1)
+                        Log.d(TAG, "Starting Single-Core Fibonacci u128 (n: $startN..$endN, iterations: $iterations)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val span = endN - startN + 1
+                        val words = LongArray(2)
+
+                        val perf = PerfReadings()
+                        val (checksum, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                var sum = 0L
+                                                BenchmarkControl.repeatUntilCancelled(iterations, checkEvery = BenchmarkControl.FINE_GRAINED_CHECK_STRIDE) { i ->
+                                                        BenchmarkHelpers.fibonacciU128(startN + i % span, words)
+                                                        sum += words[0] xor words[1]
+                                                }
+                                                sum
+                                        }
+                                }
+
+                        val opsPerSecond = if (timeMs > 0) iterations.toDouble() / (timeMs / 1000.0) else 0.0
+                        BenchmarkHelpers.fibonacciU128(endN, words)
+                        val largest = BenchmarkHelpers.u128ToBigInteger(words[0], words[1])
+                        val isValid = largest == BenchmarkHelpers.fibonacciBigInt(endN).first && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Fibonacci u128",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("start_n", startN)
+                                                        put("end_n", endN)
+                                                        put("iterations", iterations)
+                                                        put("checksum", checksum)
+                                                        put("largest_value_digits", largest.toString().length)
+                                                        put("implementation", "Iterative loop, 128-bit (hi/lo Long with carry)")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 36: Fibonacci Sequence - Arbitrary Precision
+         *
+         * - Fast-doubling F(n) with BigInteger, cycling n over fibonacciNRange clamped to
+         *   [0, MAX_FIBONACCI_N_BIGINT]
+         * - fibonacciIterations / WIDE_FIBONACCI_ITERATION_DIVISOR computations
+         * - The largest value is checked against the identity F(n+1)·F(n-1) - F(n)² = (-1)^n
+         *
+         * METRIC: BigInteger multiplications per second
+         */
+        suspend fun fibonacciBigInt(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val startN = params.fibonacciNRange.first.coerceIn(0, BenchmarkHelpers.MAX_FIBONACCI_N_BIGINT)
+                        val endN = params.fibonacciNRange.second.coerceIn(startN, BenchmarkHelpers.MAX_FIBONACCI_N_BIGINT)
+                        val iterations =
+                                (params.fibonacciIterations / BenchmarkHelpers.WIDE_FIBONACCI_ITERATION_DIVISOR).coerceAtLeast(1)
+                        Log.d(TAG, "Starting Single-Core Fibonacci BigInt (n: $startN..$endN, iterations: $iterations)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val span = endN - startN + 1
+
+                        val perf = PerfReadings()
+                        val (totals, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                var multiplications = 0L
+                                                var bits = 0L
+                                                BenchmarkControl.repeatUntilCancelled(iterations, checkEvery = BenchmarkControl.FINE_GRAINED_CHECK_STRIDE) { i ->
+                                                        val (value, muls) = BenchmarkHelpers.fibonacciBigInt(startN + i % span)
+                                                        multiplications += muls
+                                                        bits += value.bitLength()
+                                                }
+                                                Pair(multiplications, bits)
+                                        }
+                                }
+                        val (multiplications, checksum) = totals
+
+                        val opsPerSecond = if (timeMs > 0) multiplications.toDouble() / (timeMs / 1000.0) else 0.0
+                        val largest = BenchmarkHelpers.fibonacciBigInt(endN).first
+                        // Cassini's identity (needs n >= 1)
+                        val isValid =
+                                if (endN >= 1) {
+                                        val cassini =
+                                                BenchmarkHelpers.fibonacciBigInt(endN + 1).first
+                                                        .multiply(BenchmarkHelpers.fibonacciBigInt(endN - 1).first)
+                                                        .subtract(largest.multiply(largest))
+                                        cassini == (if (endN % 2 == 0) BigInteger.ONE else BigInteger.ONE.negate()) && timeMs > 0
+                                } else {
+                                        timeMs > 0
+                                }
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Fibonacci BigInt",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("start_n", startN)
+                                                        put("end_n", endN)
+                                                        put("iterations", iterations)
+                                                        put("multiplications", multiplications)
+                                                        put("checksum", checksum)
+                                                        put("largest_value_digits", largest.toString().length)
+                                                        put("implementation", "Fast doubling, BigInteger")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 37: String Formatting
+         *
+         * - stringSortCount records of five fields, each formatted with RECORD_FORMAT
+         *   ("%08.3f %d %016x %e %s"), STRING_FORMAT_PASSES times
+         * - Every formatted string must be non-empty
+         *
+         * METRIC: Format calls per second
+         */
+        suspend fun stringFormatting(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(TAG, "Starting Single-Core String Formatting (${params.stringSortCount} records)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val records = BenchmarkHelpers.generateFormatRecords(params.stringSortCount, seed = 0xF0A7L)
+                        val passes = BenchmarkHelpers.STRING_FORMAT_PASSES
+
+                        val perf = PerfReadings()
+                        val (formatted, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkHelpers.formatRecords(records, 0, records.size, passes)
+                                        }
+                                }
+                        val (totalBytes, emptyCount) = formatted
+
+                        val formatCalls = records.size.toLong() * passes
+                        val opsPerSecond = if (timeMs > 0) formatCalls / (timeMs / 1000.0) else 0.0
+                        val isValid = emptyCount == 0 && totalBytes > 0 && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core String Formatting",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("records", records.size)
+                                                        put("passes", passes)
+                                                        put("format_calls", formatCalls)
+                                                        put("total_bytes", totalBytes)
+                                                        put("format", BenchmarkHelpers.RECORD_FORMAT)
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 38: Atomic Operations
+         *
+         * - integerOpsCount rounds of fetch-and-add, compare-and-set and load on one AtomicLong
+         * - Uncontended, so every CAS must succeed and the counter must end at 2 × iterations
+         *
+         * METRIC: Atomic operations per second (three per round)
+         */
+        suspend fun atomicOps(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(TAG, "Starting Single-Core Atomic Operations (${params.integerOpsCount} iterations)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val iterations = params.integerOpsCount
+                        val counter = AtomicLong()
+
+                        val perf = PerfReadings()
+                        val (result, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) { BenchmarkHelpers.atomicOps(counter, iterations) }
+                                }
+                        val (casSuccesses, checksum) = result
+
+                        val atomicOps = iterations * 3
+                        val opsPerSecond = if (timeMs > 0) atomicOps / (timeMs / 1000.0) else 0.0
+                        val isValid = casSuccesses == iterations && counter.get() == 2 * iterations && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Atomic Operations",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("iterations", iterations)
+                                                        put("atomic_ops", atomicOps)
+                                                        put("cas_successes", casSuccesses)
+                                                        put("checksum", checksum)
+                                                        put("uncontended_ops_per_sec", opsPerSecond)
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 39: Channel Throughput
+         *
+         * - integerOpsCount / CHANNEL_MESSAGE_DIVISOR messages through an unbounded
+         *   LinkedBlockingQueue from a producer thread to this (consumer) thread
+         * - The producer inherits this thread's core affinity, so both ends share one core
+         * - Each message carries its send time; latency percentiles are reported
+         *
+         * METRIC: Messages per second
+         */
+        suspend fun channelThroughput(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val messages =
+                                (params.integerOpsCount / BenchmarkHelpers.CHANNEL_MESSAGE_DIVISOR)
+                                        .coerceIn(1L, Int.MAX_VALUE.toLong())
+                                        .toInt()
+                        Log.d(TAG, "Starting Single-Core Channel Throughput ($messages messages)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val queue = LinkedBlockingQueue<BenchmarkHelpers.ChannelMessage>()
+
+                        val perf = PerfReadings()
+                        val (received, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                val producer =
+                                                        Thread { BenchmarkHelpers.produceMessages(queue, 0, messages.toLong()) }
+                                                producer.start()
+                                                val result = BenchmarkHelpers.consumeMessages(queue, messages, producers = 1)
+                                                producer.join()
+                                                result
+                                        }
+                                }
+                        val (latencies, inOrder) = received
+                        latencies.sort()
+
+                        val opsPerSecond = if (timeMs > 0) messages.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid = inOrder && queue.isEmpty() && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Channel Throughput",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("messages", messages)
+                                                        put("producers", 1)
+                                                        put("latency_p50_ns", BenchmarkHelpers.percentileOfSorted(latencies, 50.0))
+                                                        put("latency_p99_ns", BenchmarkHelpers.percentileOfSorted(latencies, 99.0))
+                                                        put("messages_per_sec", opsPerSecond)
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 40: Hash Map
+         *
+         * - Inserts stringSortCount distinct string keys into a java.util.HashMap, then looks each
+         *   one up; repeated HASHMAP_PASSES times on a fresh map
+         * - Every lookup must return the value inserted for its key
+         * - Reports the bucket collision rate for the key set
+         *
+         * METRIC: Map operations (inserts + lookups) per second
+         */
+        suspend fun concurrentHashMap(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(TAG, "Starting Single-Core Hash Map (${params.stringSortCount} keys)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val keys = BenchmarkHelpers.generateHashMapKeys(params.stringSortCount, seed = 0x4A54L)
+                        val passes = BenchmarkHelpers.HASHMAP_PASSES
+
+                        val perf = PerfReadings()
+                        val (hits, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                var total = 0L
+                                                BenchmarkControl.repeatUntilCancelled(passes) {
+                                                        total += BenchmarkHelpers.insertAndLookup(HashMap(), keys, 0, keys.size)
+                                                }
+                                                total
+                                        }
+                                }
+
+                        val operations = keys.size.toLong() * 2 * passes
+                        val opsPerSecond = if (timeMs > 0) operations / (timeMs / 1000.0) else 0.0
+                        val isValid = hits == keys.size.toLong() * passes && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Hash Map",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("keys", keys.size)
+                                                        put("passes", passes)
+                                                        put("operations", operations)
+                                                        put("collision_rate", BenchmarkHelpers.hashCollisionRate(keys))
+                                                        put("implementation", "java.util.HashMap")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 41: Memory Allocation
+         *
+         * - integerOpsCount / ALLOCATION_DIVISOR byte arrays of random size 1-4096, each
+         *   dropped shortly after allocation (the scored run)
+         * - Then 1/ALLOCATION_CLASS_DIVISOR as many per size class: small (<256 B),
+         *   medium (256 B-4 KB) and large (>4 KB, up to 16 KB)
+         *
+         * METRIC: Allocations per second (mixed sizes)
+         */
+        suspend fun allocation(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val allocations = (params.integerOpsCount / BenchmarkHelpers.ALLOCATION_DIVISOR).coerceAtLeast(1L)
+                        val classAllocations = (allocations / BenchmarkHelpers.ALLOCATION_CLASS_DIVISOR).coerceAtLeast(1L)
+                        Log.d(TAG, "Starting Single-Core Memory Allocation ($allocations allocations)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        // (name, min size, max size)
+                        val sizeClasses = listOf(Triple("small", 1, 255), Triple("medium", 256, 4096), Triple("large", 4097, 16384))
+                        val perf = PerfReadings()
+                        val (mixed, classRates) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        val mixed =
+                                                BenchmarkHelpers.measureBenchmark(perf) {
+                                                        BenchmarkHelpers.allocationLoop(allocations, 1, 4096, seed = 0xA110CL)
+                                                }
+                                        val classRates =
+                                                sizeClasses.map { (name, minSize, maxSize) ->
+                                                        val (bytes, classMs) =
+                                                                BenchmarkHelpers.measureBenchmark(perf) {
+                                                                        BenchmarkHelpers.allocationLoop(classAllocations, minSize, maxSize, seed = 0xA110CL)
+                                                                }
+                                                        val rate = if (classMs > 0) classAllocations / (classMs / 1000.0) else 0.0
+                                                        Triple(name, rate, bytes >= classAllocations * minSize && bytes <= classAllocations * maxSize)
+                                                }
+                                        Pair(mixed, classRates)
+                                }
+                        val (totalBytes, timeMs) = mixed
+
+                        val opsPerSecond = if (timeMs > 0) allocations / (timeMs / 1000.0) else 0.0
+                        val isValid =
+                                totalBytes >= allocations && totalBytes <= allocations * 4096 &&
+                                        classRates.all { it.third } && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Memory Allocation",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("allocations", allocations)
+                                                        put("total_bytes", totalBytes)
+                                                        put("allocations_per_sec", opsPerSecond)
+                                                        put("class_allocations", classAllocations)
+                                                        for ((name, rate, _) in classRates) {
+                                                                put("${name}_allocations_per_sec", rate)
+                                                        }
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 42: N-Queens - 64-bit Bitmask Solver
+         *
+         * - Counts all solutions for nqueensSize (clamped to MAX_NQUEENS_BITMASK_SIZE) with
+         *   Long occupancy masks and an explicit stack instead of recursion
+         * - Then times it against the boolean-array solver on a NQUEENS_COMPARISON_SIZE board
+         * - Solution counts must match the known values
+         *
+         * METRIC: Solutions per second
+         */
+        suspend fun nqueensBitmask(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val boardSize = params.nqueensSize.coerceIn(1, BenchmarkHelpers.MAX_NQUEENS_BITMASK_SIZE)
+                        Log.d(TAG, "Starting Single-Core N-Queens Bitmask (size: $boardSize)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val perf = PerfReadings()
+                        val (solutions, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) { BenchmarkHelpers.solveNQueensBitmask(boardSize) }
+                                }
+
+                        val comparisonSize = BenchmarkHelpers.NQUEENS_COMPARISON_SIZE
+                        var startTime = System.nanoTime()
+                        val bitmaskSolutions = BenchmarkHelpers.solveNQueensBitmask(comparisonSize)
+                        val bitmaskNs = System.nanoTime() - startTime
+
+                        startTime = System.nanoTime()
+                        val arraySolutions = BenchmarkHelpers.solveNQueensArrays(comparisonSize)
+                        val arrayNs = System.nanoTime() - startTime
+
+                        val opsPerSecond = if (timeMs > 0) solutions.toDouble() / (timeMs / 1000.0) else 0.0
+                        val expected = BenchmarkHelpers.NQUEENS_KNOWN_SOLUTIONS[comparisonSize]
+                        val isValid =
+                                solutions == BenchmarkHelpers.NQUEENS_KNOWN_SOLUTIONS[boardSize] &&
+                                        bitmaskSolutions == expected &&
+                                        arraySolutions == expected &&
+                                        timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core N-Queens Bitmask",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("board_size", boardSize)
+                                                        put("solution_count", solutions)
+                                                        put("solutions_per_sec", opsPerSecond)
+                                                        put("comparison_board_size", comparisonSize)
+                                                        put("comparison_bitmask_ns", bitmaskNs)
+                                                        put("comparison_array_ns", arrayNs)
+                                                        put(
+                                                                "bitmask_speedup",
+                                                                if (bitmaskNs > 0) arrayNs.toDouble() / bitmaskNs else 0.0
+                                                        )
+                                                        put("implementation", "Iterative 64-bit bitmask backtracking")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 43: Ray Tracing - Anti-aliased
+         *
+         * - Sphere scene (RayTracingScene.threeSpheres) at rayTracingResolution with reflections
+         *   up to rayTracingDepth bounces
+         * - rayTracingSpp randomly jittered sub-pixel rays per pixel, averaged (RNG in the inner
+         *   loop alongside intersection)
+         * - Validation: two independent renders of a small image must show lower per-pixel
+         *   variance at rayTracingSpp than at 1 sample per pixel
+         *
+         * METRIC: Rays per second (width × height × spp)
+         */
+        suspend fun rayTracingAntialiased(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val (width, height) = params.rayTracingResolution
+                        val spp = params.rayTracingSpp
+                        Log.d(TAG, "Starting Single-Core Ray Tracing Anti-aliased (${width}x$height, $spp spp)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val scene = RayTracingScene.threeSpheres()
+                        val image = DoubleArray(width * height * 3)
+
+                        val perf = PerfReadings()
+                        val (checksum, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkHelpers.renderScene(scene, width, height, spp, params.rayTracingDepth, 0x5A5AL, image)
+                                        }
+                                }
+
+                        // Variance check on a small image, outside the timed region
+                        val size = BenchmarkHelpers.RAY_TRACING_VARIANCE_SIZE
+                        val first = DoubleArray(size * size * 3)
+                        val second = DoubleArray(size * size * 3)
+                        BenchmarkHelpers.renderScene(scene, size, size, 1, params.rayTracingDepth, 1L, first)
+                        BenchmarkHelpers.renderScene(scene, size, size, 1, params.rayTracingDepth, 2L, second)
+                        val varianceOneSpp = BenchmarkHelpers.renderVariance(first, second)
+                        BenchmarkHelpers.renderScene(scene, size, size, spp, params.rayTracingDepth, 1L, first)
+                        BenchmarkHelpers.renderScene(scene, size, size, spp, params.rayTracingDepth, 2L, second)
+                        val varianceSpp = BenchmarkHelpers.renderVariance(first, second)
+
+                        val totalRays = width.toLong() * height * spp
+                        val opsPerSecond = if (timeMs > 0) totalRays / (timeMs / 1000.0) else 0.0
+                        val isValid =
+                                checksum.isFinite() && checksum > 0.0 &&
+                                        (spp == 1 || varianceSpp < varianceOneSpp) &&
+                                        timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Ray Tracing Anti-aliased",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("resolution", listOf(width, height).toString())
+                                                        put("spp", spp)
+                                                        put("depth", params.rayTracingDepth)
+                                                        put("spheres", scene.sphereCount)
+                                                        put("total_rays", totalRays)
+                                                        put("checksum", checksum)
+                                                        put("pixel_variance_1spp", varianceOneSpp)
+                                                        put("pixel_variance", varianceSpp)
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 44: Ray Tracing - Bounding Volume Hierarchy
+         *
+         * - RAY_TRACING_BVH_SPHERES random spheres, rendered at rayTracingResolution with
+         *   rayTracingSpp samples per pixel, finding hits by walking a BVH of bounding boxes
+         * - The same frame is then rendered testing every sphere per ray, for comparison; both
+         *   renders must produce the same image
+         *
+         * METRIC: Rays per second (width × height × spp)
+         */
+        suspend fun rayTracingBvh(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val (width, height) = params.rayTracingResolution
+                        val spp = params.rayTracingSpp
+                        Log.d(TAG, "Starting Single-Core Ray Tracing BVH (${width}x$height, $spp spp)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val scene = RayTracingScene.randomSpheres(BenchmarkHelpers.RAY_TRACING_BVH_SPHERES, seed = 0xB7B7L)
+                        val image = DoubleArray(width * height * 3)
+
+                        scene.useBvh = true
+                        val perf = PerfReadings()
+                        val (bvhChecksum, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkHelpers.renderScene(scene, width, height, spp, params.rayTracingDepth, 0x5A5AL, image)
+                                        }
+                                }
+                        val avgNodesVisited = if (scene.raysCast > 0) scene.nodesVisited.toDouble() / scene.raysCast else 0.0
+
+                        scene.useBvh = false
+                        val (flatChecksum, flatTimeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkHelpers.renderScene(scene, width, height, spp, params.rayTracingDepth, 0x5A5AL, image)
+                                        }
+                                }
+
+                        val totalRays = width.toLong() * height * spp
+                        val opsPerSecond = if (timeMs > 0) totalRays / (timeMs / 1000.0) else 0.0
+                        val flatRaysPerSecond = if (flatTimeMs > 0) totalRays / (flatTimeMs / 1000.0) else 0.0
+                        val isValid =
+                                bvhChecksum.isFinite() && bvhChecksum > 0.0 &&
+                                        Math.abs(bvhChecksum - flatChecksum) <= 1e-9 * Math.abs(flatChecksum) &&
+                                        timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Ray Tracing BVH",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("resolution", listOf(width, height).toString())
+                                                        put("spp", spp)
+                                                        put("depth", params.rayTracingDepth)
+                                                        put("spheres", scene.sphereCount)
+                                                        put("bvh_nodes", scene.bvhNodeCount)
+                                                        put("total_rays", totalRays)
+                                                        put("avg_nodes_visited", avgNodesVisited)
+                                                        put("checksum", bvhChecksum)
+                                                        put("flat_rays_per_sec", flatRaysPerSecond)
+                                                        put(
+                                                                "bvh_speedup",
+                                                                if (flatRaysPerSecond > 0) opsPerSecond / flatRaysPerSecond else 0.0
+                                                        )
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 45: Tree Reduction
+         *
+         * - Sum, max and min of scanElementCount doubles by iterative pairwise (tree) reduction,
+         *   repeated scanIterations times
+         * - The sum must match a sequential sum within rounding tolerance; max / min exactly
+         *
+         * METRIC: Elements reduced per second
+         */
+        suspend fun parallelReduction(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val elementCount = params.scanElementCount
+                        val iterations = params.scanIterations
+                        Log.d(TAG, "Starting Single-Core Tree Reduction (elements: $elementCount, iterations: $iterations)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val input = BenchmarkHelpers.generateScanInput(elementCount)
+                        val scratchSize = (elementCount + 1) / 2
+                        val sums = DoubleArray(scratchSize)
+                        val maxes = DoubleArray(scratchSize)
+                        val mins = DoubleArray(scratchSize)
+                        val expected = BenchmarkHelpers.sequentialReduce(input, 0, elementCount)
+
+                        val perf = PerfReadings()
+                        val (maxAbsoluteError, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                var maxError = 0.0
+                                                BenchmarkControl.repeatUntilCancelled(iterations) {
+                                                        val (sum, max, min) =
+                                                                BenchmarkHelpers.treeReduce(input, 0, elementCount, sums, maxes, mins)
+                                                        // Extremes can't differ by rounding: flag any mismatch as an infinite error
+                                                        val error =
+                                                                if (max == expected.second && min == expected.third) {
+                                                                        Math.abs(sum - expected.first)
+                                                                } else {
+                                                                        Double.POSITIVE_INFINITY
+                                                                }
+                                                        maxError = Math.max(maxError, error)
+                                                }
+                                                maxError
+                                        }
+                                }
+
+                        val totalElements = elementCount.toLong() * iterations
+                        val opsPerSecond = if (timeMs > 0) totalElements / (timeMs / 1000.0) else 0.0
+                        val isValid =
+                                maxAbsoluteError <= BenchmarkHelpers.reductionTolerance(elementCount, expected.first) &&
+                                        timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Tree Reduction",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("elements", elementCount)
+                                                        put("iterations", iterations)
+                                                        put("sum", expected.first)
+                                                        put("max", expected.second)
+                                                        put("min", expected.third)
+                                                        put("max_absolute_error", maxAbsoluteError)
+                                                        put("implementation", "Iterative pairwise tree reduction")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 46: LZ77 Compression
+         *
+         * - Sliding-window LZ77 (32 KB window, hash chains, lazy matching; see [Lz77Compressor])
+         *   over compressionDataSizeMb of word-like text
+         * - compressionIterations / LZ77_ITERATION_DIVISOR compress + decompress round trips,
+         *   each checked byte for byte
+         * - Compression and decompression are timed separately
+         *
+         * METRIC: Input bytes compressed per second
+         */
+        suspend fun lz77(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val dataSize = params.compressionDataSizeMb * 1024 * 1024
+                        val passes =
+                                (params.compressionIterations / BenchmarkHelpers.LZ77_ITERATION_DIVISOR)
+                                        .coerceAtLeast(1)
+                        Log.d(TAG, "Starting Single-Core LZ77 (data: ${params.compressionDataSizeMb}MB, passes: $passes)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val data = Lz77Compressor.sampleText(dataSize, 0x1277L)
+                        val compressor = Lz77Compressor()
+                        val compressed = ByteArray(Lz77Compressor.maxCompressedSize(dataSize))
+                        val restored = ByteArray(dataSize)
+
+                        var compressNs = 0L
+                        var decompressNs = 0L
+                        var compressedSize = 0
+                        var roundTripOk = true
+                        val perf = PerfReadings()
+                        val (_, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkControl.repeatUntilCancelled(passes) {
+                                                        val compressStart = System.nanoTime()
+                                                        compressedSize = compressor.compress(data, compressed)
+                                                        val decompressStart = System.nanoTime()
+                                                        val restoredSize =
+                                                                Lz77Compressor.decompress(compressed, compressedSize, restored)
+                                                        decompressNs += System.nanoTime() - decompressStart
+                                                        compressNs += decompressStart - compressStart
+
+                                                        if (restoredSize != dataSize || !restored.contentEquals(data)) {
+                                                                roundTripOk = false
+                                                        }
+                                                }
+                                        }
+                                }
+
+                        val totalBytes = dataSize.toLong() * passes
+                        val compressBytesPerSec = if (compressNs > 0) totalBytes / (compressNs / 1e9) else 0.0
+                        val decompressBytesPerSec = if (decompressNs > 0) totalBytes / (decompressNs / 1e9) else 0.0
+                        val isValid = roundTripOk && compressedSize in 1 until dataSize && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core LZ77",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = compressBytesPerSec,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("data_size_mb", params.compressionDataSizeMb)
+                                                        put("passes", passes)
+                                                        put("compressed_size", compressedSize)
+                                                        put("compression_ratio", compressedSize.toDouble() / dataSize)
+                                                        put("compress_bytes_per_sec", compressBytesPerSec)
+                                                        put("decompress_bytes_per_sec", decompressBytesPerSec)
+                                                        put("round_trip_ok", roundTripOk)
+                                                        put("window_size", Lz77Compressor.WINDOW_SIZE)
+                                                        put("implementation", "Hash-chain LZ77 with lazy matching")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 47: Huffman Coding
+         *
+         * - Canonical Huffman encode + decode of compressionDataSizeMb of word-like text, each
+         *   pass checked byte for byte (see [HuffmanCoder])
+         * - Exercises histogram building, pointer-heavy tree construction and bitstream writing
+         *
+         * METRIC: Input bytes encoded per second
+         */
+        suspend fun huffman(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val dataSize = params.compressionDataSizeMb * 1024 * 1024
+                        val passes =
+                                (params.compressionIterations / BenchmarkHelpers.HUFFMAN_ITERATION_DIVISOR)
+                                        .coerceAtLeast(1)
+                        Log.d(TAG, "Starting Single-Core Huffman (data: ${params.compressionDataSizeMb}MB, passes: $passes)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val data = Lz77Compressor.sampleText(dataSize, 0x4AFFL)
+                        val encoded = ByteArray(HuffmanCoder.maxEncodedSize(dataSize))
+                        val decoded = ByteArray(dataSize)
+
+                        var encodeNs = 0L
+                        var decodeNs = 0L
+                        var encodedSize = 0
+                        var roundTripOk = true
+                        val perf = PerfReadings()
+                        val (_, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkControl.repeatUntilCancelled(passes) {
+                                                        val encodeStart = System.nanoTime()
+                                                        encodedSize = HuffmanCoder.encode(data, 0, dataSize, encoded, 0)
+                                                        val decodeStart = System.nanoTime()
+                                                        val decodedSize = HuffmanCoder.decode(encoded, 0, decoded, 0)
+                                                        decodeNs += System.nanoTime() - decodeStart
+                                                        encodeNs += decodeStart - encodeStart
+
+                                                        if (decodedSize != dataSize || !decoded.contentEquals(data)) {
+                                                                roundTripOk = false
+                                                        }
+                                                }
+                                        }
+                                }
+
+                        val totalBytes = dataSize.toLong() * passes
+                        val encodeBytesPerSec = if (encodeNs > 0) totalBytes / (encodeNs / 1e9) else 0.0
+                        val decodeBytesPerSec = if (decodeNs > 0) totalBytes / (decodeNs / 1e9) else 0.0
+                        val isValid = roundTripOk && encodedSize in 1 until dataSize && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Huffman",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = encodeBytesPerSec,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("data_size_mb", params.compressionDataSizeMb)
+                                                        put("passes", passes)
+                                                        put("encoded_size", encodedSize)
+                                                        put("compression_ratio", encodedSize.toDouble() / dataSize)
+                                                        put("encode_bytes_per_sec", encodeBytesPerSec)
+                                                        put("decode_bytes_per_sec", decodeBytesPerSec)
+                                                        put("round_trip_ok", roundTripOk)
+                                                        put("implementation", "Canonical Huffman coding")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 48: Fibonacci Sequence - Matrix Exponentiation
+         *
+         * - F(n) by 2×2 matrix powers with exact 128-bit arithmetic, cycling n over
+         *   fibonacciNRange clamped to [0, MAX_FIBONACCI_N_U128]
+         * - fibonacciIterations / WIDE_FIBONACCI_ITERATION_DIVISOR computations, as for the
+         *   iterative u128 variant (Test 35)
+         * - Branch-free multiply-add loops, vs the loop-carried additions of Test 35
+         * - Cross-checked against the iterative algorithm for n ≤ 40 and for the largest n
+         *
+         * METRIC: Fibonacci computations per second
+         */
+        suspend fun fibonacciMatrix(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val startN = params.fibonacciNRange.first.coerceIn(0, BenchmarkHelpers.MAX_FIBONACCI_N_U128)
+                        val endN = params.fibonacciNRange.second.coerceIn(startN, BenchmarkHelpers.MAX_FIBONACCI_N_U128)
+                        val iterations =
+                                (params.fibonacciIterations / BenchmarkHelpers.WIDE_FIBONACCI_ITERATION_DIVISOR).coerceAtLeast(1)
+                        Log.d(TAG, "Starting Single-Core Fibonacci Matrix (n: $startN..$endN, iterations: $iterations)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val span = endN - startN + 1
+                        val words = LongArray(2)
+
+                        val perf = PerfReadings()
+                        val (checksum, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                var sum = 0L
+                                                BenchmarkControl.repeatUntilCancelled(iterations, checkEvery = BenchmarkControl.FINE_GRAINED_CHECK_STRIDE) { i ->
+                                                        BenchmarkHelpers.fibonacciMatrixU128(startN + i % span, words)
+                                                        sum += words[0] xor words[1]
+                                                }
+                                                sum
+                                        }
+                                }
+
+                        val opsPerSecond = if (timeMs > 0) iterations.toDouble() / (timeMs / 1000.0) else 0.0
+
+                        val expected = LongArray(2)
+                        val matchesIterative =
+                                ((0..40) + endN).all { n ->
+                                        BenchmarkHelpers.fibonacciMatrixU128(n, words)
+                                        BenchmarkHelpers.fibonacciU128(n, expected)
+                                        words.contentEquals(expected)
+                                }
+                        val isValid = matchesIterative && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Fibonacci Matrix",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("start_n", startN)
+                                                        put("end_n", endN)
+                                                        put("iterations", iterations)
+                                                        put("checksum", checksum)
+                                                        put("matches_iterative", matchesIterative)
+                                                        put("implementation", "2x2 matrix exponentiation, 128-bit (hi/lo Long)")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 49: N-Queens - Dancing Links (Algorithm X)
+         *
+         * - Counts all solutions for nqueensSize (clamped to MAX_NQUEENS_DLX_SIZE) as an exact
+         *   cover problem, with Knuth's dancing links over Int index arrays
+         * - Then times the boolean-array backtracking solver on the same board, whose count must
+         *   match: linked-list cover/uncover traffic vs array flag updates
+         *
+         * METRIC: Solutions per second
+         */
+        suspend fun nqueensDancingLinks(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val boardSize = params.nqueensSize.coerceIn(1, BenchmarkHelpers.MAX_NQUEENS_DLX_SIZE)
+                        Log.d(TAG, "Starting Single-Core N-Queens Dancing Links (size: $boardSize)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val links = DancingLinks.nQueens(boardSize)
+                        val perf = PerfReadings()
+                        val (solutions, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) { links.countSolutions() }
+                                }
+
+                        val startTime = System.nanoTime()
+                        val backtrackingSolutions = BenchmarkHelpers.solveNQueensArrays(boardSize)
+                        val backtrackingNs = System.nanoTime() - startTime
+
+                        val opsPerSecond = if (timeMs > 0) solutions.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid =
+                                solutions == BenchmarkHelpers.NQUEENS_KNOWN_SOLUTIONS[boardSize] &&
+                                        backtrackingSolutions == solutions &&
+                                        timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core N-Queens Dancing Links",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("board_size", boardSize)
+                                                        put("solution_count", solutions)
+                                                        put("solutions_per_sec", opsPerSecond)
+                                                        put("search_nodes", links.searchNodes)
+                                                        put("backtracking_ns", backtrackingNs)
+                                                        put(
+                                                                "dlx_overhead",
+                                                                if (backtrackingNs > 0) timeMs * 1_000_000.0 / backtrackingNs else 0.0
+                                                        )
+                                                        put("implementation", "Algorithm X, dancing links over Int index arrays")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 50: Transcendental Functions
+         *
+         * - integerOpsCount / 5 calls each of sin, cos, exp, ln and sqrt on inputs spanning each
+         *   function's usual domain, timed per function
+         * - Results are summed per function so no call can be eliminated
+         *
+         * METRIC: Function evaluations per second (all five functions)
+         */
+        suspend fun floatTranscendental(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val functions = BenchmarkHelpers.TRANSCENDENTAL_FUNCTIONS
+                        val callsPerFunction = (params.integerOpsCount / functions.size).coerceAtLeast(1L)
+                        Log.d(TAG, "Starting Single-Core Transcendental Functions ($callsPerFunction calls per function)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val inputs = Array(functions.size) { BenchmarkHelpers.transcendentalInputs(it) }
+                        val sums = DoubleArray(functions.size)
+                        val functionNs = LongArray(functions.size)
+
+                        val perf = PerfReadings()
+                        val (_, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                for (f in functions.indices) {
+                                                        val startTime = System.nanoTime()
+                                                        sums[f] = BenchmarkHelpers.evaluateTranscendental(f, inputs[f], callsPerFunction)
+                                                        functionNs[f] = System.nanoTime() - startTime
+                                                }
+                                        }
+                                }
+
+                        val totalCalls = callsPerFunction * functions.size
+                        val opsPerSecond = if (timeMs > 0) totalCalls.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid = sums.all { it.isFinite() } && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Transcendental Functions",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("calls_per_function", callsPerFunction)
+                                                        put("total_calls", totalCalls)
+                                                        put("flops_per_sec", opsPerSecond)
+                                                        put(
+                                                                "per_function_ops_per_sec",
+                                                                JSONObject().apply {
+                                                                        functions.forEachIndexed { f, name ->
+                                                                                put(
+                                                                                        name,
+                                                                                        if (functionNs[f] > 0) callsPerFunction * 1e9 / functionNs[f] else 0.0
+                                                                                )
+                                                                        }
+                                                                }
+                                                        )
+                                                        put("checksum", sums.sum())
+                                                        put("implementation", "java.lang.Math")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 51: Unicode String Sorting
+         *
+         * - Merge sorts stringSortCount mixed-script strings (ASCII, Cyrillic, CJK, Arabic)
+         *   UNICODE_SORT_PASSES times by String.compareTo, then as many times comparing
+         *   lowercase() copies
+         * - String.compareTo orders by UTF-16 code unit, a cheap stand-in for real collation
+         *   (java.text.Collator); the lowercase pass adds the per-comparison case folding and
+         *   allocation a case-insensitive sort pays
+         *
+         * METRIC: Strings sorted per second (both comparators)
+         */
+        suspend fun unicodeStringSorting(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val count = params.stringSortCount
+                        val passes = BenchmarkHelpers.UNICODE_SORT_PASSES
+                        Log.d(TAG, "Starting Single-Core Unicode String Sorting ($count strings, $passes passes)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val corpus = BenchmarkHelpers.generateUnicodeStrings(count)
+                        val nonAsciiFraction =
+                                if (count > 0) corpus.count { s -> s.any { it.code > 0x7F } }.toDouble() / count else 0.0
+                        val ordinal = Comparator<String> { a, b -> a.compareTo(b) }
+                        val lowercase = Comparator<String> { a, b -> a.lowercase().compareTo(b.lowercase()) }
+                        val data = corpus.copyOf()
+                        val scratch = corpus.copyOf()
+                        var sorted = true
+
+                        fun sortPasses(comparator: Comparator<String>): Long {
+                                val startTime = System.nanoTime()
+                                BenchmarkControl.repeatUntilCancelled(passes) {
+                                        System.arraycopy(corpus, 0, data, 0, count)
+                                        BenchmarkHelpers.mergeSort(data, scratch, comparator)
+                                }
+                                val elapsed = System.nanoTime() - startTime
+                                sorted = sorted && BenchmarkHelpers.isSorted(data, comparator)
+                                return elapsed
+                        }
+
+                        val perf = PerfReadings()
+                        val (nanos, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) { Pair(sortPasses(ordinal), sortPasses(lowercase)) }
+                                }
+                        val (ordinalNs, lowercaseNs) = nanos
+
+                        val totalSorted = count.toLong() * passes * 2
+                        val opsPerSecond = if (timeMs > 0) totalSorted.toDouble() / (timeMs / 1000.0) else 0.0
+                        val ordinalOps = if (ordinalNs > 0) count.toLong() * passes * 1e9 / ordinalNs else 0.0
+                        val lowercaseOps = if (lowercaseNs > 0) count.toLong() * passes * 1e9 / lowercaseNs else 0.0
+                        val isValid = sorted && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Unicode String Sorting",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("string_count", count)
+                                                        put("passes", passes)
+                                                        put("non_ascii_fraction", nonAsciiFraction)
+                                                        put("ordinal_strings_per_sec", ordinalOps)
+                                                        put("lowercase_strings_per_sec", lowercaseOps)
+                                                        put(
+                                                                "lowercase_slowdown",
+                                                                if (lowercaseOps > 0.0) ordinalOps / lowercaseOps else 0.0
+                                                        )
+                                                        put("comparison", "UTF-16 code unit order (collation proxy)")
+                                                        put("implementation", "Top-down merge sort")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 52: Haar Wavelet Transform
+         *
+         * - Full multi-level 1D Haar transform and inverse on scanElementCount samples (rounded
+         *   up to a power of two), scanIterations / WAVELET_ITERATION_DIVISOR round trips
+         * - Validated by inverse(forward(x)) ≈ x after the last round trip
+         *
+         * METRIC: Samples transformed per second (forward and inverse both count)
+         */
+        suspend fun waveletTransform(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val size = BenchmarkHelpers.nextPowerOfTwo(params.scanElementCount)
+                        val roundTrips = (params.scanIterations / BenchmarkHelpers.WAVELET_ITERATION_DIVISOR).coerceAtLeast(1)
+                        Log.d(TAG, "Starting Single-Core Haar Wavelet (size: $size, round trips: $roundTrips)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val signal = BenchmarkHelpers.generateWaveletSignal(size)
+                        val data = signal.copyOf()
+                        val scratch = DoubleArray(size)
+
+                        val perf = PerfReadings()
+                        val (_, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkControl.repeatUntilCancelled(roundTrips) {
+                                                        BenchmarkHelpers.haarForward(data, scratch)
+                                                        BenchmarkHelpers.haarInverse(data, scratch)
+                                                }
+                                        }
+                                }
+
+                        val maxError = BenchmarkHelpers.maxAbsDifference(data, signal)
+                        val samples = size.toLong() * roundTrips * 2
+                        val opsPerSecond = if (timeMs > 0) samples.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid = maxError < 1e-9 && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Haar Wavelet",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("signal_size", size)
+                                                        put("round_trips", roundTrips)
+                                                        put("samples_transformed", samples)
+                                                        put("max_round_trip_error", maxError)
+                                                        put("samples_per_sec", opsPerSecond)
+                                                        put("algorithm", "Multi-level orthonormal Haar")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 53: String Deduplication
+         *
+         * - stringSortCount strings, dedupDuplicateFraction of them copies of another, inserted
+         *   into a java.util.HashSet; repeated DEDUP_PASSES times on a fresh set
+         * - The set must end up with exactly the distinct strings
+         *
+         * METRIC: Strings inserted per second
+         */
+        suspend fun stringDedup(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(
+                                TAG,
+                                "Starting Single-Core String Dedup (${params.stringSortCount} strings, " +
+                                        "duplicates: ${params.dedupDuplicateFraction})"
+                        )
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val (strings, uniqueCount) =
+                                BenchmarkHelpers.generateDedupStrings(params.stringSortCount, params.dedupDuplicateFraction)
+                        val passes = BenchmarkHelpers.DEDUP_PASSES
+
+                        val perf = PerfReadings()
+                        val (distinct, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                var total = 0L
+                                                BenchmarkControl.repeatUntilCancelled(passes) {
+                                                        total += BenchmarkHelpers.insertDistinct(HashSet(), strings, 0, strings.size)
+                                                }
+                                                total
+                                        }
+                                }
+
+                        val inserted = strings.size.toLong() * passes
+                        val opsPerSecond = if (timeMs > 0) inserted / (timeMs / 1000.0) else 0.0
+                        val isValid = distinct == uniqueCount.toLong() * passes && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core String Dedup",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("strings", strings.size)
+                                                        put("unique_strings", uniqueCount)
+                                                        put("duplicate_fraction", params.dedupDuplicateFraction)
+                                                        put("passes", passes)
+                                                        put("strings_inserted", inserted)
+                                                        put("strings_per_sec", opsPerSecond)
+                                                        put("implementation", "java.util.HashSet")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 54: Z-Order Curve
+         *
+         * - Encodes monteCarloSamples / ZORDER_DIVISOR unsigned 32-bit (x, y) pairs into 64-bit
+         *   Morton codes with the shift-and-mask bit interleave, then decodes them
+         * - Every decoded pair must match its input
+         * - Plain shifts only: the JVM doesn't expose BMI2 pdep / pext (and ARM has no
+         *   equivalent), so pdep_pext_fraction is always 0
+         *
+         * METRIC: Coordinate pairs encoded and decoded per second
+         */
+        suspend fun zorderCurve(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val pairs = (params.monteCarloSamples / BenchmarkHelpers.ZORDER_DIVISOR).coerceAtLeast(1L)
+                        Log.d(TAG, "Starting Single-Core Z-Order Curve ($pairs pairs)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val (xs, ys) = BenchmarkHelpers.generateZOrderCoordinates(BenchmarkHelpers.ZORDER_TABLE_SIZE)
+                        val codes = LongArray(xs.size)
+
+                        val perf = PerfReadings()
+                        val (mismatches, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkHelpers.zorderRoundTrips(xs, ys, codes, 0L, pairs)
+                                        }
+                                }
+
+                        val opsPerSecond = if (timeMs > 0) pairs.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid = mismatches == 0L && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Z-Order Curve",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("pairs", pairs)
+                                                        put("mismatches", mismatches)
+                                                        put("pairs_per_sec", opsPerSecond)
+                                                        put("pdep_pext_fraction", 0.0)
+                                                        put("algorithm", "Shift-and-mask bit interleave")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 55: JPEG Decode Simulation
+         *
+         * - matrixSize² / 64 level-shifted 8×8 blocks, each run through a reference 2D DCT,
+         *   quantize / dequantize with the standard luminance table and the IDCT;
+         *   matrixIterations passes
+         * - Validated by an unquantized IDCT(DCT(x)) ≈ x on every block; the quantized output's
+         *   PSNR is reported
+         *
+         * METRIC: Blocks per second
+         */
+        suspend fun jpegDecodeSim(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val blockCount = (params.matrixSize * params.matrixSize / Dct8x8.BLOCK_SIZE).coerceAtLeast(1)
+                        val passes = params.matrixIterations
+                        Log.d(TAG, "Starting Single-Core JPEG Decode Sim (blocks: $blockCount, passes: $passes)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val samples = BenchmarkHelpers.generateJpegBlocks(blockCount)
+                        val out = DoubleArray(samples.size)
+                        val dct = Dct8x8()
+                        val coefficients = DoubleArray(Dct8x8.BLOCK_SIZE)
+
+                        val perf = PerfReadings()
+                        val (_, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkControl.repeatUntilCancelled(passes) {
+                                                        BenchmarkHelpers.jpegDecodeBlocks(dct, samples, out, coefficients, 0, blockCount)
+                                                }
+                                        }
+                                }
+
+                        val roundTripError = BenchmarkHelpers.dctRoundTripError(samples)
+                        val psnr = BenchmarkHelpers.psnr(out, samples)
+                        val blocks = blockCount.toLong() * passes
+                        val opsPerSecond = if (timeMs > 0) blocks.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid = roundTripError < 1e-9 && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core JPEG Decode Sim",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("block_count", blockCount)
+                                                        put("passes", passes)
+                                                        put("blocks_processed", blocks)
+                                                        put("max_round_trip_error", roundTripError)
+                                                        put("quantized_psnr_db", psnr)
+                                                        put("blocks_per_sec", opsPerSecond)
+                                                        put("algorithm", "Reference separable 8x8 DCT-II / IDCT, quality 50 luminance table")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 56: LRU Cache
+         *
+         * - stringSortCount × LRU_ACCESS_MULTIPLIER Zipf-distributed (80/20) accesses over
+         *   LRU_KEY_SPACE keys against a LRU_CAPACITY-entry linked-list + HashMap LRU cache;
+         *   every miss is followed by a put
+         * - Every hit must return the value put for its key, and evictions must equal misses
+         *   minus the entries still cached
+         *
+         * METRIC: Cache operations (gets + puts) per second
+         */
+        suspend fun lruCache(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val accessCount = params.stringSortCount.toLong() * BenchmarkHelpers.LRU_ACCESS_MULTIPLIER
+                        Log.d(TAG, "Starting Single-Core LRU Cache ($accessCount accesses)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val accesses =
+                                BenchmarkHelpers.generateZipfAccesses(
+                                        BenchmarkHelpers.LRU_ACCESS_TABLE_SIZE,
+                                        BenchmarkHelpers.LRU_KEY_SPACE
+                                )
+                        val cache = LruCache(BenchmarkHelpers.LRU_CAPACITY)
+
+                        val perf = PerfReadings()
+                        val (counts, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkHelpers.replayCacheAccesses(cache, accesses, 0, accessCount)
+                                        }
+                                }
+                        val (hits, wrongValues) = counts
+
+                        val misses = accessCount - hits
+                        val operations = accessCount + misses
+                        val hitRate = hits.toDouble() / accessCount
+                        val opsPerSecond = if (timeMs > 0) operations / (timeMs / 1000.0) else 0.0
+                        val isValid = wrongValues == 0L && cache.evictions == misses - cache.size && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core LRU Cache",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("capacity", BenchmarkHelpers.LRU_CAPACITY)
+                                                        put("key_space", BenchmarkHelpers.LRU_KEY_SPACE)
+                                                        put("accesses", accessCount)
+                                                        put("operations", operations)
+                                                        put("hit_rate", hitRate)
+                                                        put("eviction_count", cache.evictions)
+                                                        put("access_pattern", "Zipf (s = 1)")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 57: Priority Queue
+         *
+         * - stringSortCount × PRIORITY_QUEUE_MULTIPLIER operations on a java.util.PriorityQueue of
+         *   event times: 70% inserts (current time + random delay), 30% extract-min
+         * - Extracted times must be non-decreasing
+         *
+         * METRIC: Heap operations per second
+         */
+        suspend fun priorityQueue(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val operations = params.stringSortCount.toLong() * BenchmarkHelpers.PRIORITY_QUEUE_MULTIPLIER
+                        Log.d(TAG, "Starting Single-Core Priority Queue ($operations operations)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val queue = BenchmarkHelpers.EventQueue()
+
+                        val perf = PerfReadings()
+                        val (extracted, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkHelpers.runEventQueueOps(queue, operations, seed = 0x9EA9L)
+                                        }
+                                }
+
+                        val opsPerSecond = if (timeMs > 0) operations.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid = queue.outOfOrder == 0L && extracted > 0 && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Priority Queue",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("operations", operations)
+                                                        put("inserts", operations - extracted)
+                                                        put("extracts", extracted)
+                                                        put("final_heap_size", queue.size)
+                                                        put("out_of_order", queue.outOfOrder)
+                                                        put("implementation", "java.util.PriorityQueue")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 58: Compression Ratio Sweep
+         *
+         * - LZ77 (see [Lz77Compressor]) over compressionDataSizeMb of data at each of the
+         *   COMPRESSION_SWEEP_LEVELS, from constant bytes to uniformly random ones;
+         *   compressionIterations / LZ77_ITERATION_DIVISOR compressions per level
+         * - Each level's last output must decompress back to its input
+         * - Reports measured entropy, compression ratio and throughput per level, showing
+         *   whether compression slows down on some data shapes
+         *
+         * METRIC: Input bytes compressed per second across all levels
+         */
+        suspend fun compressionRatioSweep(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val dataSize = params.compressionDataSizeMb * 1024 * 1024
+                        val passes =
+                                (params.compressionIterations / BenchmarkHelpers.LZ77_ITERATION_DIVISOR)
+                                        .coerceAtLeast(1)
+                        Log.d(TAG, "Starting Single-Core Compression Sweep (data: ${params.compressionDataSizeMb}MB, passes: $passes)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val levels = BenchmarkHelpers.COMPRESSION_SWEEP_LEVELS
+                        val inputs = levels.map { BenchmarkHelpers.generateEntropyData(dataSize, it) }
+                        val compressor = Lz77Compressor()
+                        val compressed = ByteArray(Lz77Compressor.maxCompressedSize(dataSize))
+                        val restored = ByteArray(dataSize)
+
+                        val compressNs = LongArray(levels.size)
+                        val compressedSizes = IntArray(levels.size)
+                        var roundTripOk = true
+                        val perf = PerfReadings()
+                        val (_, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                for ((i, data) in inputs.withIndex()) {
+                                                        val start = System.nanoTime()
+                                                        BenchmarkControl.repeatUntilCancelled(passes) { compressedSizes[i] = compressor.compress(data, compressed) }
+                                                        compressNs[i] = System.nanoTime() - start
+
+                                                        val restoredSize = Lz77Compressor.decompress(compressed, compressedSizes[i], restored)
+                                                        if (restoredSize != dataSize || !restored.contentEquals(data)) roundTripOk = false
+                                                }
+                                        }
+                                }
+
+                        val totalNs = compressNs.sum()
+                        val totalBytes = dataSize.toLong() * passes * levels.size
+                        val opsPerSecond = if (totalNs > 0) totalBytes / (totalNs / 1e9) else 0.0
+                        val isValid = roundTripOk && timeMs > 0
+
+                        val sweep =
+                                JSONArray().apply {
+                                        levels.forEachIndexed { i, level ->
+                                                val bytesPerSec =
+                                                        if (compressNs[i] > 0) dataSize.toLong() * passes / (compressNs[i] / 1e9) else 0.0
+                                                put(
+                                                        JSONObject().apply {
+                                                                put("level", level.name)
+                                                                put("entropy_bits_per_byte", BenchmarkHelpers.shannonEntropy(inputs[i]))
+                                                                put("compression_ratio", compressedSizes[i].toDouble() / dataSize)
+                                                                put("compress_bytes_per_sec", bytesPerSec)
+                                                        }
+                                                )
+                                        }
+                                }
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Compression Sweep",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("data_size_mb", params.compressionDataSizeMb)
+                                                        put("passes_per_level", passes)
+                                                        put("sweep", sweep)
+                                                        put("round_trip_ok", roundTripOk)
+                                                        put("implementation", "Hash-chain LZ77 with lazy matching")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 59: Bit-Reversal Permutation
+         *
+         * - In-place bit-reversal permutation of scanElementCount doubles (rounded up to a power
+         *   of two: 2^22 on Mid), scanIterations / BIT_REVERSAL_ITERATION_DIVISOR passes
+         * - The partner of each index jumps across the array, which defeats the hardware
+         *   prefetcher; the same number of sequential array copies is timed for comparison
+         * - Every element must end up at its expected index
+         *
+         * METRIC: Elements permuted per second
+         */
+        suspend fun bitReversal(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val size = BenchmarkHelpers.nextPowerOfTwo(params.scanElementCount)
+                        val bits = Integer.numberOfTrailingZeros(size)
+                        val passes = (params.scanIterations / BenchmarkHelpers.BIT_REVERSAL_ITERATION_DIVISOR).coerceAtLeast(1)
+                        Log.d(TAG, "Starting Single-Core Bit-Reversal Permutation (size: 2^$bits, passes: $passes)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val original = DoubleArray(size) { it.toDouble() }
+                        val data = original.copyOf()
+
+                        val perf = PerfReadings()
+                        val (_, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkControl.repeatUntilCancelled(passes) { BenchmarkHelpers.bitReversalPermute(data) }
+                                        }
+                                }
+
+                        // Same volume of sequential traffic, to separate the access pattern's cost
+                        val copy = DoubleArray(size)
+                        val copyStart = System.nanoTime()
+                        BenchmarkControl.repeatUntilCancelled(passes) { System.arraycopy(original, 0, copy, 0, size) }
+                        val copyNs = System.nanoTime() - copyStart
+
+                        // An even number of passes restores the original order
+                        var misplaced = 0
+                        for (i in 0 until size) {
+                                val expected = if (passes % 2 == 0) i else BenchmarkHelpers.reverseBits(i, bits)
+                                if (data[i] != expected.toDouble()) misplaced++
+                        }
+
+                        val elements = size.toLong() * passes
+                        val opsPerSecond = if (timeMs > 0) elements.toDouble() / (timeMs / 1000.0) else 0.0
+                        val copyElementsPerSec = if (copyNs > 0) elements / (copyNs / 1e9) else 0.0
+                        val isValid = misplaced == 0 && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Bit-Reversal Permutation",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("size", size)
+                                                        put("passes", passes)
+                                                        put("elements_permuted", elements)
+                                                        put("elements_per_sec", opsPerSecond)
+                                                        put("sequential_copy_elements_per_sec", copyElementsPerSec)
+                                                        put("cache_penalty", if (opsPerSecond > 0) copyElementsPerSec / opsPerSecond else 0.0)
+                                                        put("misplaced", misplaced)
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 60: Matrix Transpose
+         *
+         * - Transposes a matrixSize × matrixSize double matrix three ways: naive row-major,
+         *   blocked (TRANSPOSE_TILE_SIZE tiles) and cache-oblivious recursive
+         * - Each variant does matrixIterations passes of transpose(transpose(A)), which must give
+         *   back A exactly
+         *
+         * METRIC: Elements transposed per second across all three variants
+         */
+        suspend fun matrixTranspose(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val n = params.matrixSize
+                        val passes = params.matrixIterations
+                        Log.d(TAG, "Starting Single-Core Matrix Transpose (size: $n, passes: $passes)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val matrix = DoubleArray(n * n) { it.toDouble() }
+                        val transposed = DoubleArray(n * n)
+                        val restored = DoubleArray(n * n)
+                        val variants = arrayOf("naive", "blocked", "recursive")
+
+                        val variantNs = LongArray(variants.size)
+                        var roundTripOk = true
+                        val perf = PerfReadings()
+                        val (_, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                for (v in variants.indices) {
+                                                        restored.fill(0.0)
+                                                        val start = System.nanoTime()
+                                                        BenchmarkControl.repeatUntilCancelled(passes) {
+                                                                when (v) {
+                                                                        0 -> {
+                                                                                BenchmarkHelpers.transposeNaive(matrix, transposed, n)
+                                                                                BenchmarkHelpers.transposeNaive(transposed, restored, n)
+                                                                        }
+                                                                        1 -> {
+                                                                                BenchmarkHelpers.transposeBlocked(matrix, transposed, n, BenchmarkHelpers.TRANSPOSE_TILE_SIZE)
+                                                                                BenchmarkHelpers.transposeBlocked(transposed, restored, n, BenchmarkHelpers.TRANSPOSE_TILE_SIZE)
+                                                                        }
+                                                                        else -> {
+                                                                                BenchmarkHelpers.transposeRecursive(matrix, transposed, n)
+                                                                                BenchmarkHelpers.transposeRecursive(transposed, restored, n)
+                                                                        }
+                                                                }
+                                                        }
+                                                        variantNs[v] = System.nanoTime() - start
+                                                        if (!restored.contentEquals(matrix)) roundTripOk = false
+                                                }
+                                        }
+                                }
+
+                        val elementsPerVariant = n.toLong() * n * 2 * passes
+                        val totalNs = variantNs.sum()
+                        val opsPerSecond = if (totalNs > 0) elementsPerVariant * variants.size / (totalNs / 1e9) else 0.0
+                        val isValid = roundTripOk && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Matrix Transpose",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("matrix_size", n)
+                                                        put("passes", passes)
+                                                        put("tile_size", BenchmarkHelpers.TRANSPOSE_TILE_SIZE)
+                                                        variants.forEachIndexed { v, variant ->
+                                                                put(
+                                                                        "${variant}_elements_per_sec",
+                                                                        if (variantNs[v] > 0) elementsPerVariant / (variantNs[v] / 1e9) else 0.0
+                                                                )
+                                                        }
+                                                        put("round_trip_ok", roundTripOk)
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 61: Crypto RNG
+         *
+         * - Fills a hashDataSizeMb buffer from java.security.SecureRandom (the platform CSPRNG)
+         *   CRYPTO_RNG_PASSES times, then from XorShift128Plus, the fast generator the other
+         *   benchmarks use, for comparison
+         * - Each generator's output must pass a byte-frequency chi-square check
+         *
+         * METRIC: Cryptographically secure random bytes per second
+         */
+        suspend fun cryptoRng(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val bufferSize = params.hashDataSizeMb * 1024 * 1024
+                        val passes = BenchmarkHelpers.CRYPTO_RNG_PASSES
+                        Log.d(TAG, "Starting Single-Core Crypto RNG (buffer: ${params.hashDataSizeMb}MB, passes: $passes)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val buffer = ByteArray(bufferSize)
+                        val secureRandom = java.security.SecureRandom()
+                        // Seeding happens lazily on first use; keep it out of the timed loop
+                        secureRandom.nextBytes(ByteArray(16))
+
+                        val perf = PerfReadings()
+                        val (_, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkControl.repeatUntilCancelled(passes) { secureRandom.nextBytes(buffer) }
+                                        }
+                                }
+                        val secureChiSquare = BenchmarkHelpers.byteChiSquare(buffer)
+
+                        val rng = XorShift128Plus(0xC5A9L)
+                        val prngStart = System.nanoTime()
+                        BenchmarkControl.repeatUntilCancelled(passes) { BenchmarkHelpers.fillXorShiftBytes(rng, buffer) }
+                        val prngNs = System.nanoTime() - prngStart
+                        val prngChiSquare = BenchmarkHelpers.byteChiSquare(buffer)
+
+                        val bytes = bufferSize.toLong() * passes
+                        val opsPerSecond = if (timeMs > 0) bytes.toDouble() / (timeMs / 1000.0) else 0.0
+                        val prngBytesPerSec = if (prngNs > 0) bytes / (prngNs / 1e9) else 0.0
+                        val isValid =
+                                secureChiSquare < BenchmarkHelpers.BYTE_CHI_SQUARE_LIMIT &&
+                                        prngChiSquare < BenchmarkHelpers.BYTE_CHI_SQUARE_LIMIT &&
+                                        timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Crypto RNG",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("buffer_mb", params.hashDataSizeMb)
+                                                        put("passes", passes)
+                                                        put("csprng_algorithm", secureRandom.algorithm)
+                                                        put("csprng_bytes_per_sec", opsPerSecond)
+                                                        put("prng_bytes_per_sec", prngBytesPerSec)
+                                                        put("prng_speedup", if (opsPerSecond > 0) prngBytesPerSec / opsPerSecond else 0.0)
+                                                        put("csprng_chi_square", secureChiSquare)
+                                                        put("prng_chi_square", prngChiSquare)
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 62: Linked List Traversal
+         *
+         * - Builds a singly linked list of stringSortCount nodes holding a Long each, linked in a
+         *   shuffled order so every step is a dependent load from an unpredictable address
+         * - Walks the whole list LINKED_LIST_TRAVERSALS times summing values; each sum must match
+         * - The same walk over a list linked in allocation order is reported for comparison
+         *
+         * METRIC: Nodes visited per second on the shuffled list
+         */
+        suspend fun linkedListTraversal(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val count = params.stringSortCount
+                        val traversals = BenchmarkHelpers.LINKED_LIST_TRAVERSALS
+                        Log.d(TAG, "Starting Single-Core Linked List Traversal (nodes: $count, traversals: $traversals)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val shuffledHead = BenchmarkHelpers.buildLinkedList(count, shuffled = true)
+                        val expectedSum = BenchmarkHelpers.expectedLinkedListSum(count)
+
+                        val perf = PerfReadings()
+                        val (badSums, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                var bad = 0
+                                                BenchmarkControl.repeatUntilCancelled(traversals) {
+                                                        if (BenchmarkHelpers.sumLinkedList(shuffledHead) != expectedSum) bad++
+                                                }
+                                                bad
+                                        }
+                                }
+
+                        // Same walk with prefetch-friendly links, to separate the cache-miss cost
+                        val sequentialHead = BenchmarkHelpers.buildLinkedList(count, shuffled = false)
+                        var sequentialBadSums = 0
+                        val sequentialStart = System.nanoTime()
+                        BenchmarkControl.repeatUntilCancelled(traversals) {
+                                if (BenchmarkHelpers.sumLinkedList(sequentialHead) != expectedSum) sequentialBadSums++
+                        }
+                        val sequentialNs = System.nanoTime() - sequentialStart
+
+                        val nodes = count.toLong() * traversals
+                        val opsPerSecond = if (timeMs > 0) nodes.toDouble() / (timeMs / 1000.0) else 0.0
+                        val sequentialNodesPerSec = if (sequentialNs > 0) nodes / (sequentialNs / 1e9) else 0.0
+                        val isValid = badSums == 0 && sequentialBadSums == 0 && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Linked List Traversal",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("nodes", count)
+                                                        put("traversals", traversals)
+                                                        put("nodes_visited", nodes)
+                                                        put("nodes_per_sec", opsPerSecond)
+                                                        put("ns_per_node", if (opsPerSecond > 0) 1e9 / opsPerSecond else 0.0)
+                                                        put("sequential_nodes_per_sec", sequentialNodesPerSec)
+                                                        put("cache_penalty", if (opsPerSecond > 0) sequentialNodesPerSec / opsPerSecond else 0.0)
+                                                        put("bad_sums", badSums + sequentialBadSums)
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 63: B-Tree Operations
+         *
+         * - stringSortCount / 3 inserts into an ordered map, then as many point lookups and as
+         *   many range scans of BTREE_RANGE_WIDTH entries (see BenchmarkHelpers.runBTreeOps)
+         * - Every lookup must return the inserted value and every scan must come out in key order
+         *
+         * METRIC: Map operations (inserts + lookups + scans) per second
+         */
+        suspend fun bTreeOps(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val opsPerKind = (params.stringSortCount / 3).coerceAtLeast(1)
+                        Log.d(TAG, "Starting Single-Core B-Tree Operations (ops per kind: $opsPerKind)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val perf = PerfReadings()
+                        val (result, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) { BenchmarkHelpers.runBTreeOps(opsPerKind, seed = 0xB7EEL) }
+                                }
+
+                        val operations = opsPerKind * 3L
+                        val opsPerSecond = if (timeMs > 0) operations.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid = result.errors == 0 && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core B-Tree Operations",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("ops_per_kind", opsPerKind)
+                                                        put("range_width", BenchmarkHelpers.BTREE_RANGE_WIDTH)
+                                                        put("insert_ops_per_sec", if (result.insertNs > 0) opsPerKind / (result.insertNs / 1e9) else 0.0)
+                                                        put("lookup_ops_per_sec", if (result.lookupNs > 0) opsPerKind / (result.lookupNs / 1e9) else 0.0)
+                                                        put("range_scan_ops_per_sec", if (result.scanNs > 0) opsPerKind / (result.scanNs / 1e9) else 0.0)
+                                                        put("scanned_entries", result.scannedEntries)
+                                                        put("errors", result.errors)
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 64: String Builder
+         *
+         * - Builds stringSortCount strings of STRING_BUILDER_LENGTH characters from 10-character
+         *   fragments three ways: StringBuilder sized up front, StringBuilder grown from its
+         *   default capacity, and repeated String concatenation (a new String per fragment)
+         * - All three must produce the same strings (compared by hash-code checksum)
+         *
+         * METRIC: Characters built per second across the three strategies
+         */
+        suspend fun stringBuilder(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val count = params.stringSortCount
+                        Log.d(TAG, "Starting Single-Core String Builder (strings: $count, length: ${BenchmarkHelpers.STRING_BUILDER_LENGTH})")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val timesNs = LongArray(3)
+                        val perf = PerfReadings()
+                        val (checksums, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                var start = System.nanoTime()
+                                                val presized = BenchmarkHelpers.buildStringsAppending(count, presized = true)
+                                                timesNs[0] = System.nanoTime() - start
+                                                start = System.nanoTime()
+                                                val growing = BenchmarkHelpers.buildStringsAppending(count, presized = false)
+                                                timesNs[1] = System.nanoTime() - start
+                                                start = System.nanoTime()
+                                                val concatenated = BenchmarkHelpers.buildStringsConcatenating(count)
+                                                timesNs[2] = System.nanoTime() - start
+                                                longArrayOf(presized, growing, concatenated)
+                                        }
+                                }
+
+                        val charsPerStrategy = count.toLong() * BenchmarkHelpers.STRING_BUILDER_LENGTH
+                        val charsPerSec = DoubleArray(3) { if (timesNs[it] > 0) charsPerStrategy / (timesNs[it] / 1e9) else 0.0 }
+                        val opsPerSecond = if (timeMs > 0) charsPerStrategy * 3 / (timeMs / 1000.0) else 0.0
+                        val isValid = checksums.all { it == checksums[0] } && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core String Builder",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("strings", count)
+                                                        put("string_length", BenchmarkHelpers.STRING_BUILDER_LENGTH)
+                                                        put("presized_chars_per_sec", charsPerSec[0])
+                                                        put("growing_chars_per_sec", charsPerSec[1])
+                                                        put("concat_chars_per_sec", charsPerSec[2])
+                                                        put("growth_penalty", if (charsPerSec[1] > 0) charsPerSec[0] / charsPerSec[1] else 0.0)
+                                                        put("checksums_match", isValid)
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 65: Vector Operations
+         *
+         * - Element-wise multiply-add c = a × b + c over scanElementCount floats, scanIterations
+         *   passes, first as a plain scalar loop and then as VECTOR_LANES-wide lane blocks
+         *   (the f32x8 shape; see BenchmarkHelpers.multiplyAddLanes)
+         * - Operands are small integers, so both results must match the closed form exactly
+         *
+         * METRIC: Elements per second of the lane-blocked loop
+         */
+        suspend fun vectorOps(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val size = params.scanElementCount
+                        val passes = params.scanIterations
+                        Log.d(TAG, "Starting Single-Core Vector Operations (elements: $size, passes: $passes)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val (a, b, c) = BenchmarkHelpers.generateVectorOperands(size)
+
+                        val scalarStart = System.nanoTime()
+                        BenchmarkControl.repeatUntilCancelled(passes) { BenchmarkHelpers.multiplyAddScalar(a, b, c) }
+                        val scalarNs = System.nanoTime() - scalarStart
+                        val scalarMismatches = BenchmarkHelpers.countVectorMismatches(a, b, c, passes)
+
+                        for (i in c.indices) c[i] = (i and 255).toFloat()
+                        val perf = PerfReadings()
+                        val (_, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkControl.repeatUntilCancelled(passes) { BenchmarkHelpers.multiplyAddLanes(a, b, c) }
+                                        }
+                                }
+                        val laneMismatches = BenchmarkHelpers.countVectorMismatches(a, b, c, passes)
+
+                        val elements = size.toLong() * passes
+                        val opsPerSecond = if (timeMs > 0) elements.toDouble() / (timeMs / 1000.0) else 0.0
+                        val scalarElementsPerSec = if (scalarNs > 0) elements / (scalarNs / 1e9) else 0.0
+                        val isValid = scalarMismatches == 0 && laneMismatches == 0 && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Vector Operations",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("elements", size)
+                                                        put("passes", passes)
+                                                        put("simd_width_bits", BenchmarkHelpers.VECTOR_LANES * Float.SIZE_BITS)
+                                                        put("simd_elements_per_sec", opsPerSecond)
+                                                        put("scalar_elements_per_sec", scalarElementsPerSec)
+                                                        put("simd_speedup", if (scalarElementsPerSec > 0) opsPerSecond / scalarElementsPerSec else 0.0)
+                                                        put("mismatches", scalarMismatches + laneMismatches)
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 66: Counting Sort
+         *
+         * - Counting sort of stringSortCount random keys in [0, COUNTING_SORT_MAX_VALUE], a fresh
+         *   copy of the same input stringSortIterations times
+         * - The same copies are then sorted with Arrays.sort (dual-pivot quicksort) for comparison
+         * - Both must produce the same sorted output
+         *
+         * METRIC: Elements sorted per second by counting sort
+         */
+        suspend fun countingSort(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        val iterations = params.stringSortIterations
+                        Log.d(TAG, "Starting Single-Core Counting Sort (elements: ${params.stringSortCount}, iterations: $iterations)")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val rng = XorShift128Plus(0xC0417L)
+                        val input = IntArray(params.stringSortCount) { rng.nextInt(BenchmarkHelpers.COUNTING_SORT_MAX_VALUE + 1) }
+                        val work = IntArray(input.size)
+                        val counts = IntArray(BenchmarkHelpers.COUNTING_SORT_MAX_VALUE + 1)
+
+                        val perf = PerfReadings()
+                        val (_, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                BenchmarkControl.repeatUntilCancelled(iterations) {
+                                                        System.arraycopy(input, 0, work, 0, input.size)
+                                                        BenchmarkHelpers.countingSort(work, counts)
+                                                }
+                                        }
+                                }
+
+                        val reference = IntArray(input.size)
+                        val referenceStart = System.nanoTime()
+                        BenchmarkControl.repeatUntilCancelled(iterations) {
+                                System.arraycopy(input, 0, reference, 0, input.size)
+                                java.util.Arrays.sort(reference)
+                        }
+                        val referenceNs = System.nanoTime() - referenceStart
+
+                        val totalElements = input.size.toLong() * iterations
+                        val opsPerSecond = if (timeMs > 0) totalElements.toDouble() / (timeMs / 1000.0) else 0.0
+                        val referenceElementsPerSec = if (referenceNs > 0) totalElements / (referenceNs / 1e9) else 0.0
+                        val isValid = BenchmarkHelpers.isSorted(work) && work.contentEquals(reference) && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core Counting Sort",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("elements", input.size)
+                                                        put("iterations", iterations)
+                                                        put("value_range", BenchmarkHelpers.COUNTING_SORT_MAX_VALUE + 1)
+                                                        put("total_elements", totalElements)
+                                                        put("elements_per_sec", opsPerSecond)
+                                                        put("comparison_sort_elements_per_sec", referenceElementsPerSec)
+                                                        put(
+                                                                "speedup_vs_comparison_sort",
+                                                                if (referenceElementsPerSec > 0) opsPerSecond / referenceElementsPerSec else 0.0
+                                                        )
+                                                        put("time_complexity", "O(n + k)")
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
+                }
+
+        /**
+         * Test 67: String Interning
+         *
+         * - Interns stringSortCount / STRING_INTERN_UNIQUE_DIVISOR distinct symbols into a
+         *   HashMap<String, Int> symbol table (ID = insertion order)
+         * - Looks up stringSortCount strings, STRING_INTERN_HIT_PERCENT% of them interned,
+         *   STRING_INTERN_PASSES times; every hit's ID must resolve back to the same string
+         *
+         * METRIC: Lookups per second
+         */
+        suspend fun stringInterning(params: WorkloadParams, isTestRun: Boolean = false): BenchmarkResult =
+                withContext(Dispatchers.Default) {
+                        Log.d(TAG, "Starting Single-Core String Interning (lookups: ${params.stringSortCount})")
+                        CpuAffinityManager.setMaxPerformance()
+
+                        val workload = BenchmarkHelpers.generateInternWorkload(params.stringSortCount)
+                        val symbols = workload.symbols
+                        val lookups = workload.lookups
+                        val passes = BenchmarkHelpers.STRING_INTERN_PASSES
+
+                        val internStart = System.nanoTime()
+                        val pool = HashMap<String, Int>(symbols.size * 4 / 3 + 1)
+                        symbols.forEachIndexed { id, symbol -> pool.putIfAbsent(symbol, id) }
+                        val internNs = System.nanoTime() - internStart
+
+                        val perf = PerfReadings()
+                        val (hits, timeMs) =
+                                CpuAffinityManager.runWithAffinity(CpuAffinityManager.lastCoreIds()) {
+                                        BenchmarkHelpers.measureBenchmark(perf) {
+                                                var total = 0L
+                                                BenchmarkControl.repeatUntilCancelled(passes) {
+                                                        total += BenchmarkHelpers.lookupInterned(pool, symbols, lookups, 0, lookups.size)
+                                                }
+                                                total
+                                        }
+                                }
+
+                        val totalLookups = lookups.size.toLong() * passes
+                        val opsPerSecond = if (timeMs > 0) totalLookups.toDouble() / (timeMs / 1000.0) else 0.0
+                        val isValid = pool.size == symbols.size && hits == workload.expectedHits.toLong() * passes && timeMs > 0
+
+                        CpuAffinityManager.resetPerformance()
+
+                        // Thermal stabilization delay (skip for test runs)
+                        if (!isTestRun) {
+                            kotlinx.coroutines.delay(1500)
+                        }
+                        return@withContext BenchmarkResult(
+                                name = "Single-Core String Interning",
+                                executionTimeMs = timeMs.toDouble(),
+                                opsPerSecond = opsPerSecond,
+                                isValid = isValid,
+                                metricsJson =
+                                        JSONObject()
+                                                .apply {
+                                                        put("unique_strings_interned", pool.size)
+                                                        put("lookups", lookups.size)
+                                                        put("passes", passes)
+                                                        put("lookups_per_sec", opsPerSecond)
+                                                        put("hit_rate", if (totalLookups > 0) hits.toDouble() / totalLookups else 0.0)
+                                                        put("intern_ns_per_symbol", if (symbols.isNotEmpty()) internNs.toDouble() / symbols.size else 0.0)
+                                                }
+                                                .toString()
+                        ).withCounters(perf)
                 }
 }
@@ -0,0 +1,129 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark.output
+
+import com.ivarna.finalbenchmark2.cpuBenchmark.BenchmarkName
+import com.ivarna.finalbenchmark2.cpuBenchmark.BenchmarkResult
+import com.ivarna.finalbenchmark2.cpuBenchmark.KotlinBenchmarkManager
+import com.ivarna.finalbenchmark2.cpuBenchmark.SuiteResult
+import com.ivarna.finalbenchmark2.cpuBenchmark.defaultScoringWeights
+import java.util.Locale
+
+/**
+ * Standalone HTML document for sharing a CPU suite run offline
+ *
+ * Contains the score summary table, an inline SVG bar chart of per-benchmark points and the
+ * device metadata. Bars are scaled so the best result across single- and multi-core fills the
+ * full width, and colored by category.
+ */
+class HtmlReport(private val suiteResult: SuiteResult) {
+
+    fun render(): String {
+        val summary = suiteResult.summary
+        return buildString {
+            append("<!DOCTYPE html>\n")
+            append("<html lang=\"en\">\n<head>\n")
+            append("<meta charset=\"utf-8\">\n")
+            append("<title>FinalBenchmark 2 CPU Report</title>\n")
+            append("<style>\n")
+            append("body { font-family: sans-serif; margin: 2em; color: #222; }\n")
+            append("table { border-collapse: collapse; margin-bottom: 2em; }\n")
+            append("th, td { border: 1px solid #ccc; padding: 4px 12px; text-align: left; }\n")
+            append(".invalid { color: #c62828; }\n")
+            append("</style>\n")
+            append("</head>\n<body>\n")
+            append("<h1>FinalBenchmark 2 CPU Report</h1>\n")
+
+            append("<h2>Summary</h2>\n<table>\n")
+            appendRow("Final Score", formatNumber(summary.finalScore, 0))
+            appendRow("Single-Core Score", formatNumber(summary.singleCoreScore, 0))
+            appendRow("Multi-Core Score", formatNumber(summary.multiCoreScore, 0))
+            appendRow("Rating", summary.rating)
+            append("</table>\n")
+
+            val fingerprint = suiteResult.deviceFingerprint
+            if (suiteResult.deviceInfo.isNotEmpty() || fingerprint != null) {
+                append("<h2>Device</h2>\n<table>\n")
+                suiteResult.deviceInfo.forEach { (label, value) -> appendRow(label, value) }
+                if (fingerprint != null) {
+                    appendRow("Cores", "${fingerprint.coreCount} (${fingerprint.bigCoreCount} big)")
+                    appendRow("CPU Frequency", "${fingerprint.minFreqMhz}-${fingerprint.maxFreqMhz} MHz")
+                    appendRow("Architecture", fingerprint.arch)
+                    appendRow("Kernel", fingerprint.kernelVersion)
+                }
+                append("</table>\n")
+            }
+
+            append("<h2>Benchmarks</h2>\n")
+            append(renderBarChart())
+            append("\n<table>\n")
+            append("<tr><th>Benchmark</th><th>Points</th><th>Mops/s</th><th>Time (ms)</th></tr>\n")
+            suiteResult.results.forEach { result ->
+                val rowClass = if (result.isValid) "" else " class=\"invalid\""
+                append("<tr$rowClass><td>${escape(result.name)}</td>")
+                append("<td>${formatNumber(points(result), 1)}</td>")
+                append("<td>${formatNumber(result.opsPerSecond / 1_000_000.0, 2)}</td>")
+                append("<td>${formatNumber(result.executionTimeMs, 0)}</td></tr>\n")
+            }
+            append("</table>\n")
+            append("<p>Generated by FinalBenchmark 2</p>\n")
+            append("</body>\n</html>\n")
+        }
+    }
+
+    /** Horizontal bar per benchmark; widths are relative to the best points value in the run */
+    private fun renderBarChart(): String {
+        val results = suiteResult.results
+        val best = results.maxOfOrNull { points(it) } ?: 0.0
+        val height = results.size * (BAR_HEIGHT + BAR_GAP)
+
+        return buildString {
+            append("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"${LABEL_WIDTH + CHART_WIDTH + 60}\" height=\"$height\">\n")
+            results.forEachIndexed { i, result ->
+                val y = i * (BAR_HEIGHT + BAR_GAP)
+                val fraction = if (best > 0.0) points(result) / best else 0.0
+                val width = formatNumber(fraction * CHART_WIDTH, 1)
+                val color = if (result.name.startsWith("Single-Core")) SINGLE_CORE_COLOR else MULTI_CORE_COLOR
+                append("<text x=\"0\" y=\"${y + BAR_HEIGHT - 5}\" font-size=\"12\">${escape(result.name)}</text>\n")
+                append("<rect x=\"$LABEL_WIDTH\" y=\"$y\" width=\"$width\" height=\"$BAR_HEIGHT\" fill=\"$color\"/>\n")
+                append(
+                    "<text x=\"${LABEL_WIDTH + CHART_WIDTH + 5}\" y=\"${y + BAR_HEIGHT - 5}\" font-size=\"12\">" +
+                        "${formatNumber(fraction * 100.0, 0)}%</text>\n"
+                )
+            }
+            append("</svg>")
+        }
+    }
+
+    private fun StringBuilder.appendRow(label: String, value: String) {
+        append("<tr><th>${escape(label)}</th><td>${escape(value)}</td></tr>\n")
+    }
+
+    companion object {
+        private const val LABEL_WIDTH = 260
+        private const val CHART_WIDTH = 400
+        private const val BAR_HEIGHT = 18
+        private const val BAR_GAP = 6
+        const val SINGLE_CORE_COLOR = "#1e88e5"
+        const val MULTI_CORE_COLOR = "#fb8c00"
+
+        /** Result names of the scored suite, e.g. "Single-Core Monte Carlo π" */
+        private val scoredNames: Set<String> by lazy { defaultScoringWeights().keys }
+
+        /** Points as shown on the result screen (0 for benchmarks outside the scored suite) */
+        fun points(result: BenchmarkResult): Double {
+            // Match the exact name first: fromString maps any "Monte Carlo ..." to MONTE_CARLO
+            if (result.name !in scoredNames) return 0.0
+            val benchmarkName = BenchmarkName.fromString(result.name) ?: return 0.0
+            val factor = KotlinBenchmarkManager.SCORING_FACTORS[benchmarkName] ?: return 0.0
+            return factor * result.opsPerSecond
+        }
+
+        private fun formatNumber(value: Double, decimals: Int): String =
+            String.format(Locale.US, "%.${decimals}f", value)
+
+        fun escape(text: String): String =
+            text.replace("&", "&amp;")
+                .replace("<", "&lt;")
+                .replace(">", "&gt;")
+                .replace("\"", "&quot;")
+    }
+}
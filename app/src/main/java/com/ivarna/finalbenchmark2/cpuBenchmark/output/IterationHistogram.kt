@@ -0,0 +1,33 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark.output
+
+import java.util.Locale
+
+/**
+ * Text histogram of per-iteration times (raw_iteration_times_ms), one line per bucket, e.g.
+ * `  12.10 -   13.40 ms | ########           57`
+ *
+ * Buckets split [min, max] evenly, so a bimodal run shows up as two separate clusters.
+ */
+fun renderIterationHistogram(timesMs: List<Double>, buckets: Int = 10, barWidth: Int = 40): String {
+    require(buckets > 0) { "Bucket count must be positive, got $buckets" }
+    if (timesMs.isEmpty()) return "(no iterations)\n"
+
+    val min = timesMs.minOrNull()!!
+    val max = timesMs.maxOrNull()!!
+    val width = (max - min) / buckets
+    val counts = IntArray(buckets)
+    timesMs.forEach { time ->
+        val bucket = if (width > 0.0) ((time - min) / width).toInt().coerceAtMost(buckets - 1) else 0
+        counts[bucket]++
+    }
+    val largest = counts.maxOrNull()!!
+
+    return buildString {
+        counts.forEachIndexed { i, count ->
+            val low = min + i * width
+            val high = if (i == buckets - 1) max else low + width
+            val bar = "#".repeat(count * barWidth / largest)
+            append(String.format(Locale.US, "%8.2f - %8.2f ms | %-${barWidth}s %d\n", low, high, bar, count))
+        }
+    }
+}
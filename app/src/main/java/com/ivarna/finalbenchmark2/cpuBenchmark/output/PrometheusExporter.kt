@@ -0,0 +1,79 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark.output
+
+import com.ivarna.finalbenchmark2.cpuBenchmark.SuiteResult
+
+/**
+ * Render a suite run in the Prometheus text exposition format, e.g.
+ * `cpu_benchmark_ops_per_second{name="Single-Core Prime Generation"} 1.23E9`
+ *
+ * @param prefix Metric name prefix; characters Prometheus doesn't allow are replaced with '_'
+ */
+fun exportPrometheusMetrics(results: SuiteResult, prefix: String = "cpu_benchmark"): String {
+    val metricPrefix = sanitizeMetricName(prefix)
+    val summary = results.summary
+
+    return buildString {
+        fun gauge(name: String, help: String, samples: List<Pair<String, Double>>) {
+            append("# HELP ${metricPrefix}_$name $help\n")
+            append("# TYPE ${metricPrefix}_$name gauge\n")
+            samples.forEach { (labels, value) ->
+                append("${metricPrefix}_$name$labels ${formatValue(value)}\n")
+            }
+        }
+
+        gauge(
+            "score",
+            "Suite score by type.",
+            listOf(
+                "{type=\"single_core\"}" to summary.singleCoreScore,
+                "{type=\"multi_core\"}" to summary.multiCoreScore,
+                "{type=\"final\"}" to summary.finalScore
+            )
+        )
+        gauge(
+            "ops_per_second",
+            "Benchmark throughput in operations per second.",
+            results.results.map { "{name=\"${escapeLabelValue(it.name)}\"}" to it.opsPerSecond }
+        )
+        gauge(
+            "execution_time_ms",
+            "Benchmark execution time in milliseconds.",
+            results.results.map { "{name=\"${escapeLabelValue(it.name)}\"}" to it.executionTimeMs }
+        )
+        gauge(
+            "valid",
+            "1 if the benchmark result passed validation, 0 otherwise.",
+            results.results.map { "{name=\"${escapeLabelValue(it.name)}\"}" to if (it.isValid) 1.0 else 0.0 }
+        )
+        results.deviceFingerprint?.let { fingerprint ->
+            val labels =
+                listOf(
+                    "model" to fingerprint.model,
+                    "arch" to fingerprint.arch,
+                    "os_version" to fingerprint.osVersion,
+                    "kernel_version" to fingerprint.kernelVersion,
+                    "core_count" to fingerprint.coreCount.toString(),
+                    "big_core_count" to fingerprint.bigCoreCount.toString(),
+                    "max_freq_mhz" to fingerprint.maxFreqMhz.toString(),
+                    "min_freq_mhz" to fingerprint.minFreqMhz.toString()
+                ).joinToString(",", "{", "}") { (key, value) -> "$key=\"${escapeLabelValue(value)}\"" }
+            gauge("device_info", "Device the suite ran on; always 1.", listOf(labels to 1.0))
+        }
+    }
+}
+
+private fun sanitizeMetricName(name: String): String {
+    val sanitized = name.replace(Regex("[^a-zA-Z0-9_:]"), "_")
+    return if (sanitized.isEmpty() || sanitized[0].isDigit()) "_$sanitized" else sanitized
+}
+
+private fun escapeLabelValue(value: String): String =
+    value.replace("\\", "\\\\").replace("\"", "\\\"").replace("\n", "\\n")
+
+private fun formatValue(value: Double): String =
+    when {
+        value.isNaN() -> "NaN"
+        value == Double.POSITIVE_INFINITY -> "+Inf"
+        value == Double.NEGATIVE_INFINITY -> "-Inf"
+        else -> value.toString()
+    }
@@ -11,6 +11,7 @@ import androidx.lifecycle.viewmodel.initializer
 import androidx.lifecycle.viewmodel.viewModelFactory
 import com.google.gson.Gson
 import com.ivarna.finalbenchmark2.BenchmarkForegroundService
+import com.ivarna.finalbenchmark2.cpuBenchmark.BenchmarkControl
 import com.ivarna.finalbenchmark2.cpuBenchmark.BenchmarkResult
 import com.ivarna.finalbenchmark2.cpuBenchmark.CpuAffinityManager
 import com.ivarna.finalbenchmark2.ui.models.SystemStats
@@ -888,6 +889,9 @@ class BenchmarkViewModel(
         }
 
         fun stopBenchmark() {
+                // Kernels don't suspend, so also tell the suite to stop at the next benchmark
+                BenchmarkControl.requestCancellation()
+
                 // Cancel the actual execution job
                 benchmarkJob?.cancel()
                 
@@ -1,5 +1,6 @@
 package com.ivarna.finalbenchmark2.utils
 
+import android.util.Log
 import org.json.JSONObject
 
 data class CpuCache(val level: String, val type: String, val size: String)
@@ -7,10 +8,16 @@ data class CpuDetails(val socName: String, val abi: String, val hasNeon: Boolean
 
 class CpuNativeBridge {
     companion object {
+        private const val TAG = "CpuNativeBridge"
+        private var isLibraryLoaded = false
+
         init {
             try {
                 System.loadLibrary("vulkan_native") // Must match CMake name
-            } catch (e: Exception) { e.printStackTrace() }
+                isLibraryLoaded = true
+            } catch (e: UnsatisfiedLinkError) {
+                Log.e(TAG, "Failed to load vulkan_native native library", e)
+            }
         }
 
         @JvmStatic
@@ -18,6 +25,7 @@ class CpuNativeBridge {
     }
 
     fun getCpuDetails(): CpuDetails {
+        if (!isLibraryLoaded) return CpuDetails("Unknown", "Unknown", false, emptyList())
         return try {
             val json = JSONObject(getCpuDetailsNative())
             val socName = json.optString("socName", "Unknown")
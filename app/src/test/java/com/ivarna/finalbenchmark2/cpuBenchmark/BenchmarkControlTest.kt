@@ -0,0 +1,105 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import com.ivarna.finalbenchmark2.cpuBenchmark.algorithms.BenchmarkHelpers
+import kotlinx.coroutines.runBlocking
+import org.junit.After
+import org.junit.Assert.*
+import org.junit.Test
+
+class BenchmarkControlTest {
+
+    @After
+    fun tearDown() {
+        BenchmarkControl.reset()
+    }
+
+    @Test
+    fun testCancellationFlag() {
+        BenchmarkControl.reset()
+        assertFalse(BenchmarkControl.isCancelled())
+
+        BenchmarkControl.requestCancellation()
+        assertTrue(BenchmarkControl.isCancelled())
+
+        BenchmarkControl.reset()
+        assertFalse(BenchmarkControl.isCancelled())
+    }
+
+    @Test
+    fun testCancellationVisibleAcrossThreads() {
+        BenchmarkControl.reset()
+        val thread = Thread { BenchmarkControl.requestCancellation() }
+        thread.start()
+        thread.join()
+        assertTrue(BenchmarkControl.isCancelled())
+    }
+
+    @Test
+    fun testCancelAfterFirstBenchmarkGivesOneResult() = runBlocking {
+        BenchmarkControl.reset()
+        val scheduler =
+            BenchmarkScheduler(100.0).apply {
+                add("first", { _, _, _ ->
+                    BenchmarkControl.requestCancellation()
+                    BenchmarkResultBuilder().name("first").build()
+                }, 1.0)
+                add("second", { _, _, _ -> BenchmarkResultBuilder().name("second").build() }, 2.0)
+            }
+
+        val result = scheduler.runAll(WorkloadParams())
+
+        val ran = result.benchmarks.filter { !it.skipped }
+        assertEquals(listOf("first"), ran.map { it.name })
+        // Cancelled while it ran, so its measurement isn't trusted
+        assertFalse(ran.single().result!!.isValid)
+        assertEquals(1, result.skippedCount)
+    }
+
+    @Test
+    fun testRepeatUntilCancelledStopsAtIterationBoundary() {
+        BenchmarkControl.reset()
+        var runs = 0
+        val completed =
+            BenchmarkControl.repeatUntilCancelled(10) { i ->
+                runs++
+                if (i == 2) BenchmarkControl.requestCancellation()
+            }
+        assertEquals(3, completed)
+        assertEquals(3, runs)
+    }
+
+    @Test
+    fun testRepeatUntilCancelledChecksEveryStride() {
+        BenchmarkControl.reset()
+        BenchmarkControl.requestCancellation()
+        assertEquals(0, BenchmarkControl.repeatUntilCancelled(10, checkEvery = 4) {})
+
+        BenchmarkControl.reset()
+        val completed =
+            BenchmarkControl.repeatUntilCancelled(10, checkEvery = 4) { i ->
+                if (i == 1) BenchmarkControl.requestCancellation()
+            }
+        // Next check is at iteration 4
+        assertEquals(4, completed)
+    }
+
+    @Test
+    fun testCompressionStopsAndCountsOnlyCompletedPasses() {
+        BenchmarkControl.reset()
+        var runs = 0
+        val (timesMs, resultSum) =
+            BenchmarkHelpers.runBenchmarkTimedIterations(10) { i ->
+                runs++
+                if (i == 1) BenchmarkControl.requestCancellation()
+                1
+            }
+        assertEquals(2, runs)
+        assertEquals(2, timesMs.size)
+        assertEquals(2L, resultSum)
+
+        // Cancelled before the first pass: nothing processed
+        val (bytes, passTimesMs) = BenchmarkHelpers.performCompressionTimed(1024, 10)
+        assertEquals(0L, bytes)
+        assertEquals(0, passTimesMs.size)
+    }
+}
@@ -0,0 +1,28 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import org.junit.Assert.*
+import org.junit.Test
+
+class BenchmarkEnvironmentTest {
+
+    @Test
+    fun testParsesTracerPid() {
+        val status =
+                """
+                Name:   finalbenchmark2
+                State:  S (sleeping)
+                Tgid:   4242
+                PPid:   612
+                TracerPid:      4300
+                Uid:    10123   10123   10123   10123
+                """.trimIndent()
+
+        assertEquals(4300, parseTracerPid(status))
+        assertEquals(0, parseTracerPid(status.replace("4300", "0")))
+    }
+
+    @Test
+    fun testMissingTracerPidIsNull() {
+        assertNull(parseTracerPid("Name:   finalbenchmark2\nState:  R (running)"))
+    }
+}
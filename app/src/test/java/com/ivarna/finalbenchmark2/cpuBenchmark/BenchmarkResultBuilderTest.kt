@@ -0,0 +1,33 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import org.junit.Assert.*
+import org.junit.Test
+
+class BenchmarkResultBuilderTest {
+
+    @Test
+    fun testBuildUsesDefaults() {
+        val result = BenchmarkResultBuilder().name("Single-Core N-Queens").build()
+        assertEquals("Single-Core N-Queens", result.name)
+        assertEquals(0.0, result.executionTimeMs, 0.0)
+        assertEquals(0.0, result.opsPerSecond, 0.0)
+        assertTrue(result.isValid)
+        assertEquals("{}", result.metricsJson)
+        assertNull(result.accelerationMode)
+    }
+
+    @Test
+    fun testBuildWithMetrics() {
+        val result =
+                BenchmarkResultBuilder()
+                        .name("Multi-Core FFT")
+                        .ops(1.5e9)
+                        .timeMs(1200.0)
+                        .valid(false)
+                        .buildWithMetrics("{\"fft_size\": 65536}")
+        assertEquals(1.5e9, result.opsPerSecond, 0.0)
+        assertEquals(1200.0, result.executionTimeMs, 0.0)
+        assertFalse(result.isValid)
+        assertEquals("{\"fft_size\": 65536}", result.metricsJson)
+    }
+}
@@ -0,0 +1,68 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import kotlinx.coroutines.runBlocking
+import org.junit.After
+import org.junit.Assert.*
+import org.junit.Test
+
+class BenchmarkSchedulerTest {
+
+    @After
+    fun tearDown() {
+        BenchmarkControl.reset()
+    }
+
+    private fun scheduler(budgetSecs: Double, ran: MutableList<String>, vararg estimates: Pair<String, Double>) =
+        BenchmarkScheduler(budgetSecs).apply {
+            estimates.forEach { (name, estimate) ->
+                add(name, { _, _, _ ->
+                    ran.add(name)
+                    BenchmarkResultBuilder().name(name).build()
+                }, estimate)
+            }
+        }
+
+    @Test
+    fun testRunsCheapestFirst() = runBlocking {
+        val ran = mutableListOf<String>()
+        val result = scheduler(100.0, ran, "c" to 3.0, "a" to 1.0, "b" to 2.0).runAll(WorkloadParams())
+
+        assertEquals(listOf("a", "b", "c"), ran)
+        assertEquals(listOf("a", "b", "c"), result.benchmarks.map { it.name })
+        assertEquals(0, result.skippedCount)
+        assertTrue(result.benchmarks.all { it.result != null })
+    }
+
+    @Test
+    fun testSkipsBenchmarksOverBudget() = runBlocking {
+        val ran = mutableListOf<String>()
+        val result = scheduler(5.0, ran, "slow" to 10.0, "fast" to 1.0).runAll(WorkloadParams())
+
+        assertEquals(listOf("fast"), ran)
+        val slow = result.benchmarks.single { it.name == "slow" }
+        assertTrue(slow.skipped)
+        assertNull(slow.result)
+        assertEquals(1, result.skippedCount)
+    }
+
+    @Test
+    fun testInvalidParamsRunNothing() = runBlocking {
+        val ran = mutableListOf<String>()
+        val params = WorkloadParams(fibonacciNRange = Pair(10, 100))
+
+        val error = runCatching { scheduler(100.0, ran, "a" to 1.0).runAll(params) }.exceptionOrNull()
+
+        assertTrue(error is BenchmarkError.InvalidParams)
+        assertTrue(ran.isEmpty())
+    }
+
+    @Test
+    fun testCancellationSkipsRemaining() = runBlocking {
+        val ran = mutableListOf<String>()
+        BenchmarkControl.requestCancellation()
+        val result = scheduler(100.0, ran, "a" to 1.0, "b" to 2.0).runAll(WorkloadParams())
+
+        assertTrue(ran.isEmpty())
+        assertEquals(2, result.skippedCount)
+    }
+}
@@ -0,0 +1,87 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import com.ivarna.finalbenchmark2.cpuBenchmark.CpuTopologyDetector.ClusterType
+import org.junit.Assert.*
+import org.junit.Test
+
+class CpuTopologyDetectorTest {
+
+    private fun types(freqs: Map<Int, Long>): List<ClusterType> =
+        CpuTopologyDetector.classifyClusters(freqs).clusters.map { it.clusterType }
+
+    @Test
+    fun testTriClusterWithPrimeCore() {
+        // Snapdragon 888: 4x 1.8 GHz, 3x 2.42 GHz, 1x 2.84 GHz
+        val freqs = (0..3).associateWith { 1_804_800L } +
+            (4..6).associateWith { 2_419_200L } +
+            mapOf(7 to 2_841_600L)
+        assertEquals(listOf(ClusterType.PRIME, ClusterType.BIG, ClusterType.LITTLE), types(freqs))
+
+        val prime = CpuTopologyDetector.classifyClusters(freqs).clusters.first()
+        assertEquals(listOf(7), prime.coreIds)
+        assertEquals(2841, prime.maxFreqMhz)
+    }
+
+    @Test
+    fun testTriClusterWithoutPrimeCore() {
+        val freqs = (0..1).associateWith { 1_800_000L } +
+            (2..4).associateWith { 2_400_000L } +
+            (5..7).associateWith { 3_000_000L }
+        assertEquals(listOf(ClusterType.BIG, ClusterType.MID, ClusterType.LITTLE), types(freqs))
+    }
+
+    @Test
+    fun testBigLittleAndSingleCluster() {
+        val bigLittle = (0..3).associateWith { 1_800_000L } + (4..7).associateWith { 2_400_000L }
+        assertEquals(listOf(ClusterType.BIG, ClusterType.LITTLE), types(bigLittle))
+
+        val single = (0..3).associateWith { 2_000_000L }
+        assertEquals(listOf(ClusterType.BIG), types(single))
+    }
+
+    @Test
+    fun testQuadCluster() {
+        val freqs = (0..1).associateWith { 1_500_000L } +
+            (2..4).associateWith { 2_000_000L } +
+            (5..6).associateWith { 2_500_000L } +
+            mapOf(7 to 3_200_000L)
+        assertEquals(
+            listOf(ClusterType.PRIME, ClusterType.BIG, ClusterType.MID, ClusterType.LITTLE),
+            types(freqs)
+        )
+    }
+
+    @Test
+    fun testUnreadableCoresIgnored() {
+        val freqs = mapOf(0 to 0L, 1 to 2_000_000L)
+        val clusters = CpuTopologyDetector.classifyClusters(freqs).clusters
+        assertEquals(1, clusters.size)
+        assertEquals(listOf(1), clusters[0].coreIds)
+    }
+
+    @Test
+    fun testCapacityThresholds() {
+        assertEquals(ClusterType.PRIME, CpuTopologyDetector.classifyCapacity(1024))
+        assertEquals(ClusterType.PRIME, CpuTopologyDetector.classifyCapacity(768))
+        assertEquals(ClusterType.BIG, CpuTopologyDetector.classifyCapacity(767))
+        assertEquals(ClusterType.BIG, CpuTopologyDetector.classifyCapacity(512))
+        assertEquals(ClusterType.MID, CpuTopologyDetector.classifyCapacity(511))
+        assertEquals(ClusterType.MID, CpuTopologyDetector.classifyCapacity(256))
+        assertEquals(ClusterType.LITTLE, CpuTopologyDetector.classifyCapacity(255))
+    }
+
+    @Test
+    fun testClustersByCapacity() {
+        val capacities = (0..3).associateWith { 160L } + (4..6).associateWith { 640L } + mapOf(7 to 1024L)
+        val freqs = (0..3).associateWith { 1_804_800L } +
+            (4..6).associateWith { 2_419_200L } +
+            mapOf(7 to 2_841_600L)
+        val clusters = CpuTopologyDetector.classifyClustersByCapacity(capacities, freqs).clusters
+        assertEquals(
+            listOf(ClusterType.PRIME, ClusterType.BIG, ClusterType.LITTLE),
+            clusters.map { it.clusterType }
+        )
+        assertEquals(listOf(4, 5, 6), clusters[1].coreIds)
+        assertEquals(2419, clusters[1].maxFreqMhz)
+    }
+}
@@ -0,0 +1,43 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import org.junit.Assert.*
+import org.junit.Test
+
+class MemoryPressureTest {
+
+    @Test
+    fun testParsesMeminfo() {
+        val meminfo =
+                """
+                MemTotal:        8000000 kB
+                MemFree:          300000 kB
+                MemAvailable:    1600000 kB
+                Buffers:           10240 kB
+                Cached:          1536000 kB
+                SwapTotal:       4096000 kB
+                SwapFree:        3072000 kB
+                """.trimIndent()
+
+        val pressure = MemoryPressure.parse(meminfo)!!
+
+        assertEquals(7812L, pressure.totalMb)
+        assertEquals(1562L, pressure.availableMb)
+        assertEquals(1500L, pressure.cachedMb)
+        assertEquals(1000L, pressure.swapUsedMb)
+        assertEquals(PressureLevel.MODERATE, pressure.pressureLevel)
+    }
+
+    @Test
+    fun testFallsBackWithoutMemAvailable() {
+        val meminfo = "MemTotal: 1024000 kB\nMemFree: 20480 kB\nBuffers: 10240 kB\nCached: 40960 kB\n"
+        val pressure = MemoryPressure.parse(meminfo)!!
+        assertEquals(70L, pressure.availableMb)
+        assertEquals(0L, pressure.swapUsedMb)
+        assertEquals(PressureLevel.HIGH, pressure.pressureLevel)
+    }
+
+    @Test
+    fun testMissingTotalIsUnreadable() {
+        assertNull(MemoryPressure.parse("MemFree: 1024 kB\n"))
+    }
+}
@@ -0,0 +1,39 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import org.junit.Assert.*
+import org.junit.Test
+
+class ScoreExplanationTest {
+
+    private val summary = BenchmarkSummary(0.0, 0.0, 0.0, 0.0, "")
+
+    private fun suite(vararg results: Pair<String, Double>) =
+        SuiteResult(summary, results.map { (name, ops) -> BenchmarkResultBuilder().name(name).ops(ops).build() })
+
+    @Test
+    fun testContributionsBottleneckAndHighlight() {
+        val primeRef = KotlinBenchmarkManager.REFERENCE_MOPS[BenchmarkName.PRIME_GENERATION]!!
+        val hashRef = KotlinBenchmarkManager.REFERENCE_MOPS[BenchmarkName.HASH_COMPUTING]!!
+        val result = suite("Single-Core Prime Generation" to primeRef * 0.5, "Multi-Core Hash Computing" to hashRef * 3.0)
+        val weights = mapOf("Single-Core Prime Generation" to 0.5, "Multi-Core Hash Computing" to 0.5)
+
+        val explanation = explainScore(result, weights)
+
+        assertEquals(listOf("Multi-Core Hash Computing", "Single-Core Prime Generation"), explanation.contributions.map { it.name })
+        assertEquals(300.0, explanation.contributions[0].rawScore, 1e-9)
+        assertEquals(150.0, explanation.contributions[0].weightedScore, 1e-9)
+        assertEquals(300.0 / 3.5, explanation.contributions[0].pctOfTotal, 1e-9)
+        assertEquals(100.0, explanation.contributions.sumOf { it.pctOfTotal }, 1e-9)
+        assertEquals("Single-Core Prime Generation", explanation.bottleneck)
+        assertEquals("Multi-Core Hash Computing", explanation.highlight)
+    }
+
+    @Test
+    fun testSkipsUnweightedAndUnreferencedResults() {
+        val result = suite("Single-Core FFT" to 1e9, "Single-Core Prime Generation" to 1e6)
+        val explanation = explainScore(result, mapOf("Single-Core FFT" to 1.0))
+        assertTrue(explanation.contributions.isEmpty())
+        assertEquals("", explanation.bottleneck)
+        assertEquals("", explanation.highlight)
+    }
+}
@@ -0,0 +1,39 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import org.junit.Assert.*
+import org.junit.Test
+
+class ScoringWeightsTest {
+
+    private val suite = setOf("Single-Core FFT", "Multi-Core FFT", "Multi-Core Compression")
+
+    @Test
+    fun testValidWeights() {
+        val weights = mapOf("Single-Core FFT" to 0.3, "Multi-Core FFT" to 0.695)
+        assertTrue(validateScoringWeights(weights, suite).isEmpty())
+    }
+
+    @Test
+    fun testSumMustBeNearOne() {
+        val errors = validateScoringWeights(mapOf("Single-Core FFT" to 0.5, "Multi-Core FFT" to 0.3), suite)
+        assertEquals(1, errors.size)
+        assertEquals(0.8, (errors[0] as ScoringWeightError.SumNotOne).sum, 1e-12)
+        assertFalse(validateScoringWeights(emptyMap(), suite).isEmpty())
+    }
+
+    @Test
+    fun testRejectsUnknownAndNegativeWeights() {
+        val errors =
+            validateScoringWeights(
+                mapOf("Single-Core Quake" to 0.5, "Multi-Core FFT" to 0.7, "Multi-Core Compression" to -0.2),
+                suite
+            )
+        assertEquals(
+            listOf(
+                ScoringWeightError.NotInSuite("Single-Core Quake"),
+                ScoringWeightError.Negative("Multi-Core Compression", -0.2)
+            ),
+            errors
+        )
+    }
+}
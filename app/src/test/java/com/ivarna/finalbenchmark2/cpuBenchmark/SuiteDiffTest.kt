@@ -0,0 +1,34 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import org.junit.Assert.*
+import org.junit.Test
+
+class SuiteDiffTest {
+
+    private val summary = BenchmarkSummary(0.0, 0.0, 0.0, 0.0, "")
+
+    private fun suite(vararg results: Pair<String, Double>) =
+        SuiteResult(summary, results.map { (name, ops) -> BenchmarkResultBuilder().name(name).ops(ops).build() })
+
+    @Test
+    fun testDeltaAndRegressionThreshold() {
+        val baseline = suite("Single-Core FFT" to 100.0, "Multi-Core FFT" to 200.0, "Multi-Core AES" to 50.0)
+        val current = suite("Multi-Core AES" to 60.0, "Single-Core FFT" to 96.0, "Multi-Core FFT" to 180.0)
+        val diff = diffResults(baseline, current)
+
+        assertEquals(listOf("Single-Core FFT", "Multi-Core FFT", "Multi-Core AES"), diff.perBenchmark.map { it.name })
+        assertEquals(-4.0, diff.perBenchmark[0].deltaPercent, 1e-9)
+        assertFalse(diff.perBenchmark[0].regression)
+        assertEquals(-10.0, diff.perBenchmark[1].deltaPercent, 1e-9)
+        assertTrue(diff.perBenchmark[1].regression)
+        assertEquals(20.0, diff.perBenchmark[2].deltaPercent, 1e-9)
+        assertEquals(listOf("Multi-Core FFT"), diff.regressions.map { it.name })
+    }
+
+    @Test
+    fun testSkipsBenchmarksMissingFromEitherRun() {
+        val diff = diffResults(suite("A" to 1.0, "B" to 0.0), suite("B" to 5.0, "C" to 1.0))
+        assertEquals(1, diff.perBenchmark.size)
+        assertEquals(0.0, diff.perBenchmark[0].deltaPercent, 0.0)
+    }
+}
@@ -0,0 +1,23 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import java.io.File
+import org.junit.Assert.*
+import org.junit.Rule
+import org.junit.Test
+import org.junit.rules.TemporaryFolder
+
+class SuiteResultStorageTest {
+
+    @get:Rule
+    val folder = TemporaryFolder()
+
+    @Test
+    fun testSaveReplacesContentsWithoutLeavingTempFiles() {
+        val file = File(folder.root, "results/latest.json")
+        saveResults("{\"final_score\": 1.0}", file)
+        saveResults("{\"final_score\": 2.0}", file)
+
+        assertEquals("{\"final_score\": 2.0}", file.readText())
+        assertEquals(listOf("latest.json"), file.parentFile!!.list()!!.toList())
+    }
+}
@@ -0,0 +1,59 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import org.junit.Assert.*
+import org.junit.Test
+
+class WorkloadParamValidationTest {
+
+    @Test
+    fun testDefaultsAreValid() {
+        assertTrue(validateWorkloadParams(WorkloadParams()).isEmpty())
+    }
+
+    @Test
+    fun testScaledParamsStayValid() {
+        assertTrue(validateWorkloadParams(WorkloadParams().scale(0.001)).isEmpty())
+        assertTrue(validateWorkloadParams(WorkloadParams().scale(4.0)).isEmpty())
+    }
+
+    @Test
+    fun testInvalidParamsTable() {
+        val defaults = WorkloadParams()
+        val cases =
+                listOf(
+                        defaults.copy(matrixSize = 4_096) to
+                                WorkloadParamError.MatrixSizeTooLarge(4_096, MAX_MATRIX_SIZE),
+                        defaults.copy(nqueensSize = 20) to
+                                WorkloadParamError.NQueensSizeTooLarge(20, MAX_NQUEENS_SIZE),
+                        defaults.copy(nqueensSize = 2) to
+                                WorkloadParamError.NQueensSizeTooSmall(2, MIN_NQUEENS_SIZE),
+                        defaults.copy(fibonacciNRange = Pair(40, 30)) to
+                                WorkloadParamError.FibonacciRangeInverted(40, 30),
+                        defaults.copy(fibonacciNRange = Pair(10, 100)) to
+                                WorkloadParamError.FibonacciNTooLarge(100, 92),
+                        defaults.copy(fftSize = 1_000) to WorkloadParamError.FftSizeNotPowerOfTwo(1_000),
+                        defaults.copy(waveletFrameSize = 3_000) to
+                                WorkloadParamError.WaveletFrameSizeNotPowerOfTwo(3_000),
+                        defaults.copy(dedupDuplicateFraction = 1.0) to
+                                WorkloadParamError.DedupDuplicateFractionOutOfRange(1.0),
+                        defaults.copy(stringSearchPatternLen = 32) to
+                                WorkloadParamError.StringSearchPatternTooLong(32, MAX_STRING_SEARCH_PATTERN_LEN),
+                        defaults.copy(matrixIterations = 0) to
+                                WorkloadParamError.NonPositive("matrixIterations", 0),
+                        defaults.copy(monteCarloSamples = -5L) to
+                                WorkloadParamError.NonPositive("monteCarloSamples", -5),
+                        defaults.copy(rayTracingResolution = Pair(256, 0)) to
+                                WorkloadParamError.NonPositive("rayTracingResolution.height", 0)
+                )
+
+        for ((params, expected) in cases) {
+            assertEquals(listOf(expected), validateWorkloadParams(params))
+        }
+    }
+
+    @Test
+    fun testReportsEveryError() {
+        val params = WorkloadParams(matrixSize = 4_096, nqueensSize = 20, fftSize = 3)
+        assertEquals(3, validateWorkloadParams(params).size)
+    }
+}
@@ -0,0 +1,84 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark
+
+import org.junit.Assert.*
+import org.junit.Test
+
+class WorkloadParamsTest {
+
+    @Test
+    fun testScaleMultipliesIterations() {
+        val params = WorkloadParams()
+        val doubled = params.scale(2.0)
+        assertEquals(params.matrixIterations * 2, doubled.matrixIterations)
+        assertEquals(params.hashIterations * 2, doubled.hashIterations)
+        assertEquals(params.monteCarloSamples * 2, doubled.monteCarloSamples)
+    }
+
+    @Test
+    fun testScaleClampsMinimums() {
+        val tiny = WorkloadParams().scale(0.001)
+        assertEquals(10, tiny.matrixSize)
+        assertTrue(tiny.fibonacciIterations >= 1)
+        assertEquals(WorkloadParams().fibonacciNRange, tiny.fibonacciNRange)
+    }
+
+    @Test
+    fun testScaleCapsNQueensAndKeepsFftPowerOfTwo() {
+        val huge = WorkloadParams().scale(3.0)
+        assertEquals(18, huge.nqueensSize)
+        assertEquals(0, huge.fftSize and (huge.fftSize - 1))
+    }
+
+    @Test(expected = IllegalArgumentException::class)
+    fun testScaleRejectsNonPositiveFactor() {
+        WorkloadParams().scale(0.0)
+    }
+
+    @Test
+    fun testJsonRoundTrip() {
+        val params =
+                WorkloadParams(
+                        primeRange = 1_000,
+                        fibonacciNRange = Pair(10, 20),
+                        fibonacciIterations = 2,
+                        matrixSize = 32,
+                        matrixIterations = 3,
+                        hashDataSizeMb = 4,
+                        hashIterations = 5,
+                        stringSortCount = 6,
+                        stringSortIterations = 7,
+                        rayTracingIterations = 8,
+                        rayTracingResolution = Pair(64, 48),
+                        rayTracingDepth = 2,
+                        compressionDataSizeMb = 3,
+                        compressionIterations = 9,
+                        monteCarloSamples = 10_000_000_000L,
+                        jsonDataSizeMb = 2,
+                        jsonParsingIterations = 11,
+                        nqueensSize = 8,
+                        scanElementCount = 12,
+                        scanIterations = 13,
+                        stringSearchCount = 14,
+                        stringSearchPatternLen = 4,
+                        stringSearchIterations = 15,
+                        graphNodeCount = 16,
+                        graphBfsIterations = 17,
+                        fftSize = 1_024,
+                        fftIterations = 18,
+                        aesDataSizeMb = 19
+                )
+        assertEquals(params, WorkloadParams.fromJson(params.toJson()))
+    }
+
+    @Test
+    fun testJsonUsesSnakeCaseAndArrays() {
+        val json = WorkloadParams().toJson()
+        assertTrue(json.contains("\"nqueens_size\":15"))
+        assertTrue(json.contains("\"ray_tracing_resolution\":[256,256]"))
+
+        val decoded = WorkloadParams.fromJson("{\"fibonacci_n_range\": [30, 35], \"matrix_size\": 64}")
+        assertEquals(Pair(30, 35), decoded.fibonacciNRange)
+        assertEquals(64, decoded.matrixSize)
+        assertEquals(WorkloadParams().hashIterations, decoded.hashIterations)
+    }
+}
@@ -0,0 +1,38 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark.algorithms
+
+import org.junit.Assert.*
+import org.junit.Test
+
+class BloomFilterTest {
+
+    @Test
+    fun testInsertedKeysAreFound() {
+        val filter = BloomFilter(10_000)
+        val keys = (0 until 1_000).map { "key-$it".toByteArray() }
+        keys.forEach { filter.add(it) }
+        assertTrue(keys.all { filter.mightContain(it) })
+    }
+
+    @Test
+    fun testFalsePositiveRateAtTenBitsPerKey() {
+        val filter = BloomFilter(10_000 * 10)
+        (0 until 10_000).forEach { filter.add("member-$it".toByteArray()) }
+        val falsePositives = (0 until 10_000).count { filter.mightContain("other-$it".toByteArray()) }
+        assertTrue("false positives: $falsePositives", falsePositives < 500)
+    }
+
+    @Test
+    fun testMergeMatchesSingleFilter() {
+        val whole = BloomFilter(4_096)
+        val left = BloomFilter(4_096)
+        val right = BloomFilter(4_096)
+        (0 until 200).forEach { i ->
+            val key = "k$i".toByteArray()
+            whole.add(key)
+            (if (i % 2 == 0) left else right).add(key)
+        }
+        left.mergeFrom(right)
+        assertEquals(whole.fillRatio(), left.fillRatio(), 0.0)
+        assertEquals(0.0, BloomFilter(4_096).fillRatio(), 0.0)
+    }
+}
@@ -0,0 +1,29 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark.algorithms
+
+import org.junit.Assert.*
+import org.junit.Test
+
+class DancingLinksTest {
+
+    @Test
+    fun testKnuthExample() {
+        // Example from Knuth's "Dancing Links" paper: the only cover is rows 0, 3 and 4
+        val links = DancingLinks(7, 0)
+        links.addRow(intArrayOf(2, 4, 5))
+        links.addRow(intArrayOf(0, 3, 6))
+        links.addRow(intArrayOf(1, 2, 5))
+        links.addRow(intArrayOf(0, 3))
+        links.addRow(intArrayOf(1, 6))
+        links.addRow(intArrayOf(3, 4, 6))
+        assertEquals(1L, links.countSolutions())
+        // Links are restored after the search
+        assertEquals(1L, links.countSolutions())
+    }
+
+    @Test
+    fun testNQueensMatchesBacktracking() {
+        for (n in 1..10) {
+            assertEquals("n = $n", BenchmarkHelpers.solveNQueensArrays(n), DancingLinks.nQueens(n).countSolutions())
+        }
+    }
+}
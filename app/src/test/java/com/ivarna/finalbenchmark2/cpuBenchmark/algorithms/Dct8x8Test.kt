@@ -0,0 +1,37 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark.algorithms
+
+import org.junit.Assert.*
+import org.junit.Test
+
+class Dct8x8Test {
+
+    @Test
+    fun testConstantBlockHasOnlyDc() {
+        val block = DoubleArray(64) { 10.0 }
+        val coefficients = DoubleArray(64)
+        Dct8x8().forward(block, 0, coefficients, 0)
+        // Orthonormal scaling: DC = 8 × mean
+        assertEquals(80.0, coefficients[0], 1e-9)
+        for (i in 1 until 64) assertEquals("coefficient $i", 0.0, coefficients[i], 1e-9)
+    }
+
+    @Test
+    fun testRoundTripAtOffset() {
+        val dct = Dct8x8()
+        val data = DoubleArray(3 * 64) { i -> ((i * 37) % 255 - 128).toDouble() }
+        val coefficients = DoubleArray(64)
+        val out = DoubleArray(data.size)
+        dct.forward(data, 64, coefficients, 0)
+        dct.inverse(coefficients, 0, out, 64)
+        for (i in 64 until 128) assertEquals(data[i], out[i], 1e-9)
+    }
+
+    @Test
+    fun testQuantizeRoundTripSnapsToTable() {
+        val coefficients = DoubleArray(64) { 100.0 }
+        Dct8x8.quantizeRoundTrip(coefficients, 0)
+        assertEquals(96.0, coefficients[0], 0.0) // q = 16
+        assertEquals(99.0, coefficients[63], 0.0) // q = 99
+        for (i in 0 until 64) assertEquals(0.0, coefficients[i] % Dct8x8.LUMINANCE_QUANT[i], 0.0)
+    }
+}
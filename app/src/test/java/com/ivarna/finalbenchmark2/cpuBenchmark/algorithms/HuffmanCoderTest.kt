@@ -0,0 +1,45 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark.algorithms
+
+import org.junit.Assert.*
+import org.junit.Test
+
+class HuffmanCoderTest {
+
+    private fun roundTrip(data: ByteArray): Int {
+        val encoded = ByteArray(HuffmanCoder.maxEncodedSize(data.size))
+        val size = HuffmanCoder.encode(data, 0, data.size, encoded, 0)
+        val decoded = ByteArray(data.size)
+        assertEquals(data.size, HuffmanCoder.decode(encoded, 0, decoded, 0))
+        assertArrayEquals(data, decoded)
+        return size
+    }
+
+    @Test
+    fun testRoundTripText() {
+        val data = Lz77Compressor.sampleText(100_000, 5L)
+        val size = roundTrip(data)
+        assertTrue("encoded to $size bytes", size < data.size * 3 / 4)
+    }
+
+    @Test
+    fun testRoundTripEdgeCases() {
+        assertEquals(HuffmanCoder.HEADER_SIZE, roundTrip(ByteArray(0)))
+        roundTrip(byteArrayOf(9))
+        roundTrip(ByteArray(1_000) { 42 })
+        roundTrip(ByteArray(256) { it.toByte() })
+    }
+
+    @Test
+    fun testCodeLengthsFormCompletePrefixCode() {
+        val histogram = LongArray(256)
+        longArrayOf(1, 1, 2, 3, 5, 8, 13, 21, 34).forEachIndexed { i, count -> histogram[i] = count }
+        val lengths = HuffmanCoder.codeLengths(histogram)
+
+        // Kraft sum of a full binary tree is exactly 1
+        val kraft = lengths.filter { it > 0 }.sumOf { Math.pow(2.0, -it.toDouble()) }
+        assertEquals(1.0, kraft, 1e-12)
+        // Fibonacci weights give the deepest possible tree
+        assertEquals(8, lengths.maxOrNull())
+        assertEquals(1, lengths[8])
+    }
+}
@@ -0,0 +1,43 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark.algorithms
+
+import org.junit.Assert.*
+import org.junit.Test
+
+class LruCacheTest {
+
+    @Test
+    fun testEvictsLeastRecentlyUsed() {
+        val cache = LruCache(3)
+        cache.put(1, 10)
+        cache.put(2, 20)
+        cache.put(3, 30)
+        // Reading 1 makes 2 the oldest
+        assertEquals(10, cache.get(1))
+        cache.put(4, 40)
+
+        assertEquals(IntCache.MISS, cache.get(2))
+        assertEquals(listOf(4, 1, 3), cache.keys())
+        assertEquals(1L, cache.evictions)
+    }
+
+    @Test
+    fun testPutExistingKeyUpdatesWithoutEvicting() {
+        val cache = LruCache(2)
+        cache.put(1, 10)
+        cache.put(2, 20)
+        cache.put(1, 11)
+        assertEquals(11, cache.get(1))
+        assertEquals(listOf(1, 2), cache.keys())
+        assertEquals(0L, cache.evictions)
+    }
+
+    @Test
+    fun testShardedCacheKeepsEveryShardBounded() {
+        val cache = ShardedLruCache(capacity = 8, shardCount = 4)
+        for (key in 0 until 100) cache.put(key, key)
+        // 4 shards of 2 entries: only the last two keys of each shard survive
+        for (key in 92 until 100) assertEquals(key, cache.get(key))
+        assertEquals(IntCache.MISS, cache.get(0))
+        assertEquals(92L, cache.evictions)
+    }
+}
@@ -0,0 +1,43 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark.algorithms
+
+import org.junit.Assert.*
+import org.junit.Test
+
+class Lz77CompressorTest {
+
+    private fun roundTrip(data: ByteArray): Int {
+        val compressed = ByteArray(Lz77Compressor.maxCompressedSize(data.size))
+        val size = Lz77Compressor().compress(data, compressed)
+        val restored = ByteArray(data.size)
+        assertEquals(data.size, Lz77Compressor.decompress(compressed, size, restored))
+        assertArrayEquals(data, restored)
+        return size
+    }
+
+    @Test
+    fun testRoundTripText() {
+        val data = Lz77Compressor.sampleText(200_000, 42L)
+        val size = roundTrip(data)
+        assertTrue("compressed to $size bytes", size < data.size / 2)
+    }
+
+    @Test
+    fun testRoundTripEdgeCases() {
+        assertEquals(0, roundTrip(ByteArray(0)))
+        roundTrip(byteArrayOf(7))
+        roundTrip(byteArrayOf(1, 2))
+        // Overlapping matches (distance 1) and runs longer than the maximum match length
+        roundTrip(ByteArray(100_000))
+        // Repeats further apart than the window can't be matched but must still round-trip
+        val rng = XorShift128Plus(7L)
+        val block = ByteArray(Lz77Compressor.WINDOW_SIZE + 100) { rng.nextInt().toByte() }
+        roundTrip(block + block)
+    }
+
+    @Test
+    fun testRandomDataStaysWithinBound() {
+        val rng = XorShift128Plus(3L)
+        val data = ByteArray(50_000) { rng.nextInt().toByte() }
+        assertTrue(roundTrip(data) <= Lz77Compressor.maxCompressedSize(data.size))
+    }
+}
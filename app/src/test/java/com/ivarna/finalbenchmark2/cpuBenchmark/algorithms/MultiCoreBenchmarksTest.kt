@@ -0,0 +1,19 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark.algorithms
+
+import org.junit.Assert.*
+import org.junit.Test
+
+class MultiCoreBenchmarksTest {
+
+    @Test
+    fun testPoolThreadsFollowsCustomPool() {
+        MultiCoreBenchmarks.buildBenchmarkPool(3).use { pool ->
+            assertEquals(3, MultiCoreBenchmarks.poolThreads(pool))
+        }
+    }
+
+    @Test
+    fun testPoolThreadsDefaultsToCoreCount() {
+        assertEquals(Runtime.getRuntime().availableProcessors(), MultiCoreBenchmarks.poolThreads(null))
+    }
+}
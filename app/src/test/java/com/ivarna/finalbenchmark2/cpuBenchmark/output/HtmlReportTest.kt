@@ -0,0 +1,50 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark.output
+
+import com.ivarna.finalbenchmark2.cpuBenchmark.BenchmarkResultBuilder
+import com.ivarna.finalbenchmark2.cpuBenchmark.BenchmarkSummary
+import com.ivarna.finalbenchmark2.cpuBenchmark.SuiteResult
+import org.junit.Assert.*
+import org.junit.Test
+
+class HtmlReportTest {
+
+    private val suiteResult =
+            SuiteResult(
+                    summary = BenchmarkSummary(120.0, 480.0, 354.0, 354.0, "★☆☆☆☆ (Basic Performance)"),
+                    results =
+                            listOf(
+                                    BenchmarkResultBuilder().name("Single-Core Prime Generation").ops(1e8).build(),
+                                    BenchmarkResultBuilder().name("Multi-Core Prime Generation").ops(4e8).build()
+                            ),
+                    deviceInfo = mapOf("Model" to "Test <Device>")
+            )
+
+    @Test
+    fun testRenderContainsSummaryAndDevice() {
+        val html = HtmlReport(suiteResult).render()
+        assertTrue(html.startsWith("<!DOCTYPE html>"))
+        assertTrue(html.contains("<td>354</td>"))
+        assertTrue(html.contains("Test &lt;Device&gt;"))
+    }
+
+    @Test
+    fun testBestResultFillsChartAndBarsAreColoredByCategory() {
+        val html = HtmlReport(suiteResult).render()
+        assertTrue(html.contains("width=\"400.0\" height=\"18\" fill=\"${HtmlReport.MULTI_CORE_COLOR}\""))
+        assertTrue(html.contains("width=\"100.0\" height=\"18\" fill=\"${HtmlReport.SINGLE_CORE_COLOR}\""))
+        assertTrue(html.contains(">25%</text>"))
+    }
+
+    @Test
+    fun testPointsOnlyForScoredSuiteNames() {
+        val scored = BenchmarkResultBuilder().name("Single-Core Monte Carlo π").ops(1e8).build()
+        val unscored = BenchmarkResultBuilder().name("Single-Core Monte Carlo Integration").ops(1e8).build()
+        assertTrue(HtmlReport.points(scored) > 0.0)
+        assertEquals(0.0, HtmlReport.points(unscored), 0.0)
+    }
+
+    @Test
+    fun testEscape() {
+        assertEquals("a &amp; &lt;b&gt; &quot;c&quot;", HtmlReport.escape("a & <b> \"c\""))
+    }
+}
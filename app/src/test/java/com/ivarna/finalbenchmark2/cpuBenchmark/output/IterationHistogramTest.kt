@@ -0,0 +1,38 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark.output
+
+import org.junit.Assert.*
+import org.junit.Test
+
+class IterationHistogramTest {
+
+    private fun counts(histogram: String): List<Int> =
+        histogram.trimEnd('\n').lines().map { it.substringAfterLast(' ').toInt() }
+
+    @Test
+    fun testBimodalTimesLandInOuterBuckets() {
+        val times = List(50) { 10.0 } + List(5) { 100.0 }
+        val histogram = renderIterationHistogram(times)
+        val counts = counts(histogram)
+
+        assertEquals(10, counts.size)
+        assertEquals(50, counts.first())
+        assertEquals(5, counts.last())
+        assertEquals(55, counts.sum())
+    }
+
+    @Test
+    fun testIdenticalTimesUseFirstBucket() {
+        val counts = counts(renderIterationHistogram(List(7) { 3.5 }, buckets = 4))
+        assertEquals(listOf(7, 0, 0, 0), counts)
+    }
+
+    @Test
+    fun testEmptyInput() {
+        assertEquals("(no iterations)\n", renderIterationHistogram(emptyList()))
+    }
+
+    @Test(expected = IllegalArgumentException::class)
+    fun testRejectsZeroBuckets() {
+        renderIterationHistogram(listOf(1.0), buckets = 0)
+    }
+}
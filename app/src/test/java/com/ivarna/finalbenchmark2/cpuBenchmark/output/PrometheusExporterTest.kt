@@ -0,0 +1,50 @@
+package com.ivarna.finalbenchmark2.cpuBenchmark.output
+
+import com.ivarna.finalbenchmark2.cpuBenchmark.BenchmarkResultBuilder
+import com.ivarna.finalbenchmark2.cpuBenchmark.BenchmarkSummary
+import com.ivarna.finalbenchmark2.cpuBenchmark.DeviceFingerprint
+import com.ivarna.finalbenchmark2.cpuBenchmark.SuiteResult
+import org.junit.Assert.*
+import org.junit.Test
+
+class PrometheusExporterTest {
+
+    private val suiteResult =
+            SuiteResult(
+                    summary = BenchmarkSummary(100.0, 400.0, 295.0, 295.0, "★☆☆☆☆ (Basic Performance)"),
+                    results =
+                            listOf(
+                                    BenchmarkResultBuilder()
+                                            .name("Single-Core Prime Generation")
+                                            .ops(1.23e9)
+                                            .timeMs(1500.0)
+                                            .build(),
+                                    BenchmarkResultBuilder().name("Multi-Core \"Quoted\"").valid(false).build()
+                            )
+            )
+
+    @Test
+    fun testExportsSamples() {
+        val text = exportPrometheusMetrics(suiteResult, "cpu_benchmark")
+        assertTrue(text.contains("cpu_benchmark_ops_per_second{name=\"Single-Core Prime Generation\"} 1.23E9\n"))
+        assertTrue(text.contains("cpu_benchmark_execution_time_ms{name=\"Single-Core Prime Generation\"} 1500.0\n"))
+        assertTrue(text.contains("cpu_benchmark_score{type=\"final\"} 295.0\n"))
+        assertTrue(text.contains("# TYPE cpu_benchmark_ops_per_second gauge\n"))
+    }
+
+    @Test
+    fun testEscapesLabelsAndSanitizesPrefix() {
+        val text = exportPrometheusMetrics(suiteResult, "my-build.v2")
+        assertTrue(text.contains("my_build_v2_valid{name=\"Multi-Core \\\"Quoted\\\"\"} 0.0\n"))
+    }
+
+    @Test
+    fun testExportsDeviceInfoOnlyWithFingerprint() {
+        assertFalse(exportPrometheusMetrics(suiteResult).contains("device_info"))
+
+        val fingerprint = DeviceFingerprint("Acme X1", 8, 4, 3200, 300, "aarch64", "Android 14 (API 34)", "6.1.0")
+        val text = exportPrometheusMetrics(suiteResult.copy(deviceFingerprint = fingerprint))
+        assertTrue(text.contains("cpu_benchmark_device_info{model=\"Acme X1\",arch=\"aarch64\","))
+        assertTrue(text.contains("core_count=\"8\",big_core_count=\"4\",max_freq_mhz=\"3200\",min_freq_mhz=\"300\"} 1.0\n"))
+    }
+}